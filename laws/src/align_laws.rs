@@ -0,0 +1,44 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+type SelfAligned<FA> = <FA as Higher>::Target<Ior<<FA as Higher>::Param, <FA as Higher>::Param>>;
+
+pub fn align_idempotency<FA>(fa: FA) -> IsEq<SelfAligned<FA>>
+where
+    FA: Align<<FA as Higher>::Param, Target<<FA as Higher>::Param> = FA>
+        + Functor<Ior<<FA as Higher>::Param, <FA as Higher>::Param>>
+        + Clone,
+    FA::Param: Clone,
+{
+    IsEq::equal_under_law(
+        fa.clone().align(fa.clone()),
+        fa.map(|a| Ior::Both(a.clone(), a)),
+    )
+}
+
+pub fn align_commutativity<FA, B>(fa: FA, fb: FA::Target<B>) -> IsEq<FA::Target<Ior<FA::Param, B>>>
+where
+    FA: Align<B> + Clone,
+    FA::Target<B>: Align<FA::Param, Target<FA::Param> = FA> + Clone,
+    <FA::Target<B> as Higher>::Target<Ior<B, FA::Param>>:
+        Functor<Ior<FA::Param, B>, Target<Ior<FA::Param, B>> = FA::Target<Ior<FA::Param, B>>>,
+{
+    IsEq::equal_under_law(fa.clone().align(fb.clone()), fb.align(fa).map(Ior::swap))
+}
+
+pub fn align_with_consistency<FA, B, C>(
+    fa: FA,
+    fb: FA::Target<B>,
+    mut f: impl FnMut(Ior<FA::Param, B>) -> C,
+) -> IsEq<FA::Target<C>>
+where
+    FA: Align<B> + Clone,
+    FA::Target<B>: Clone,
+    FA::Target<Ior<FA::Param, B>>: Functor<C, Target<C> = FA::Target<C>>,
+{
+    IsEq::equal_under_law(
+        fa.clone().align_with(fb.clone(), &mut f),
+        fa.align(fb).map(f),
+    )
+}
@@ -0,0 +1,49 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn and_then_left_identity<FA, B, F>(a: FA::Param, mut f: F) -> IsEq<FA::Target<B>>
+where
+    F: FnMut(FA::Param) -> FA::Target<B>,
+    FA: AndThen<B> + Pure,
+    FA::Param: Clone,
+{
+    let lhs = FA::pure(a.clone()).and_then(&mut f);
+    let rhs = f(a);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn and_then_right_identity<FA>(fa: FA) -> IsEq<FA>
+where
+    FA: AndThen<<FA as Higher>::Param, Target<<FA as Higher>::Param> = FA> + Pure + Clone,
+{
+    let lhs = fa.clone().and_then(FA::pure);
+    let rhs = fa;
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn and_then_associativity<FA, B, C, F, G>(fa: FA, f: F, mut g: G) -> IsEq<FA::Target<C>>
+where
+    FA: AndThen<B> + AndThen<C> + Clone,
+    F: Fn(FA::Param) -> FA::Target<B>,
+    G: Fn(B) -> FA::Target<C>,
+    FA::Target<B>: AndThen<C, Target<C> = FA::Target<C>>,
+{
+    let lhs = fa.clone().and_then(|a| f(a).and_then(&mut g));
+    let rhs = fa.and_then(f).and_then(g);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn and_then_flat_map_consistency<FA, B, F>(fa: FA, mut f: F) -> IsEq<FA::Target<B>>
+where
+    F: FnMut(FA::Param) -> FA::Target<B>,
+    FA: AndThen<B> + FlatMap<B> + Clone,
+{
+    let lhs = AndThen::and_then(fa.clone(), &mut f);
+    let rhs = fa.flat_map(f);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
@@ -0,0 +1,47 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn ap2_product_consistency<FA, B, Z, F>(
+    fa: FA,
+    fb: FA::Target<B>,
+    mut f: F,
+) -> IsEq<FA::Target<Z>>
+where
+    F: FnMut(FA::Param, B) -> Z + Clone,
+    FA: Semigroupal<B> + Clone,
+    FA::Target<B>: Clone,
+    FA::Target<F>: ApN<Z>
+        + Pure
+        + Higher<Target<FA::Param> = FA>
+        + Higher<Target<B> = FA::Target<B>>
+        + Higher<Target<(FA::Param, B)> = FA::Target<(FA::Param, B)>>
+        + Higher<Target<Z> = FA::Target<Z>>,
+    FA::Target<(FA::Param, B)>: Functor<Z, Target<Z> = FA::Target<Z>> + Clone,
+{
+    let lhs = <FA::Target<F>>::pure(f.clone()).ap2(fa.clone(), fb.clone());
+    let rhs = fa.product(fb).map(|(a, b)| f(a, b));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn map3_product_consistency<FA, B, C, Z, F>(
+    fa: FA,
+    fb: FA::Target<B>,
+    fc: FA::Target<C>,
+    mut f: F,
+) -> IsEq<FA::Target<Z>>
+where
+    F: FnMut(FA::Param, B, C) -> Z,
+    FA: MapN<B> + Clone,
+    FA::Target<B>: Clone,
+    FA::Target<C>: Clone,
+    FA::Target<(FA::Param, B)>: Semigroupal<C, Target<C> = FA::Target<C>>
+        + Higher<Target<((FA::Param, B), C)> = FA::Target<((FA::Param, B), C)>>,
+    FA::Target<((FA::Param, B), C)>: Functor<Z, Target<Z> = FA::Target<Z>>,
+{
+    let lhs = fa.clone().map3(fb.clone(), fc.clone(), &mut f);
+    let rhs = fa.product(fb).product(fc).map(|((a, b), c)| f(a, b, c));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
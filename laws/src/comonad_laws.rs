@@ -0,0 +1,38 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn comonad_left_identity<FA>(fa: FA) -> IsEq<FA>
+where
+    FA: CoflatMap<<FA as Higher>::Param, Target<<FA as Higher>::Param> = FA> + Comonad + Clone,
+{
+    let lhs = fa.clone();
+    let rhs = fa.coflat_map(Comonad::extract);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn comonad_right_identity<FA, B, F>(fa: FA, mut f: F) -> IsEq<B>
+where
+    FA: CoflatMap<B> + Clone,
+    FA::Target<B>: Comonad<Param = B>,
+    F: FnMut(FA) -> B,
+{
+    let lhs = f(fa.clone());
+    let rhs = fa.coflat_map(f).extract();
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn coflat_map_associativity<FA, B, C, F, G>(fa: FA, mut f: F, mut g: G) -> IsEq<FA::Target<C>>
+where
+    FA: CoflatMap<B> + CoflatMap<C> + Clone,
+    FA::Target<B>: CoflatMap<C, Target<C> = FA::Target<C>>,
+    F: FnMut(FA) -> B,
+    G: FnMut(FA::Target<B>) -> C,
+{
+    let lhs = fa.clone().coflat_map(|x| g(x.coflat_map(&mut f)));
+    let rhs = fa.coflat_map(f).coflat_map(g);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
@@ -0,0 +1,24 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn group_left_inverse<A>(a: A) -> IsEq<A>
+where
+    A: Group + Clone,
+{
+    IsEq::equal_under_law(A::empty(), a.clone().inverse().combine(a))
+}
+
+pub fn group_right_inverse<A>(a: A) -> IsEq<A>
+where
+    A: Group + Clone,
+{
+    IsEq::equal_under_law(A::empty(), a.clone().combine(a.inverse()))
+}
+
+pub fn group_consistent_remove<A>(a: A, b: A) -> IsEq<A>
+where
+    A: Group + Clone,
+{
+    IsEq::equal_under_law(a.clone().remove(b.clone()), a.combine(b.inverse()))
+}
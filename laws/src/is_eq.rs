@@ -1,3 +1,7 @@
+use std::collections::{LinkedList, VecDeque};
+
+use rust2fun::data::{Ior, Validated};
+
 pub struct IsEq<T> {
     lhs: T,
     rhs: T,
@@ -7,6 +11,11 @@ impl<T> IsEq<T> {
     pub fn equal_under_law(lhs: T, rhs: T) -> Self {
         IsEq { lhs, rhs }
     }
+
+    /// Check that both sides of the law are equal according to the given equality function.
+    pub fn holds_by(self, eq: impl FnOnce(&T, &T) -> bool) -> bool {
+        eq(&self.lhs, &self.rhs)
+    }
 }
 
 impl<T: Eq> IsEq<T> {
@@ -14,3 +23,148 @@ impl<T: Eq> IsEq<T> {
         self.lhs == self.rhs
     }
 }
+
+impl<T: rust2fun::eq::Eq> IsEq<T> {
+    /// Check that both sides of the law are equivalent according to the [rust2fun::eq::Eq]
+    /// typeclass, for types which do not implement [Eq].
+    pub fn holds_eqv(self) -> bool {
+        self.lhs.eqv(&self.rhs)
+    }
+}
+
+impl<T: ApproxEq> IsEq<T> {
+    /// Check that both sides of the law are equal up to the given relative tolerance on the
+    /// floating point numbers they contain.
+    pub fn holds_approx(self, epsilon: f64) -> bool {
+        self.lhs.approx_eq(&self.rhs, epsilon)
+    }
+}
+
+/// Equality up to a tolerance, for structures containing floating point numbers.
+///
+/// Floating point numbers are compared with a tolerance relative to the largest of their absolute
+/// values and `1.0`, so that `epsilon` works as an absolute tolerance close to zero. Two `NaN`
+/// are considered equal. All other types are compared exactly.
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+macro_rules! approx_eq_float {
+    ($($t:ty)*) => ($(
+        impl ApproxEq for $t {
+            fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                let (a, b) = (*self as f64, *other as f64);
+                if a.is_nan() || b.is_nan() {
+                    a.is_nan() && b.is_nan()
+                } else {
+                    a == b || (a - b).abs() <= epsilon * a.abs().max(b.abs()).max(1.0)
+                }
+            }
+        }
+    )*)
+}
+
+approx_eq_float! { f32 f64 }
+
+macro_rules! approx_eq_exact {
+    ($($t:ty)*) => ($(
+        impl ApproxEq for $t {
+            fn approx_eq(&self, other: &Self, _epsilon: f64) -> bool {
+                self == other
+            }
+        }
+    )*)
+}
+
+approx_eq_exact! {
+    () bool char usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 String &str
+}
+
+impl<T: ApproxEq> ApproxEq for [T] {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+}
+
+impl<T: ApproxEq, const N: usize> ApproxEq for [T; N] {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self[..].approx_eq(&other[..], epsilon)
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self[..].approx_eq(&other[..], epsilon)
+    }
+}
+
+macro_rules! approx_eq_iter {
+    ($($t:ident)*) => ($(
+        impl<T: ApproxEq> ApproxEq for $t<T> {
+            fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                self.len() == other.len()
+                    && self.iter().zip(other).all(|(a, b)| a.approx_eq(b, epsilon))
+            }
+        }
+    )*)
+}
+
+approx_eq_iter! { LinkedList VecDeque }
+
+impl<T: ApproxEq> ApproxEq for Option<T> {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.approx_eq(b, epsilon),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: ApproxEq, E: ApproxEq> ApproxEq for Result<T, E> {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Ok(a), Ok(b)) => a.approx_eq(b, epsilon),
+            (Err(a), Err(b)) => a.approx_eq(b, epsilon),
+            _ => false,
+        }
+    }
+}
+
+impl<T: ApproxEq, E: ApproxEq> ApproxEq for Validated<T, E> {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Validated::Valid(a), Validated::Valid(b)) => a.approx_eq(b, epsilon),
+            (Validated::Invalid(a), Validated::Invalid(b)) => a.approx_eq(b, epsilon),
+            _ => false,
+        }
+    }
+}
+
+impl<A: ApproxEq, B: ApproxEq> ApproxEq for Ior<A, B> {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (Ior::Left(a), Ior::Left(b)) => a.approx_eq(b, epsilon),
+            (Ior::Right(a), Ior::Right(b)) => a.approx_eq(b, epsilon),
+            (Ior::Both(a1, b1), Ior::Both(a2, b2)) => {
+                a1.approx_eq(a2, epsilon) && b1.approx_eq(b2, epsilon)
+            }
+            _ => false,
+        }
+    }
+}
+
+macro_rules! approx_eq_tuple {
+    ($($t:ident $i:tt),+) => {
+        impl<$($t: ApproxEq),+> ApproxEq for ($($t,)+) {
+            fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                $(self.$i.approx_eq(&other.$i, epsilon))&&+
+            }
+        }
+    };
+}
+
+approx_eq_tuple!(A 0);
+approx_eq_tuple!(A 0, B 1);
+approx_eq_tuple!(A 0, B 1, C 2);
+approx_eq_tuple!(A 0, B 1, C 2, D 3);
@@ -0,0 +1,249 @@
+/// Generate property based tests checking the laws of the given typeclasses for a type.
+///
+/// Each listed typeclass expands into a [proptest](https://docs.rs/proptest) test named after it
+/// with a `_laws` suffix, which checks all the laws of the typeclass for arbitrary values of the
+/// type. The type must implement `Arbitrary`, `Clone`, `Debug` and `Eq`, and so must its
+/// parameter. As the generated tests are named after the typeclasses, invoke the macro in a
+/// separate module for each type.
+///
+/// The supported typeclasses are `invariant`, `functor`, `semigroupal`, `apply`, `applicative`,
+/// `flat_map`, `monad`, `align`, `traverse`, `reducible`, `semigroup`,
+/// `commutative_semigroup` and `monoid`. The functions passed to the laws are derived from the
+/// `Debug` representation of the values, and the effectful ones lift their results with `pure`.
+///
+/// The values of the type are drawn from `any::<T>()` unless a strategy is given after `=`, e.g.
+/// to keep the sums of integers from overflowing.
+///
+/// # Examples
+///
+/// ```
+/// mod vec_laws {
+///     use rust2fun_laws::law_suite;
+///
+///     law_suite!(Vec<i32>: functor, apply, applicative, monad, monoid);
+/// }
+///
+/// mod option_laws {
+///     use rust2fun_laws::law_suite;
+///
+///     law_suite!(Option<bool>: functor, semigroupal, flat_map, traverse);
+/// }
+///
+/// mod u32_laws {
+///     use rust2fun_laws::law_suite;
+///
+///     law_suite!(u32 = 0..1_000u32; semigroup, commutative_semigroup, monoid);
+/// }
+/// ```
+#[macro_export]
+macro_rules! law_suite {
+    ($t:ty: $($law:ident),+ $(,)?) => {
+        $( $crate::law_suite!(@law $t, $crate::__private::proptest::prelude::any::<$t>(), $law); )+
+    };
+
+    ($t:ty = $s:expr; $($law:ident),+ $(,)?) => {
+        $( $crate::law_suite!(@law $t, $s, $law); )+
+    };
+
+    (@show $t:ty) => {
+        |a: <$t as $crate::__private::rust2fun::higher::Higher>::Param| format!("{:?}", a)
+    };
+
+    (@law $t:ty, $s:expr, invariant) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn invariant_laws(fa in $s) {
+                $crate::__private::proptest::prop_assert!(
+                    $crate::invariant_laws::invariant_identity(fa).holds()
+                );
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, functor) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn functor_laws(fa in $s) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::functor_laws::*;
+
+                let show = $crate::law_suite!(@show $t);
+                prop_assert!(covariant_identity(fa.clone()).holds());
+                prop_assert!(covariant_composition(fa.clone(), show, |s: String| s.len()).holds());
+                prop_assert!(lift_identity(fa.clone()).holds());
+                prop_assert!(lift_composition(fa, show, |s: String| s.len()).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, semigroupal) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn semigroupal_laws(fa in $s, fb in $s, fc in $s) {
+                $crate::__private::proptest::prop_assert!(
+                    $crate::semigroupal_laws::semigroupal_associativity(fa, fb, fc).holds()
+                );
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, apply) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn apply_laws(fa in $s, fb in $s) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::apply_laws::*;
+
+                let f = |a, b| format!("{:?}{:?}", a, b);
+                prop_assert!(map2_product_consistency(fa.clone(), fb.clone(), f).holds());
+                prop_assert!(product_r_consistency(fa.clone(), fb.clone()).holds());
+                prop_assert!(product_l_consistency(fa, fb).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, applicative) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn applicative_laws(
+                a in $crate::__private::proptest::prelude::any::<
+                    <$t as $crate::__private::rust2fun::higher::Higher>::Param,
+                >(),
+                fa in $s,
+            ) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::__private::rust2fun::prelude::*;
+                use $crate::applicative_laws::*;
+
+                let show = $crate::law_suite!(@show $t);
+                prop_assert!(applicative_identity(fa.clone()).holds());
+                prop_assert!(applicative_homomorphism::<$t, <$t as Higher>::Target<String>, _>(
+                    a.clone(),
+                    show,
+                ).holds());
+                prop_assert!(applicative_map(fa.clone(), show).holds());
+                prop_assert!(ap_product_consistent(fa, Pure::pure(show)).holds());
+                prop_assert!(applicative_unit::<$t>(a).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, flat_map) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn flat_map_laws(fa in $s) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::__private::rust2fun::prelude::*;
+                use $crate::flatmap_laws::*;
+
+                let show = $crate::law_suite!(@show $t);
+                prop_assert!(flat_map_associativity::<$t, String, usize, _, _>(
+                    fa.clone(),
+                    |a| Pure::pure(show(a)),
+                    |s| Pure::pure(s.len()),
+                ).holds());
+                prop_assert!(flat_map_consistent_apply(fa, Pure::pure(show)).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, monad) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn monad_laws(
+                a in $crate::__private::proptest::prelude::any::<
+                    <$t as $crate::__private::rust2fun::higher::Higher>::Param,
+                >(),
+                fa in $s,
+            ) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::__private::rust2fun::prelude::*;
+                use $crate::monad_laws::*;
+
+                let show = $crate::law_suite!(@show $t);
+                prop_assert!(monad_left_identity::<$t, String, _>(a, |a| Pure::pure(show(a))).holds());
+                prop_assert!(monad_right_identity(fa.clone()).holds());
+                prop_assert!(map_flat_map_coherence(fa, show).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, align) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn align_laws(fa in $s, fb in $s) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::__private::rust2fun::data::Ior;
+                use $crate::align_laws::*;
+
+                prop_assert!(align_idempotency(fa.clone()).holds());
+                prop_assert!(align_commutativity(fa.clone(), fb.clone()).holds());
+                prop_assert!(align_with_consistency(fa, fb, Ior::pad).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, traverse) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn traverse_laws(fa in $s) {
+                $crate::__private::proptest::prop_assert!(
+                    $crate::traverse_laws::traverse_identity(fa, $crate::law_suite!(@show $t))
+                        .holds()
+                );
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, reducible) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn reducible_laws(fa in $s) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::reducible_laws::*;
+
+                prop_assert!(reduce_left_consistent_with_iter(fa.clone(), |_, a| a).holds());
+                prop_assert!(reduce_map_consistent_with_reduce_left_to(fa, |a| vec![a]).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, semigroup) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn semigroup_laws(a in $s, b in $s, c in $s) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::semigroup_laws::*;
+
+                prop_assert!(repeat_0(a.clone()).holds());
+                prop_assert!(repeat_1(b.clone()).holds());
+                prop_assert!(semigroup_associativity(a, b, c).holds());
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, commutative_semigroup) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn commutative_semigroup_laws(a in $s, b in $s) {
+                $crate::__private::proptest::prop_assert!(
+                    $crate::semigroup_laws::semigroup_commutativity(a, b).holds()
+                );
+            }
+        }
+    };
+
+    (@law $t:ty, $s:expr, monoid) => {
+        $crate::__private::proptest::proptest! {
+            #[test]
+            fn monoid_laws(a in $s) {
+                use $crate::__private::proptest::prop_assert;
+                use $crate::monoid_laws::*;
+
+                prop_assert!(monoid_left_identity(a.clone()).holds());
+                prop_assert!(monoid_right_identity(a.clone()).holds());
+                prop_assert!(is_id(a).holds());
+            }
+        }
+    };
+}
@@ -1,14 +1,33 @@
 extern crate rust2fun;
 
+pub mod align_laws;
+pub mod and_then_laws;
+pub mod ap_n_laws;
 pub mod applicative_laws;
 pub mod apply_laws;
 pub mod bifunctor_laws;
+pub mod comonad_laws;
 pub mod contravariant_laws;
 pub mod flatmap_laws;
 pub mod functor_laws;
+pub mod group_laws;
 pub mod invariant_laws;
 pub mod is_eq;
+mod law_suite;
+pub mod monad_error_laws;
 pub mod monad_laws;
+pub mod monad_trans_laws;
 pub mod monoid_laws;
+pub mod order_laws;
+pub mod reducible_laws;
 pub mod semigroup_laws;
 pub mod semigroupal_laws;
+pub mod stack_safety_laws;
+pub mod strategies;
+pub mod traverse_laws;
+
+#[doc(hidden)]
+pub mod __private {
+    pub extern crate proptest;
+    pub extern crate rust2fun;
+}
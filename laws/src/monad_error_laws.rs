@@ -0,0 +1,108 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn handle_error_with_raise_error<FA, E, F>(e: E, mut f: F) -> IsEq<FA>
+where
+    F: FnMut(E) -> FA,
+    FA: MonadError<E>,
+    E: Clone,
+{
+    let lhs = FA::raise_error(e.clone()).handle_error_with(&mut f);
+    let rhs = f(e);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn handle_error_raise_error<FA, E, F>(e: E, mut f: F) -> IsEq<FA>
+where
+    F: FnMut(E) -> FA::Param,
+    FA: MonadError<E>,
+    E: Clone,
+{
+    let lhs = FA::raise_error(e.clone()).handle_error(&mut f);
+    let rhs = FA::pure(f(e));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn handle_error_with_pure<FA, E, F>(a: FA::Param, f: F) -> IsEq<FA>
+where
+    F: FnOnce(E) -> FA,
+    FA: MonadError<E>,
+    FA::Param: Clone,
+{
+    let lhs = FA::pure(a.clone()).handle_error_with(f);
+    let rhs = FA::pure(a);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn raise_error_attempt<FA, E>(e: E) -> IsEq<FA::Target<Result<FA::Param, E>>>
+where
+    FA: MonadError<E> + Functor<Result<<FA as Higher>::Param, E>>,
+    FA::Target<Result<FA::Param, E>>: ApplicativeError<E>,
+    E: Clone,
+{
+    let lhs = FA::raise_error(e.clone()).attempt();
+    let rhs = Pure::pure(Err(e));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn pure_attempt<FA, E>(a: FA::Param) -> IsEq<FA::Target<Result<FA::Param, E>>>
+where
+    FA: MonadError<E> + Functor<Result<<FA as Higher>::Param, E>>,
+    FA::Target<Result<FA::Param, E>>: ApplicativeError<E>,
+    FA::Param: Clone,
+{
+    let lhs = FA::pure(a.clone()).attempt();
+    let rhs = Pure::pure(Ok(a));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn attempt_rethrow<FA, E>(fa: FA) -> IsEq<FA>
+where
+    FA: MonadError<E> + Functor<Result<<FA as Higher>::Param, E>> + Clone,
+    FA::Target<Result<FA::Param, E>>: MonadError<E>
+        + FlatMap<FA::Param, Target<FA::Param> = FA>
+        + Higher<Param = Result<FA::Param, E>>,
+    FA: ApplicativeError<E>,
+{
+    let lhs = fa.clone().attempt().rethrow();
+    let rhs = fa;
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn monad_error_left_zero<FA, B, E, F>(e: E, f: F) -> IsEq<FA::Target<B>>
+where
+    F: FnMut(FA::Param) -> FA::Target<B>,
+    FA: MonadError<E> + FlatMap<B>,
+    FA::Target<B>: ApplicativeError<E>,
+    E: Clone,
+{
+    let lhs = FA::raise_error(e.clone()).flat_map(f);
+    let rhs = ApplicativeError::raise_error(e);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn ensure_consistency<FA, E, P>(fa: FA, e: E, mut predicate: P) -> IsEq<FA>
+where
+    P: FnMut(&FA::Param) -> bool,
+    FA: MonadError<E> + FlatMap<<FA as Higher>::Param, Target<<FA as Higher>::Param> = FA> + Clone,
+    E: Clone,
+{
+    let lhs = fa.clone().ensure(|| e.clone(), &mut predicate);
+    let rhs = fa.flat_map(|a| {
+        if predicate(&a) {
+            FA::pure(a)
+        } else {
+            FA::raise_error(e.clone())
+        }
+    });
+
+    IsEq::equal_under_law(lhs, rhs)
+}
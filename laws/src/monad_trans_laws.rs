@@ -0,0 +1,28 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn monad_trans_identity<T>(a: T::Param) -> IsEq<T>
+where
+    T: MonadTrans + Pure,
+    T::Inner: Pure,
+    T::Param: Clone,
+{
+    let lhs = T::lift(T::Inner::pure(a.clone()));
+    let rhs = T::pure(a);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn monad_trans_composition<T, B, F>(ga: T::Inner, mut f: F) -> IsEq<T::Target<B>>
+where
+    T: MonadTrans + FlatMap<B>,
+    T::Target<B>: MonadTrans,
+    T::Inner: FlatMap<B, Target<B> = <T::Target<B> as MonadTrans>::Inner> + Clone,
+    F: FnMut(T::Param) -> <T::Target<B> as MonadTrans>::Inner,
+{
+    let lhs = <T::Target<B>>::lift(ga.clone().flat_map(&mut f));
+    let rhs = T::lift(ga).flat_map(|a| <T::Target<B>>::lift(f(a)));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
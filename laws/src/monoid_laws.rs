@@ -22,3 +22,11 @@ where
 {
     IsEq::equal_under_law(a.clone() == A::empty(), a.is_empty())
 }
+
+pub fn combine_all_consistency<A>(xs: Vec<A>) -> IsEq<A>
+where
+    A: Monoid + Clone,
+{
+    let rhs = xs.iter().cloned().fold(A::empty(), A::combine);
+    IsEq::equal_under_law(Monoid::combine_all(xs), rhs)
+}
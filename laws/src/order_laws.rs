@@ -0,0 +1,45 @@
+use core::cmp::Ordering;
+
+use rust2fun::eq::Eq;
+use rust2fun::order::Order;
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn eqv_reflexivity<A: Eq>(a: A) -> IsEq<bool> {
+    IsEq::equal_under_law(true, a.eqv(&a))
+}
+
+pub fn eqv_symmetry<A: Eq>(a: A, b: A) -> IsEq<bool> {
+    IsEq::equal_under_law(a.eqv(&b), b.eqv(&a))
+}
+
+pub fn eqv_transitivity<A: Eq>(a: A, b: A, c: A) -> IsEq<bool> {
+    IsEq::equal_under_law(true, !(a.eqv(&b) && b.eqv(&c)) || a.eqv(&c))
+}
+
+pub fn partial_order_antisymmetry<A: PartialOrder>(a: A, b: A) -> IsEq<bool> {
+    IsEq::equal_under_law(true, !(a.lteqv(&b) && b.lteqv(&a)) || a.eqv(&b))
+}
+
+pub fn partial_order_transitivity<A: PartialOrder>(a: A, b: A, c: A) -> IsEq<bool> {
+    IsEq::equal_under_law(true, !(a.lteqv(&b) && b.lteqv(&c)) || a.lteqv(&c))
+}
+
+pub fn order_totality<A: Order>(a: A, b: A) -> IsEq<bool> {
+    IsEq::equal_under_law(true, a.lteqv(&b) || b.lteqv(&a))
+}
+
+pub fn order_compare_consistency<A: Order>(a: A, b: A) -> IsEq<Option<Ordering>> {
+    IsEq::equal_under_law(a.partial_compare(&b), Some(a.compare(&b)))
+}
+
+pub fn order_eqv_consistency<A: Order>(a: A, b: A) -> IsEq<bool> {
+    IsEq::equal_under_law(a.eqv(&b), a.compare(&b) == Ordering::Equal)
+}
+
+pub fn order_min_max<A: Order + Clone>(a: A, b: A) -> IsEq<bool> {
+    let min = Order::min(a.clone(), b.clone());
+    let max = Order::max(a, b);
+    IsEq::equal_under_law(true, min.lteqv(&max))
+}
@@ -0,0 +1,37 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn reduce_left_consistent_with_iter<FA, F>(fa: FA, mut f: F) -> IsEq<FA::Param>
+where
+    FA: Reducible + IntoIterator<Item = <FA as Higher>::Param> + Clone,
+    F: FnMut(FA::Param, FA::Param) -> FA::Param,
+{
+    let lhs = fa.clone().reduce_left(&mut f);
+    let rhs = fa.into_iter().reduce(f).expect("Reducible cannot be empty");
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn reduce_consistent_with_reduce_left<FA>(fa: FA) -> IsEq<FA::Param>
+where
+    FA: Reducible + Clone,
+    FA::Param: Semigroup,
+{
+    let lhs = fa.clone().reduce();
+    let rhs = fa.reduce_left(Semigroup::combine);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn reduce_map_consistent_with_reduce_left_to<FA, B, F>(fa: FA, mut f: F) -> IsEq<B>
+where
+    FA: Reducible + Clone,
+    B: Semigroup,
+    F: FnMut(FA::Param) -> B + Clone,
+{
+    let lhs = fa.clone().reduce_map(f.clone());
+    let rhs = fa.reduce_left_to(f.clone(), |b, a| b.combine(f(a)));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
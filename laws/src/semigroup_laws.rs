@@ -25,3 +25,26 @@ where
 {
     IsEq::equal_under_law(a.clone().combine(a.clone()), a.combine_n(1))
 }
+
+pub fn repeat_n<A>(a: A, n: u32) -> IsEq<A>
+where
+    A: Semigroup + Clone,
+{
+    let rhs = (0..n).fold(a.clone(), |acc, _| acc.combine(a.clone()));
+    IsEq::equal_under_law(a.combine_n(n), rhs)
+}
+
+pub fn combine_all_option_consistency<A>(xs: Vec<A>) -> IsEq<Option<A>>
+where
+    A: Semigroup + Clone,
+{
+    let rhs = xs.iter().cloned().reduce(A::combine);
+    IsEq::equal_under_law(Semigroup::combine_all_option(xs), rhs)
+}
+
+pub fn semigroup_commutativity<A>(a: A, b: A) -> IsEq<A>
+where
+    A: CommutativeSemigroup + Clone,
+{
+    IsEq::equal_under_law(a.clone().combine(b.clone()), b.combine(a))
+}
@@ -2,11 +2,13 @@ use rust2fun::prelude::*;
 
 use crate::is_eq::IsEq;
 
+type Product3<FA, B, C> = <FA as Higher>::Target<(<FA as Higher>::Param, B, C)>;
+
 pub fn semigroupal_associativity<FA, B, C>(
     fa: FA,
     fb: FA::Target<B>,
     fc: FA::Target<C>,
-) -> IsEq<FA::Target<(FA::Param, B, C)>>
+) -> IsEq<Product3<FA, B, C>>
 where
     FA: Semigroupal<B> + Semigroupal<(B, C)> + Clone,
     FA::Target<(<FA as Higher>::Param, B)>: Semigroupal<C>,
@@ -30,3 +32,19 @@ where
 
     IsEq::equal_under_law(lhs, rhs)
 }
+
+pub fn product_ref_consistency<FA, B>(fa: FA, fb: FA::Target<B>) -> IsEq<FA::Target<(FA::Param, B)>>
+where
+    FA: Semigroupal<B> + ProductRef<B>,
+    FA::Param: Clone,
+    B: Clone,
+    for<'a> FA::Target<(&'a FA::Param, &'a B)>: Functor<(FA::Param, B)>,
+{
+    let lhs = fa
+        .product_ref(&fb)
+        .map(|(a, b)| (a.clone(), b.clone()))
+        .unsafe_cast();
+    let rhs = fa.product(fb);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
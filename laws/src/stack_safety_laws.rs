@@ -0,0 +1,34 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn flat_map_stack_safety<FA>(a: FA::Param, n: usize) -> IsEq<FA>
+where
+    FA: Monad<<FA as Higher>::Param, Target<<FA as Higher>::Param> = FA>,
+    FA::Param: Clone,
+{
+    let lhs = (0..n).fold(FA::pure(a.clone()), |fa, _| fa.flat_map(FA::pure));
+    let rhs = FA::pure(a);
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn map_stack_safety<FA>(fa: FA, n: usize) -> IsEq<FA>
+where
+    FA: Functor<<FA as Higher>::Param, Target<<FA as Higher>::Param> = FA> + Clone,
+{
+    let lhs = (0..n).fold(fa.clone(), |fa, _| fa.map(id));
+    let rhs = fa;
+
+    IsEq::equal_under_law(lhs, rhs)
+}
+
+pub fn combine_stack_safety<A>(a: A, n: usize) -> IsEq<A>
+where
+    A: Semigroup + Clone,
+{
+    let lhs = (0..n).fold(a.clone(), |acc, _| acc.combine(a.clone()));
+    let rhs = (0..n).fold(a.clone(), |acc, _| a.clone().combine(acc));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
@@ -0,0 +1,159 @@
+//! Reusable [proptest] strategies for the data types of rust2fun.
+//!
+//! Besides the strategies of [rust2fun::arbitrary], this module provides strategies for the
+//! collections which can't be generated directly from an [Arbitrary] instance: chains with a
+//! nested structure, non-empty sets and maps, and maps with keys drawn from a small range, so
+//! that generated maps often share keys.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use rust2fun::prelude::*;
+//! use rust2fun_laws::semigroup_laws::semigroup_associativity;
+//! use rust2fun_laws::strategies::{ne_chain, small_hash_map};
+//!
+//! proptest! {
+//!     fn test_ne_chain(xs in ne_chain(any::<u8>(), 1..4)) {
+//!         prop_assert!(xs.iter().next().is_some());
+//!     }
+//!
+//!     fn test_hash_map(
+//!         a in small_hash_map(any::<String>()),
+//!         b in small_hash_map(any::<String>()),
+//!         c in small_hash_map(any::<String>()),
+//!     ) {
+//!         prop_assert!(semigroup_associativity(a, b, c).holds());
+//!     }
+//! }
+//! # test_ne_chain();
+//! # test_hash_map();
+//! ```
+//!
+//! [Arbitrary]: proptest::arbitrary::Arbitrary
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use proptest::collection::{btree_map, hash_map, hash_set, vec, SizeRange};
+use proptest::strategy::Strategy;
+use rust2fun::prelude::*;
+
+#[cfg(feature = "either")]
+use either::Either;
+
+pub use rust2fun::arbitrary::{ior, ne_vec, validated};
+
+/// Upper bound (exclusive) of the keys generated by [small_key].
+pub const SMALL_KEYS: u8 = 8;
+
+/// Creates a strategy generating keys in `0..SMALL_KEYS`.
+pub fn small_key() -> impl Strategy<Value = u8> {
+    0..SMALL_KEYS
+}
+
+fn segments<S: Strategy>(
+    element: S,
+    min: usize,
+    size: SizeRange,
+) -> impl Strategy<Value = Vec<Vec<S::Value>>> {
+    vec(vec(element, min..4), size)
+}
+
+/// Creates a strategy generating [Chain] values by combining a number of segments within `size`,
+/// each of up to three elements drawn from `element`, so that the chains have a nested
+/// structure.
+pub fn chain<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Chain<S::Value>> {
+    segments(element, 0, size.into()).prop_map(|xss| {
+        xss.into_iter()
+            .map(Chain::from)
+            .fold(Chain::new(), Semigroup::combine)
+    })
+}
+
+/// Creates a strategy generating [NEChain] values by combining a number of segments within
+/// `size`, each of one to three elements drawn from `element`. A lower bound of zero is raised
+/// to one, as a [NEChain] is never empty.
+pub fn ne_chain<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = NEChain<S::Value>> {
+    let (start, end) = size.into().start_end_incl();
+    segments(element, 1, (start.max(1)..=end.max(1)).into()).prop_map(|xss| {
+        xss.into_iter()
+            .map(|xs| NEChain::from(NEVec::from_vec(xs).unwrap()))
+            .reduce(Semigroup::combine)
+            .unwrap()
+    })
+}
+
+/// Creates a strategy generating [NESet] values with elements drawn from `element` and a size
+/// within `size`. A lower bound of zero is raised to one, as a [NESet] is never empty.
+pub fn ne_set<S>(element: S, size: impl Into<SizeRange>) -> impl Strategy<Value = NESet<S::Value>>
+where
+    S: Strategy,
+    S::Value: Hash + Eq,
+{
+    let (start, end) = size.into().start_end_incl();
+    hash_set(element, start.max(1)..=end.max(1)).prop_map(|s| NESet::from_set(s).unwrap())
+}
+
+/// Creates a strategy generating [NEMap] values with keys drawn from `key`, values drawn from
+/// `value` and a size within `size`. A lower bound of zero is raised to one, as a [NEMap] is
+/// never empty.
+pub fn ne_map<K, V>(
+    key: K,
+    value: V,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = NEMap<K::Value, V::Value>>
+where
+    K: Strategy,
+    V: Strategy,
+    K::Value: Hash + Eq,
+{
+    let (start, end) = size.into().start_end_incl();
+    hash_map(key, value, start.max(1)..=end.max(1)).prop_map(|m| NEMap::from_map(m).unwrap())
+}
+
+/// Creates a strategy generating [HashMap] values with keys drawn from [small_key] and values
+/// drawn from `value`.
+pub fn small_hash_map<V: Strategy>(value: V) -> impl Strategy<Value = HashMap<u8, V::Value>> {
+    hash_map(small_key(), value, 0..=SMALL_KEYS as usize / 2)
+}
+
+/// Creates a strategy generating [BTreeMap] values with keys drawn from [small_key] and values
+/// drawn from `value`.
+pub fn small_btree_map<V: Strategy>(value: V) -> impl Strategy<Value = BTreeMap<u8, V::Value>> {
+    btree_map(small_key(), value, 0..=SMALL_KEYS as usize / 2)
+}
+
+/// Creates a strategy generating [Either] values, with the left values drawn from `left` and the
+/// right values drawn from `right`.
+#[cfg(feature = "either")]
+pub fn either<A, B>(
+    left: impl Strategy<Value = A>,
+    right: impl Strategy<Value = B>,
+) -> impl Strategy<Value = Either<A, B>>
+where
+    A: Debug,
+    B: Debug,
+{
+    proptest::prop_oneof![left.prop_map(Either::Left), right.prop_map(Either::Right)]
+}
+
+/// Creates a strategy generating [Result] values, with the successful values drawn from `ok` and
+/// the errors drawn from `err`.
+pub fn result<T, E>(
+    ok: impl Strategy<Value = T>,
+    err: impl Strategy<Value = E>,
+) -> impl Strategy<Value = Result<T, E>>
+where
+    T: Debug,
+    E: Debug,
+{
+    proptest::prop_oneof![ok.prop_map(Ok), err.prop_map(Err)]
+}
@@ -0,0 +1,14 @@
+use rust2fun::prelude::*;
+
+use crate::is_eq::IsEq;
+
+pub fn traverse_identity<FA, B, F>(fa: FA, mut f: F) -> IsEq<Option<FA::Target<B>>>
+where
+    FA: Traverse<Option<B>> + Functor<B> + Clone,
+    F: FnMut(FA::Param) -> B,
+{
+    let lhs = fa.clone().traverse(|a| Some(f(a)));
+    let rhs = Some(fa.map(f));
+
+    IsEq::equal_under_law(lhs, rhs)
+}
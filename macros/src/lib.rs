@@ -1,11 +1,18 @@
+use std::ops::RangeInclusive;
+
 use proc_macro::{TokenStream, TokenTree};
 
 use proc_macro2::Ident;
 use quote::{format_ident, quote};
+use syn::visit_mut::VisitMut;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericParam, Member, Path, Type};
 
 #[proc_macro]
 pub fn curry_arity(input: TokenStream) -> TokenStream {
-    let arity = parse_arity(input);
+    expand_arities(input, curry)
+}
+
+fn curry(arity: u32) -> proc_macro2::TokenStream {
     let fn_name = format_ident!("curry{}", arity);
     let fn_args = (0..arity)
         .map(|i| format_ident!("x{}", i))
@@ -22,12 +29,15 @@ pub fn curry_arity(input: TokenStream) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    expanded
 }
 
 #[proc_macro]
 pub fn constant_arity(input: TokenStream) -> TokenStream {
-    let arity = parse_arity(input);
+    expand_arities(input, constant)
+}
+
+fn constant(arity: u32) -> proc_macro2::TokenStream {
     let fn_name = format_ident!("constant{}", arity);
     let fn_args = (0..arity).map(|_| quote!(_));
     let msg_args = vec!["_"; arity as usize].join(", ");
@@ -45,12 +55,15 @@ pub fn constant_arity(input: TokenStream) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    expanded
 }
 
 #[proc_macro]
 pub fn tuple_arity(input: TokenStream) -> TokenStream {
-    let arity = parse_arity(input);
+    expand_arities(input, tuple)
+}
+
+fn tuple(arity: u32) -> proc_macro2::TokenStream {
     let fn_name = format_ident!("tuple{}", arity);
     let types = ('A'..='Z').take(arity as usize);
     let type_args = types.clone().map(|t| format_ident!("{}", t));
@@ -70,12 +83,15 @@ pub fn tuple_arity(input: TokenStream) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    expanded
 }
 
 #[proc_macro]
 pub fn noop_arity(input: TokenStream) -> TokenStream {
-    let arity = parse_arity(input);
+    expand_arities(input, noop)
+}
+
+fn noop(arity: u32) -> proc_macro2::TokenStream {
     let fn_name = format_ident!("noop{}", arity);
     let type_args = ('A'..='Z')
         .take(arity as usize)
@@ -89,12 +105,15 @@ pub fn noop_arity(input: TokenStream) -> TokenStream {
         pub fn #fn_name< #( #type_args ),* >( #( #fn_args ),*) {}
     };
 
-    TokenStream::from(expanded)
+    expanded
 }
 
 #[proc_macro]
 pub fn ap_n(input: TokenStream) -> TokenStream {
-    let arity = parse_arity(input);
+    expand_arities(input, ap)
+}
+
+fn ap(arity: u32) -> proc_macro2::TokenStream {
     let fn_name = format_ident!("ap{}", arity);
     let msg = format!("Is a version of [Apply::ap] for a function of {arity} arguments.");
 
@@ -116,7 +135,7 @@ pub fn ap_n(input: TokenStream) -> TokenStream {
         .map(|t| format_ident!("{}", t.to_lowercase().next().unwrap()));
 
     let products = types.clone().skip(1).map(|t| {
-        let a = format_ident!("f{}", t.to_lowercase().next().unwrap());
+        let a = fn_arg_name(t);
         quote!(product(#a))
     });
     let map_pattern = types
@@ -134,16 +153,19 @@ pub fn ap_n(input: TokenStream) -> TokenStream {
             #( #constraints ),*
         {
             let product = fa. #( #products ).*;
-            self.and_then(|mut func| product.clone().map(| #map_pattern | func( #( #f_args ),* )))
+            self.and_then_with(product, |mut func, product| product.map(| #map_pattern | func( #( #f_args ),* )))
         }
     };
 
-    TokenStream::from(expanded)
+    expanded
 }
 
 #[proc_macro]
 pub fn map_n(input: TokenStream) -> TokenStream {
-    let arity = parse_arity(input);
+    expand_arities(input, map)
+}
+
+fn map(arity: u32) -> proc_macro2::TokenStream {
     let fn_name = format_ident!("map{}", arity);
     let msg = format!("Is a version of [Apply::map2] for a function of {arity} arguments.");
 
@@ -165,7 +187,7 @@ pub fn map_n(input: TokenStream) -> TokenStream {
         .map(|t| format_ident!("{}", t.to_lowercase().next().unwrap()));
 
     let products = types.clone().map(|t| {
-        let a = format_ident!("f{}", t.to_lowercase().next().unwrap());
+        let a = fn_arg_name(t);
         quote!(product(#a))
     });
     let map_pattern = types
@@ -185,11 +207,448 @@ pub fn map_n(input: TokenStream) -> TokenStream {
         }
     };
 
-    TokenStream::from(expanded)
+    expanded
+}
+
+/// Derive a `validate` constructor accumulating the errors of per-field validators.
+///
+/// The error type is given with `#[validate(error = Type)]` on the struct, and each field may be
+/// validated with `#[validate(with = path)]`, where `path` is a function taking the field value
+/// and returning a `ValidatedNev<FieldType, Type>`. Fields without a validator are always valid.
+/// The generated constructor takes all the fields in declaration order and returns
+/// `ValidatedNev<Self, Type>`.
+///
+/// See the `rust2fun::data::validated` module for an example.
+#[proc_macro_derive(Validate, attributes(validate))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_validate(input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+fn expand_validate(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut error: Option<Type> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                error = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `error = Type`"))
+            }
+        })?;
+    }
+    let error = error.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "missing `#[validate(error = Type)]` attribute",
+        )
+    })?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`Validate` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut args = Vec::new();
+    let mut validations = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut validator: Option<Path> = None;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("with") {
+                    validator = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `with = path`"))
+                }
+            })?;
+        }
+
+        let arg = match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field{}", i),
+        };
+        let ty = &field.ty;
+        let validated = match validator {
+            Some(validator) => quote!(#validator(#arg)),
+            None => quote!(::rust2fun::data::Validated::Valid(#arg)),
+        };
+        validations.push(quote! {
+            let #arg: ::rust2fun::data::ValidatedNev<#ty, #error> = #validated;
+        });
+        args.push((arg, ty));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fn_args = args.iter().map(|(arg, ty)| quote!(#arg: #ty));
+    let names = args.iter().map(|(arg, _)| arg);
+    let construct = match fields {
+        Fields::Named(_) => quote!(Self { #( #names ),* }),
+        Fields::Unnamed(_) => quote!(Self( #( #names ),* )),
+        Fields::Unit => quote!(Self),
+    };
+    let body = match args.split_first() {
+        None => quote!(::rust2fun::data::Validated::Valid(#construct)),
+        Some(((first, _), rest)) => {
+            let rest = rest.iter().map(|(arg, _)| arg);
+            let pattern = args
+                .iter()
+                .skip(1)
+                .fold(quote!(#first), |acc, (arg, _)| quote!((#acc, #arg)));
+            quote! {
+                #first #( .product(#rest) )* .map(| #pattern | #construct)
+            }
+        }
+    };
+    let msg =
+        format!("Construct a `{name}` validating its fields and accumulating all the errors.");
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #msg]
+            #vis fn validate( #( #fn_args ),* ) -> ::rust2fun::data::ValidatedNev<Self, #error> {
+                use ::rust2fun::functor::Functor as _;
+                use ::rust2fun::semigroupal::Semigroupal as _;
+
+                #( #validations )*
+                #body
+            }
+        }
+    })
+}
+
+const NEWTYPE_TRAITS: [&str; 8] = [
+    "Invariant",
+    "Functor",
+    "Semigroupal",
+    "Apply",
+    "FlatMap",
+    "Pure",
+    "Semigroup",
+    "Monoid",
+];
+
+/// Derive type class instances for a single-field wrapper by delegating to the wrapped type.
+///
+/// The last type parameter of the wrapper is its [Higher] parameter and it must not be bounded.
+/// `Higher` is always implemented. By default `Invariant`, `Functor`, `Semigroupal`, `Apply`,
+/// `FlatMap`, `Pure`, `Semigroup` and `Monoid` are implemented as well, each one only when the
+/// wrapped type implements it. The list can be restricted with
+/// `#[higher_newtype(Functor, FlatMap, ...)]`, e.g. to provide a different `Apply` by hand.
+///
+/// See the `rust2fun::higher` module for an example.
+///
+/// [Higher]: https://docs.rs/rust2fun/latest/rust2fun/higher/trait.Higher.html
+#[proc_macro_derive(HigherNewtype, attributes(higher_newtype))]
+pub fn derive_higher_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_higher_newtype(input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+fn expand_higher_newtype(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut only: Option<Vec<Ident>> = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("higher_newtype"))
+    {
+        attr.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(ident) if NEWTYPE_TRAITS.iter().any(|t| ident == t) => {
+                only.get_or_insert_with(Vec::new).push(ident.clone());
+                Ok(())
+            }
+            _ => Err(meta.error(format!("expected one of {}", NEWTYPE_TRAITS.join(", ")))),
+        })?;
+    }
+    let enabled = |name: &str| only.as_ref().is_none_or(|o| o.iter().any(|i| i == name));
+
+    let name = &input.ident;
+    let field = match &input.data {
+        Data::Struct(data) if data.fields.len() == 1 => data.fields.iter().next().unwrap(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`HigherNewtype` can only be derived for structs with a single field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    let wrap = |x: proc_macro2::TokenStream| match &field.ident {
+        Some(ident) => quote!(#name { #ident: #x }),
+        None => quote!(#name(#x)),
+    };
+
+    let param = match input.generics.type_params().last() {
+        Some(param) => param.ident.clone(),
+        None => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "`HigherNewtype` requires a type parameter",
+            ))
+        }
+    };
+    let others = input
+        .generics
+        .params
+        .iter()
+        .filter(|p| !matches!(p, GenericParam::Type(t) if t.ident == param))
+        .collect::<Vec<_>>();
+    let predicates = input
+        .generics
+        .where_clause
+        .as_ref()
+        .map(|w| w.predicates.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let wrapper = |ty: proc_macro2::TokenStream| {
+        let args = input.generics.params.iter().map(|p| match p {
+            GenericParam::Lifetime(l) => {
+                let l = &l.lifetime;
+                quote!(#l)
+            }
+            GenericParam::Type(t) if t.ident == param => ty.clone(),
+            GenericParam::Type(t) => {
+                let t = &t.ident;
+                quote!(#t)
+            }
+            GenericParam::Const(c) => {
+                let c = &c.ident;
+                quote!(#c)
+            }
+        });
+        quote!(#name< #( #args ),* >)
+    };
+    let inner = |ty: proc_macro2::TokenStream| {
+        let mut inner = field.ty.clone();
+        ReplaceParam {
+            param: &param,
+            ty: syn::parse2(ty).expect("a valid type"),
+        }
+        .visit_type_mut(&mut inner);
+        inner
+    };
+
+    let p = quote!(#param);
+    let self_ty = wrapper(p.clone());
+    let inner_p = inner(p.clone());
+    let b = quote!(__B);
+    let wrapper_b = wrapper(b.clone());
+    let inner_b = inner(b.clone());
+    let a = quote!(__A);
+    let wrapper_a = wrapper(a.clone());
+    let inner_a = inner(a.clone());
+    let pb = quote!((#param, __B));
+    let wrapper_pb = wrapper(pb.clone());
+    let inner_pb = inner(pb);
+    let u = quote!(__U);
+    let wrapper_u = wrapper(u);
+    let this = quote!(self.#member);
+
+    let mut impls = vec![quote! {
+        impl< #( #others, )* #param > ::rust2fun::higher::Higher for #self_ty
+        where
+            #( #predicates, )*
+        {
+            type Param = #param;
+            type Target<__U> = #wrapper_u;
+        }
+    }];
+
+    if enabled("Invariant") || enabled("Functor") {
+        let body = wrap(quote!(::rust2fun::invariant::Invariant::imap(#this, f, g)));
+        impls.push(quote! {
+            impl< #( #others, )* #param, __B > ::rust2fun::invariant::Invariant<__B> for #self_ty
+            where
+                #inner_p: ::rust2fun::invariant::Invariant<__B, Param = #param, Target<__B> = #inner_b>,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn imap<__F, __G>(self, f: __F, g: __G) -> #wrapper_b
+                where
+                    __F: FnMut(#param) -> __B,
+                    __G: FnMut(__B) -> #param,
+                {
+                    #body
+                }
+            }
+        });
+    }
+
+    if enabled("Functor") {
+        let body = wrap(quote!(::rust2fun::functor::Functor::map(#this, f)));
+        impls.push(quote! {
+            impl< #( #others, )* #param, __B > ::rust2fun::functor::Functor<__B> for #self_ty
+            where
+                #inner_p: ::rust2fun::functor::Functor<__B, Param = #param, Target<__B> = #inner_b>,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn map(self, f: impl FnMut(#param) -> __B) -> #wrapper_b {
+                    #body
+                }
+            }
+        });
+    }
+
+    if enabled("Semigroupal") {
+        let body = wrap(quote!(::rust2fun::semigroupal::Semigroupal::product(#this, fb.#member)));
+        impls.push(quote! {
+            impl< #( #others, )* #param, __B > ::rust2fun::semigroupal::Semigroupal<__B> for #self_ty
+            where
+                #inner_p: ::rust2fun::semigroupal::Semigroupal<
+                    __B,
+                    Param = #param,
+                    Target<__B> = #inner_b,
+                    Target<(#param, __B)> = #inner_pb,
+                >,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn product(self, fb: #wrapper_b) -> #wrapper_pb {
+                    #body
+                }
+            }
+        });
+    }
+
+    if enabled("Apply") {
+        let body = wrap(quote!(::rust2fun::apply::Apply::ap(#this, fa.#member)));
+        impls.push(quote! {
+            impl< #( #others, )* #param, __A, __B > ::rust2fun::apply::Apply<__A, __B> for #self_ty
+            where
+                #inner_p: ::rust2fun::apply::Apply<
+                    __A,
+                    __B,
+                    Param = #param,
+                    Target<__A> = #inner_a,
+                    Target<__B> = #inner_b,
+                >,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn ap(self, fa: #wrapper_a) -> #wrapper_b
+                where
+                    Self::Param: FnMut(__A) -> __B,
+                {
+                    #body
+                }
+            }
+        });
+    }
+
+    if enabled("FlatMap") {
+        let unwrap = quote!(f(a).#member);
+        let body = wrap(quote!(::rust2fun::flatmap::FlatMap::flat_map(#this, |a| #unwrap)));
+        impls.push(quote! {
+            impl< #( #others, )* #param, __B > ::rust2fun::flatmap::FlatMap<__B> for #self_ty
+            where
+                #inner_p: ::rust2fun::flatmap::FlatMap<__B, Param = #param, Target<__B> = #inner_b>,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn flat_map<__F>(self, mut f: __F) -> #wrapper_b
+                where
+                    __F: FnMut(#param) -> #wrapper_b,
+                {
+                    #body
+                }
+            }
+        });
+    }
+
+    if enabled("Pure") {
+        let body = wrap(quote!(::rust2fun::pure::Pure::pure(x)));
+        impls.push(quote! {
+            impl< #( #others, )* #param > ::rust2fun::pure::Pure for #self_ty
+            where
+                #inner_p: ::rust2fun::pure::Pure + ::rust2fun::higher::Higher<Param = #param>,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn pure(x: #param) -> Self {
+                    #body
+                }
+            }
+        });
+    }
+
+    if enabled("Semigroup") {
+        let body = wrap(quote!(::rust2fun::semigroup::Semigroup::combine(#this, other.#member)));
+        impls.push(quote! {
+            impl< #( #others, )* #param > ::rust2fun::semigroup::Semigroup for #self_ty
+            where
+                #inner_p: ::rust2fun::semigroup::Semigroup,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn combine(self, other: Self) -> Self {
+                    #body
+                }
+            }
+        });
+    }
+
+    if enabled("Monoid") {
+        let body = wrap(quote!(::rust2fun::monoid::Monoid::empty()));
+        impls.push(quote! {
+            impl< #( #others, )* #param > ::rust2fun::monoid::Monoid for #self_ty
+            where
+                #inner_p: ::rust2fun::monoid::Monoid,
+                #( #predicates, )*
+            {
+                #[inline]
+                fn empty() -> Self {
+                    #body
+                }
+            }
+        });
+    }
+
+    Ok(quote!( #( #impls )* ))
+}
+
+/// Replaces a type parameter with a type.
+struct ReplaceParam<'a> {
+    param: &'a Ident,
+    ty: Type,
+}
+
+impl VisitMut for ReplaceParam<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        match ty {
+            Type::Path(path) if path.qself.is_none() && path.path.is_ident(self.param) => {
+                *ty = self.ty.clone();
+            }
+            _ => syn::visit_mut::visit_type_mut(self, ty),
+        }
+    }
+}
+
+fn fn_arg_name(t: char) -> Ident {
+    match t.to_lowercase().next().unwrap() {
+        // `fn` is a keyword
+        'n' => format_ident!("fn_"),
+        t => format_ident!("f{}", t),
+    }
 }
 
 fn to_fn_arg(t: char) -> proc_macro2::TokenStream {
-    let a = format_ident!("f{}", t.to_lowercase().next().unwrap());
+    let a = fn_arg_name(t);
     let t = format_ident!("{}", t);
     quote!(#a: Self::Target<#t>)
 }
@@ -208,12 +667,31 @@ fn generate_semigroupal_constraint(
     (next, acc)
 }
 
-fn parse_arity(input: TokenStream) -> u32 {
-    match input.into_iter().next().expect("arity is required") {
+fn expand_arities(input: TokenStream, f: fn(u32) -> proc_macro2::TokenStream) -> TokenStream {
+    parse_arities(input)
+        .map(f)
+        .collect::<proc_macro2::TokenStream>()
+        .into()
+}
+
+/// Parse either a single arity `N` or an inclusive range of arities `N..=M`.
+fn parse_arities(input: TokenStream) -> RangeInclusive<u32> {
+    let parse = |x: Option<TokenTree>| match x.expect("arity is required") {
         TokenTree::Literal(x) => x
             .to_string()
             .parse::<u32>()
             .expect("arity must be a number"),
         _ => panic!("arity must be a literal"),
+    };
+
+    let mut tokens = input.into_iter();
+    let start = parse(tokens.next());
+    let range = tokens.map(|t| t.to_string()).collect::<Vec<_>>();
+    match range.as_slice() {
+        [] => start..=start,
+        [a, b, c, end] if a == "." && b == "." && c == "=" => {
+            start..=end.parse::<u32>().expect("arity must be a number")
+        }
+        _ => panic!("arities must be given as a literal or an inclusive range"),
     }
 }
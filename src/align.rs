@@ -0,0 +1,153 @@
+//! Align.
+//!
+//! Align allows to zip two structures of possibly different shapes without losing any elements,
+//! using [Ior] to represent positions present in only one of them. This is something
+//! [Semigroupal::product] cannot express, because it only pairs matching positions.
+//!
+//! [Semigroupal::product]: crate::semigroupal::Semigroupal::product
+
+use crate::data::ior::Ior;
+use crate::higher::Higher;
+
+/// Align two structures, keeping the elements that are present in only one of them.
+/// See [the module level documentation](self) for more.
+pub trait Align<B>: Higher {
+    /// Align two structures and combine the elements with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = vec![1, 2, 3];
+    /// let ys = vec![10, 20];
+    /// let sum = |x: Ior<i32, i32>| match x {
+    ///     Ior::Left(a) | Ior::Right(a) => a,
+    ///     Ior::Both(a, b) => a + b,
+    /// };
+    /// assert_eq!(vec![11, 22, 3], xs.align_with(ys, sum));
+    /// ```
+    fn align_with<C, F>(self, fb: Self::Target<B>, f: F) -> Self::Target<C>
+    where
+        F: FnMut(Ior<Self::Param, B>) -> C;
+
+    /// Align two structures pairing the elements into [Ior] values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(Ior::Both(1, "a")), Some(1).align(Some("a")));
+    /// assert_eq!(Some(Ior::Left(1)), Some(1).align(None::<&str>));
+    /// assert_eq!(None, None::<i32>.align(None::<&str>));
+    /// ```
+    #[inline]
+    fn align(self, fb: Self::Target<B>) -> Self::Target<Ior<Self::Param, B>>
+    where
+        Self: Sized,
+    {
+        self.align_with(fb, |x| x)
+    }
+
+    /// Align two structures pairing the elements into tuples of [Option]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = vec![1, 2];
+    /// let ys = vec!["a"];
+    /// assert_eq!(vec![(Some(1), Some("a")), (Some(2), None)], xs.pad_zip(ys));
+    /// ```
+    #[inline]
+    fn pad_zip(self, fb: Self::Target<B>) -> Self::Target<(Option<Self::Param>, Option<B>)>
+    where
+        Self: Sized,
+    {
+        self.align_with(fb, Ior::pad)
+    }
+}
+
+/// Macro to implement [Align] for types with [Iterator] support.
+#[macro_export]
+macro_rules! align_iter {
+    ($name:ident) => {
+        impl<A, B> $crate::align::Align<B> for $name<A> {
+            #[inline]
+            fn align_with<C, F>(self, fb: Self::Target<B>, f: F) -> Self::Target<C>
+            where
+                F: FnMut($crate::data::ior::Ior<A, B>) -> C,
+            {
+                let mut xs = self.into_iter();
+                let mut ys = fb.into_iter();
+                core::iter::from_fn(|| $crate::data::ior::Ior::from_options(xs.next(), ys.next()))
+                    .map(f)
+                    .collect()
+            }
+        }
+    };
+}
+
+impl<A, B> Align<B> for Option<A> {
+    #[inline]
+    fn align_with<C, F>(self, fb: Option<B>, mut f: F) -> Option<C>
+    where
+        F: FnMut(Ior<A, B>) -> C,
+    {
+        Ior::from_options(self, fb).map(&mut f)
+    }
+}
+
+if_std! {
+    use std::collections::*;
+    use std::hash::Hash;
+    use std::vec::Vec;
+
+    align_iter!(Vec);
+    align_iter!(LinkedList);
+    align_iter!(VecDeque);
+
+    impl<A, B, K: Eq + Hash> Align<B> for HashMap<K, A> {
+        #[inline]
+        fn align_with<C, F>(self, mut fb: HashMap<K, B>, mut f: F) -> HashMap<K, C>
+        where
+            F: FnMut(Ior<A, B>) -> C,
+        {
+            let mut result: HashMap<K, C> = self
+                .into_iter()
+                .map(|(k, a)| {
+                    let ior = match fb.remove(&k) {
+                        Some(b) => Ior::Both(a, b),
+                        None => Ior::Left(a),
+                    };
+                    (k, f(ior))
+                })
+                .collect();
+            result.extend(fb.into_iter().map(|(k, b)| (k, f(Ior::Right(b)))));
+            result
+        }
+    }
+
+    impl<A, B, K: Ord> Align<B> for BTreeMap<K, A> {
+        #[inline]
+        fn align_with<C, F>(self, mut fb: BTreeMap<K, B>, mut f: F) -> BTreeMap<K, C>
+        where
+            F: FnMut(Ior<A, B>) -> C,
+        {
+            let mut result: BTreeMap<K, C> = self
+                .into_iter()
+                .map(|(k, a)| {
+                    let ior = match fb.remove(&k) {
+                        Some(b) => Ior::Both(a, b),
+                        None => Ior::Left(a),
+                    };
+                    (k, f(ior))
+                })
+                .collect();
+            result.extend(fb.into_iter().map(|(k, b)| (k, f(Ior::Right(b)))));
+            result
+        }
+    }
+}
@@ -1,6 +1,13 @@
 //! AndThen.
 
 use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 use crate::higher::Higher;
 
@@ -16,6 +23,28 @@ pub trait AndThen<B>: Higher {
     fn and_then<F>(self, f: F) -> Self::Target<B>
     where
         F: FnMut(Self::Param) -> Self::Target<B>;
+
+    /// Like [and_then] but also passes a value of `x` to every call of `f`. The default
+    /// implementation clones `x` for every call, collections move it into the last call instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let actual = vec![1, 2].and_then_with(vec![10, 20], |x, ys| ys.map(|y| x + y));
+    /// assert_eq!(vec![11, 21, 12, 22], actual);
+    /// ```
+    ///
+    /// [and_then]: AndThen::and_then
+    #[inline]
+    fn and_then_with<X: Clone, F>(self, x: X, mut f: F) -> Self::Target<B>
+    where
+        F: FnMut(Self::Param, X) -> Self::Target<B>,
+        Self: Sized,
+    {
+        self.and_then(|a| f(a, x.clone()))
+    }
 }
 
 // TODO. Refactor this when specialization is stable.
@@ -46,6 +75,47 @@ macro_rules! and_then_flat_map {
     };
 }
 
+/// Macro to implement [AndThen] for types with [Iterator] support. The last element takes the
+/// value passed to [AndThen::and_then_with] instead of cloning it.
+#[macro_export]
+macro_rules! and_then_iter {
+    ($name:ident) => {
+        impl<A, B> $crate::and_then::AndThen<B> for $name<A> {
+            $crate::and_then_iter!(@body $name);
+        }
+    };
+    ($name:ident, $ct:tt $(+ $dt:tt )*) => {
+        impl<A, B: $ct $(+ $dt )*> $crate::and_then::AndThen<B> for $name<A> {
+            $crate::and_then_iter!(@body $name);
+        }
+    };
+    (@body $name:ident) => {
+        #[inline]
+        fn and_then<F>(self, f: F) -> Self::Target<B>
+        where
+            F: FnMut(A) -> Self::Target<B>,
+        {
+            $crate::flatmap::FlatMap::flat_map(self, f)
+        }
+
+        #[inline]
+        fn and_then_with<X: Clone, F>(self, x: X, mut f: F) -> Self::Target<B>
+        where
+            F: FnMut(A, X) -> Self::Target<B>,
+        {
+            let mut xs = self.into_iter().peekable();
+            let mut x = Some(x);
+            core::iter::from_fn(move || {
+                let a = xs.next()?;
+                let x = if xs.peek().is_some() { x.clone() } else { x.take() };
+                x.map(|x| (a, x))
+            })
+            .flat_map(move |(a, x)| f(a, x))
+            .collect::<$name<B>>()
+        }
+    };
+}
+
 impl<A, B> AndThen<B> for PhantomData<A> {
     fn and_then<F>(self, _f: F) -> PhantomData<B>
     where
@@ -56,7 +126,23 @@ impl<A, B> AndThen<B> for PhantomData<A> {
 }
 
 and_then_flat_map!(Option<T>);
+and_then_flat_map!(Poll<T>);
 and_then_flat_map!(Result<T, E>);
+and_then_flat_map!(ControlFlow<X, T>);
+
+#[cfg(feature = "smallvec")]
+impl<A, B, const N: usize> AndThen<B> for SmallVec<[A; N]> {
+    #[inline]
+    fn and_then<F>(self, f: F) -> SmallVec<[B; N]>
+    where
+        F: FnMut(A) -> SmallVec<[B; N]>,
+    {
+        crate::flatmap::FlatMap::flat_map(self, f)
+    }
+}
+
+#[cfg(feature = "either")]
+and_then_flat_map!(Either<L, T>);
 
 if_std! {
     use std::boxed::Box;
@@ -65,13 +151,13 @@ if_std! {
     use std::vec::Vec;
     use crate::flatmap::FlatMap;
 
-    and_then_flat_map!(Vec<T>);
-    and_then_flat_map!(LinkedList<T>);
-    and_then_flat_map!(VecDeque<T>);
+    and_then_iter!(Vec);
+    and_then_iter!(LinkedList);
+    and_then_iter!(VecDeque);
     and_then_flat_map!(Box<T>);
-    and_then_flat_map!(BinaryHeap<T>, Ord);
-    and_then_flat_map!(BTreeSet<T>, Ord);
-    and_then_flat_map!(HashSet<T>, Hash + Eq);
+    and_then_iter!(BinaryHeap, Ord);
+    and_then_iter!(BTreeSet, Ord);
+    and_then_iter!(HashSet, Hash + Eq);
 
     impl<A, B, K: Hash + Eq> AndThen<B> for HashMap<K, A> {
         #[inline]
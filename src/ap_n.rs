@@ -31,7 +31,7 @@ pub trait ApN<Z>: AndThen<Z> {
         Self: Sized,
     {
         let product = fa.product(fb);
-        self.and_then(|mut f| product.clone().map(move |(a, b)| f(a, b)))
+        self.and_then_with(product, |mut f, product| product.map(move |(a, b)| f(a, b)))
     }
 
     /// Is a ternary version of [Apply::ap].
@@ -63,18 +63,16 @@ pub trait ApN<Z>: AndThen<Z> {
         Self: Sized,
     {
         let product = fa.product(fb).product(fc);
-        self.and_then(|mut f| product.clone().map(move |((a, b), c)| f(a, b, c)))
+        self.and_then_with(product, |mut f, product| {
+            product.map(move |((a, b), c)| f(a, b, c))
+        })
     }
 
-    ap_n!(4);
-    ap_n!(5);
-    ap_n!(6);
-    ap_n!(7);
-    ap_n!(8);
-    ap_n!(9);
-    ap_n!(10);
-    ap_n!(11);
-    ap_n!(12);
+    ap_n!(4..=6);
+    #[cfg(feature = "arity-12")]
+    ap_n!(7..=12);
+    #[cfg(feature = "arity-16")]
+    ap_n!(13..=16);
 }
 
 impl<Z, T: AndThen<Z>> ApN<Z> for T {}
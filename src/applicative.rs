@@ -52,6 +52,7 @@
 //! ```
 
 use crate::apply::Apply;
+use crate::functor::Functor;
 use crate::pure::Pure;
 
 /// Applicative functor. This is a stronger version of Apply that has pure.
@@ -59,3 +60,82 @@ use crate::pure::Pure;
 pub trait Applicative<A, B>: Apply<A, B> + Pure {}
 
 impl<A, B, T> Applicative<A, B> for T where T: Apply<A, B> + Pure {}
+
+/// Run the effect `fa` discarding its result if `cond` is `true`, otherwise do nothing.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// assert_eq!(Ok(()), when_a(false, Err::<i32, _>("error")));
+/// assert_eq!(Err("error"), when_a(true, Err::<i32, _>("error")));
+/// assert_eq!(vec![(), ()], when_a(true, vec![1, 2]));
+/// ```
+#[inline]
+pub fn when_a<FA>(cond: bool, fa: FA) -> FA::Target<()>
+where
+    FA: Functor<()>,
+    FA::Target<()>: Pure<Param = ()>,
+{
+    if cond {
+        fa.map(|_| ())
+    } else {
+        Pure::pure(())
+    }
+}
+
+/// Run the effect `fa` discarding its result if `cond` is `false`, otherwise do nothing.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// assert_eq!(Some(()), unless_a(true, None::<i32>));
+/// assert_eq!(None, unless_a(false, None::<i32>));
+/// ```
+#[inline]
+pub fn unless_a<FA>(cond: bool, fa: FA) -> FA::Target<()>
+where
+    FA: Functor<()>,
+    FA::Target<()>: Pure<Param = ()>,
+{
+    when_a(!cond, fa)
+}
+
+if_std! {
+    use std::vec::Vec;
+
+    use crate::higher::Higher;
+    use crate::semigroupal::Semigroupal;
+
+    /// Run the effect `fa` `n` times, collecting the results into a [Vec].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(vec![1, 1, 1]), replicate_a(3, Some(1)));
+    /// assert_eq!(Ok::<_, ()>(vec![]), replicate_a(0, Ok(1)));
+    /// assert_eq!(
+    ///     vec![vec![1, 1], vec![1, 2], vec![2, 1], vec![2, 2]],
+    ///     replicate_a(2, vec![1, 2])
+    /// );
+    /// ```
+    pub fn replicate_a<FA, A>(n: usize, fa: FA) -> FA::Target<Vec<A>>
+    where
+        FA: Higher<Param = A> + Clone,
+        FA::Target<Vec<A>>: Semigroupal<A, Param = Vec<A>, Target<A> = FA> + Pure,
+        <FA::Target<Vec<A>> as Higher>::Target<(Vec<A>, A)>:
+            Functor<Vec<A>, Param = (Vec<A>, A), Target<Vec<A>> = FA::Target<Vec<A>>>,
+    {
+        (0..n).fold(Pure::pure(Vec::with_capacity(n)), |acc: FA::Target<Vec<A>>, _| {
+            acc.product(fa.clone()).map(|(mut xs, x)| {
+                xs.push(x);
+                xs
+            })
+        })
+    }
+}
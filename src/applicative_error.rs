@@ -0,0 +1,202 @@
+//! ApplicativeError.
+//!
+//! An applicative functor that is able to raise and handle errors of type `E`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn parse_or_zero<F>(fa: F) -> F
+//! where
+//!     F: ApplicativeError<String, Param = i32>,
+//! {
+//!     fa.handle_error(|_| 0)
+//! }
+//!
+//! assert_eq!(Ok(1), parse_or_zero(Ok(1)));
+//! assert_eq!(Ok(0), parse_or_zero(Err("error".to_string())));
+//! assert_eq!(Valid(0), parse_or_zero(Invalid("error".to_string())));
+//! ```
+
+use crate::data::validated::Validated::{self, Invalid, Valid};
+use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::pure::Pure;
+use crate::semigroup::Semigroup;
+
+/// An applicative functor with an error channel of type `E`.
+/// See [the module level documentation](self) for more.
+pub trait ApplicativeError<E>: Pure {
+    /// Lift an error into the context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Err::<i32, _>("error"), Result::raise_error("error"));
+    /// assert_eq!(None::<i32>, Option::raise_error(()));
+    /// ```
+    fn raise_error(e: E) -> Self;
+
+    /// Handle any error, potentially recovering from it, by mapping it to a `Self` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Err("error");
+    /// assert_eq!(Ok(5), x.handle_error_with(|e| Ok(e.len() as i32)));
+    ///
+    /// let x: Validated<i32, String> = Invalid("error".to_string());
+    /// assert_eq!(Invalid("ERROR".to_string()), x.handle_error_with(|e| Invalid(e.to_uppercase())));
+    /// ```
+    fn handle_error_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(E) -> Self;
+
+    /// Handle any error, by mapping it to a value of the parameter type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Err("error");
+    /// assert_eq!(Ok(5), x.handle_error(|e| e.len() as i32));
+    /// assert_eq!(Some(0), None.handle_error(|_| 0));
+    /// ```
+    #[inline]
+    fn handle_error<F>(self, f: F) -> Self
+    where
+        F: FnOnce(E) -> Self::Param,
+        Self: Sized,
+    {
+        self.handle_error_with(|e| Self::pure(f(e)))
+    }
+
+    /// Handle errors by turning them into [Result] values inside the context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(1);
+    /// assert_eq!(Ok(Ok(1)), x.attempt());
+    ///
+    /// let x: Result<i32, &str> = Err("error");
+    /// assert_eq!(Ok(Err("error")), x.attempt());
+    ///
+    /// let x: Validated<i32, String> = Invalid("error".to_string());
+    /// assert_eq!(Valid(Err("error".to_string())), x.attempt());
+    /// ```
+    #[inline]
+    fn attempt(self) -> Self::Target<Result<Self::Param, E>>
+    where
+        Self: Functor<Result<<Self as Higher>::Param, E>> + Sized,
+        Self::Target<Result<Self::Param, E>>: ApplicativeError<E>,
+    {
+        self.map(Ok).handle_error_with(|e| Pure::pure(Err(e)))
+    }
+
+    /// Recover from the errors for which the given partial function returns `Some` value.
+    /// All other errors are kept untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let recover_empty = |e: &&str| if e.is_empty() { Some(0) } else { None };
+    ///
+    /// let x: Result<i32, &str> = Err("");
+    /// assert_eq!(Ok(0), x.recover(recover_empty));
+    ///
+    /// let x: Result<i32, &str> = Err("error");
+    /// assert_eq!(Err("error"), x.recover(recover_empty));
+    /// ```
+    #[inline]
+    fn recover<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&E) -> Option<Self::Param>,
+        Self: Sized,
+    {
+        self.handle_error_with(|e| match f(&e) {
+            Some(x) => Self::pure(x),
+            None => Self::raise_error(e),
+        })
+    }
+
+    /// Transform the error, if any, leaving a successful value untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Result<i32, String> = Err("error".to_string());
+    /// assert_eq!(Err("ERROR".to_string()), x.adapt_error(|e| e.to_uppercase()));
+    ///
+    /// let x: Validated<i32, String> = Valid(1);
+    /// assert_eq!(Valid(1), x.adapt_error(|e| e.to_uppercase()));
+    /// ```
+    #[inline]
+    fn adapt_error<F>(self, f: F) -> Self
+    where
+        F: FnOnce(E) -> E,
+        Self: Sized,
+    {
+        self.handle_error_with(|e| Self::raise_error(f(e)))
+    }
+}
+
+impl<A> ApplicativeError<()> for Option<A> {
+    #[inline]
+    fn raise_error(_e: ()) -> Self {
+        None
+    }
+
+    #[inline]
+    fn handle_error_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(()) -> Self,
+    {
+        self.or_else(|| f(()))
+    }
+}
+
+impl<A, E> ApplicativeError<E> for Result<A, E> {
+    #[inline]
+    fn raise_error(e: E) -> Self {
+        Err(e)
+    }
+
+    #[inline]
+    fn handle_error_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(E) -> Self,
+    {
+        self.or_else(f)
+    }
+}
+
+impl<A, E: Semigroup> ApplicativeError<E> for Validated<A, E> {
+    #[inline]
+    fn raise_error(e: E) -> Self {
+        Invalid(e)
+    }
+
+    #[inline]
+    fn handle_error_with<F>(self, f: F) -> Self
+    where
+        F: FnOnce(E) -> Self,
+    {
+        match self {
+            Valid(x) => Valid(x),
+            Invalid(e) => f(e),
+        }
+    }
+}
@@ -1,8 +1,16 @@
 //! Apply.
 
 use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 use crate::functor::Functor;
+use crate::semigroup::Semigroup;
 
 /// Weaker version of Applicative has apply but not pure.
 pub trait Apply<A, B>: Functor<B> {
@@ -37,9 +45,16 @@ macro_rules! apply_iter {
             where
                 Self::Param: FnMut(A) -> B,
             {
-                self.into_iter()
-                    .flat_map(|mut f| fa.clone().into_iter().map(move |a| f(a)))
-                    .collect::<$name<B>>()
+                let mut fs = self.into_iter().peekable();
+                let mut fa = Some(fa);
+                // The last function consumes `fa`, so a single function needs no clone.
+                core::iter::from_fn(move || {
+                    let f = fs.next()?;
+                    let fa = if fs.peek().is_some() { fa.clone() } else { fa.take() };
+                    fa.map(|fa| (f, fa))
+                })
+                .flat_map(|(mut f, fa)| fa.into_iter().map(move |a| f(a)))
+                .collect::<$name<B>>()
             }
         }
     };
@@ -50,9 +65,16 @@ macro_rules! apply_iter {
             where
                 Self::Param: FnMut(A) -> B,
             {
-                self.into_iter()
-                    .flat_map(|mut f| fa.clone().into_iter().map(move |a| f(a)))
-                    .collect::<$name<B>>()
+                let mut fs = self.into_iter().peekable();
+                let mut fa = Some(fa);
+                // The last function consumes `fa`, so a single function needs no clone.
+                core::iter::from_fn(move || {
+                    let f = fs.next()?;
+                    let fa = if fs.peek().is_some() { fa.clone() } else { fa.take() };
+                    fa.map(|fa| (f, fa))
+                })
+                .flat_map(|(mut f, fa)| fa.into_iter().map(move |a| f(a)))
+                .collect::<$name<B>>()
             }
         }
     };
@@ -81,6 +103,19 @@ impl<F, A, B> Apply<A, B> for Option<F> {
     }
 }
 
+impl<F, A, B> Apply<A, B> for Poll<F> {
+    #[inline]
+    fn ap(self, fa: Poll<A>) -> Poll<B>
+    where
+        F: FnOnce(A) -> B,
+    {
+        match (self, fa) {
+            (Poll::Ready(f), Poll::Ready(a)) => Poll::Ready(f(a)),
+            _ => Poll::Pending,
+        }
+    }
+}
+
 impl<F, A, B, E> Apply<A, B> for Result<F, E> {
     #[inline]
     fn ap(self, fa: Result<A, E>) -> Result<B, E>
@@ -95,10 +130,76 @@ impl<F, A, B, E> Apply<A, B> for Result<F, E> {
     }
 }
 
+impl<X, F, A, B> Apply<A, B> for ControlFlow<X, F> {
+    #[inline]
+    fn ap(self, fa: ControlFlow<X, A>) -> ControlFlow<X, B>
+    where
+        F: FnOnce(A) -> B,
+    {
+        match (self, fa) {
+            (ControlFlow::Continue(f), ControlFlow::Continue(a)) => ControlFlow::Continue(f(a)),
+            (ControlFlow::Break(x), _) => ControlFlow::Break(x),
+            (_, ControlFlow::Break(x)) => ControlFlow::Break(x),
+        }
+    }
+}
+
+impl<X: Semigroup, F, A, B> Apply<A, B> for (X, F) {
+    #[inline]
+    fn ap(self, fa: (X, A)) -> (X, B)
+    where
+        F: FnMut(A) -> B,
+    {
+        let (x, mut f) = self;
+        (x.combine(fa.0), f(fa.1))
+    }
+}
+
+impl<F, A, B, const N: usize> Apply<A, B> for [F; N] {
+    #[inline]
+    fn ap(self, fa: [A; N]) -> [B; N]
+    where
+        F: FnMut(A) -> B,
+    {
+        let mut fa = fa.into_iter();
+        self.map(|mut f| f(fa.next().expect("arrays have the same length")))
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<F, A: Clone, B, const N: usize> Apply<A, B> for SmallVec<[F; N]> {
+    #[inline]
+    fn ap(self, fa: SmallVec<[A; N]>) -> SmallVec<[B; N]>
+    where
+        F: FnMut(A) -> B,
+    {
+        self.into_iter()
+            .flat_map(|mut f| fa.iter().map(move |a| f(a.clone())))
+            .collect()
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, F, A, B> Apply<A, B> for Either<L, F> {
+    #[inline]
+    fn ap(self, fa: Either<L, A>) -> Either<L, B>
+    where
+        F: FnOnce(A) -> B,
+    {
+        match (self, fa) {
+            (Either::Right(f), Either::Right(a)) => Either::Right(f(a)),
+            (Either::Left(l), _) => Either::Left(l),
+            (_, Either::Left(l)) => Either::Left(l),
+        }
+    }
+}
+
 if_std! {
     use std::boxed::Box;
     use std::collections::*;
     use std::hash::Hash;
+    use std::rc::Rc;
+    use std::sync::Arc;
     use std::vec::Vec;
 
     impl<F, A, B> Apply<A, B> for Box<F> {
@@ -111,6 +212,26 @@ if_std! {
         }
     }
 
+    impl<F: Clone, A: Clone, B> Apply<A, B> for Rc<F> {
+        #[inline]
+        fn ap(self, fa: Rc<A>) -> Rc<B>
+        where
+            F: FnMut(A) -> B,
+        {
+            Rc::new(Rc::unwrap_or_clone(self)(Rc::unwrap_or_clone(fa)))
+        }
+    }
+
+    impl<F: Clone, A: Clone, B> Apply<A, B> for Arc<F> {
+        #[inline]
+        fn ap(self, fa: Arc<A>) -> Arc<B>
+        where
+            F: FnMut(A) -> B,
+        {
+            Arc::new(Arc::unwrap_or_clone(self)(Arc::unwrap_or_clone(fa)))
+        }
+    }
+
     apply_iter!(Vec);
     apply_iter!(LinkedList);
     apply_iter!(VecDeque);
@@ -129,4 +250,16 @@ if_std! {
                 .collect()
         }
     }
+
+    impl<F, A, B, K: Ord> Apply<A, B> for BTreeMap<K, F> {
+        #[inline]
+        fn ap(mut self, fa: BTreeMap<K, A>) -> BTreeMap<K, B>
+        where
+            F: FnOnce(A) -> B,
+        {
+            fa.into_iter()
+                .filter_map(|(k, a)| self.remove(&k).map(|f| (k, f(a))))
+                .collect()
+        }
+    }
 }
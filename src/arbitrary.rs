@@ -0,0 +1,147 @@
+//! Support for property-based testing with [proptest].
+//!
+//! This module provides reusable strategies for the data types of the library, as well as
+//! [Arbitrary] instances built on top of them, so that the data types can be used directly in
+//! the arguments of `proptest!` tests.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use rust2fun::arbitrary::ne_vec;
+//! use rust2fun::prelude::*;
+//!
+//! proptest! {
+//!     fn test_head(xs in ne_vec(any::<i32>(), 1..10)) {
+//!         prop_assert_eq!(Some(xs.head()), xs.iter().next());
+//!     }
+//!
+//!     fn test_valid(x: Validated<u8, String>) {
+//!         prop_assert_eq!(x.is_valid(), x.valid().is_some());
+//!     }
+//! }
+//! # test_head();
+//! # test_valid();
+//! ```
+//!
+//! [proptest]: https://docs.rs/proptest
+
+use proptest::arbitrary::{any_with, Arbitrary};
+use proptest::collection::{vec, SizeRange};
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Strategy};
+
+use crate::data::{Invalid, Ior, NEChain, NEVec, Valid, Validated};
+
+/// Creates a strategy generating [NEVec] values with elements drawn from `element` and a length
+/// within `size`. A lower bound of zero is raised to one, as a [NEVec] is never empty.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use rust2fun::arbitrary::ne_vec;
+///
+/// proptest! {
+///     fn test_len(xs in ne_vec(any::<bool>(), 0..5)) {
+///         prop_assert!((1..5).contains(&xs.len()));
+///     }
+/// }
+/// # test_len();
+/// ```
+pub fn ne_vec<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = NEVec<S::Value>> {
+    let (start, end) = size.into().start_end_incl();
+    vec(element, start.max(1)..=end.max(1)).prop_map(|xs| NEVec::from_vec(xs).unwrap())
+}
+
+/// Creates a strategy generating [NEChain] values with elements drawn from `element` and a
+/// length within `size`. A lower bound of zero is raised to one, as a [NEChain] is never empty.
+pub fn ne_chain<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = NEChain<S::Value>> {
+    ne_vec(element, size).prop_map(NEChain::from)
+}
+
+/// Creates a strategy generating [Validated] values, which are [Valid] with a value drawn from
+/// `valid` or [Invalid] with an error drawn from `invalid`.
+///
+/// # Examples
+///
+/// ```
+/// use proptest::prelude::*;
+/// use rust2fun::arbitrary::validated;
+///
+/// proptest! {
+///     fn test_even(x in validated((0..128u8).prop_map(|x| x * 2), Just("odd"))) {
+///         prop_assert!(x.iter().all(|x| x % 2 == 0));
+///     }
+/// }
+/// # test_even();
+/// ```
+pub fn validated<T, E>(
+    valid: impl Strategy<Value = T>,
+    invalid: impl Strategy<Value = E>,
+) -> impl Strategy<Value = Validated<T, E>>
+where
+    T: core::fmt::Debug,
+    E: core::fmt::Debug,
+{
+    prop_oneof![valid.prop_map(Valid), invalid.prop_map(Invalid)]
+}
+
+/// Creates a strategy generating [Ior] values, with the left values drawn from `left` and the
+/// right values drawn from `right`.
+pub fn ior<A, B>(
+    left: impl Strategy<Value = A>,
+    right: impl Strategy<Value = B>,
+) -> impl Strategy<Value = Ior<A, B>>
+where
+    A: core::fmt::Debug,
+    B: core::fmt::Debug,
+{
+    (left, right, 0..3u8).prop_map(|(a, b, variant)| match variant {
+        0 => Ior::Left(a),
+        1 => Ior::Right(b),
+        _ => Ior::Both(a, b),
+    })
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for NEVec<T> {
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        ne_vec(any_with::<T>(args), size).boxed()
+    }
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for NEChain<T> {
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, args): Self::Parameters) -> Self::Strategy {
+        ne_chain(any_with::<T>(args), size).boxed()
+    }
+}
+
+impl<T: Arbitrary + 'static, E: Arbitrary + 'static> Arbitrary for Validated<T, E> {
+    type Parameters = (T::Parameters, E::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((valid, invalid): Self::Parameters) -> Self::Strategy {
+        validated(any_with::<T>(valid), any_with::<E>(invalid)).boxed()
+    }
+}
+
+impl<A: Arbitrary + 'static, B: Arbitrary + 'static> Arbitrary for Ior<A, B> {
+    type Parameters = (A::Parameters, B::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((left, right): Self::Parameters) -> Self::Strategy {
+        ior(any_with::<A>(left), any_with::<B>(right)).boxed()
+    }
+}
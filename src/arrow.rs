@@ -0,0 +1,163 @@
+//! Category and Arrow.
+//!
+//! A category abstracts over function-like types that have an identity and can be composed.
+//! An arrow is a category that can lift plain functions and that can be combined to work on
+//! pairs of values.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! type Func<A, B> = Box<dyn FnMut(A) -> B>;
+//!
+//! let parse = Func::<(), ()>::arr(|s: &str| s.parse::<i32>().unwrap());
+//! let double = Func::<(), ()>::arr(|x: i32| x * 2);
+//! let len = Func::<(), ()>::arr(|s: &str| s.len());
+//! let mut f = parse.and_then(double).fanout(len);
+//! assert_eq!((84, 2), f("42"));
+//! ```
+
+use crate::higher::Higher2;
+use crate::profunctor::Strong;
+
+/// A category is a function-like type which can be composed and has an identity.
+/// See [the module level documentation](self) for more.
+pub trait Category<C>: Higher2 {
+    /// The identity arrow. `Self` is only used to select the type constructor, so its type
+    /// parameters are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Box::<dyn FnMut(()) -> ()>::id();
+    /// assert_eq!(1, f(1));
+    /// ```
+    fn id() -> Self::Target<C, C>;
+
+    /// Compose with another arrow. The other arrow is applied first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let g: Box<dyn FnMut(&str) -> i32> = Box::new(|s| s.parse().unwrap());
+    /// let mut h = f.compose(g);
+    /// assert_eq!(2, h("1"));
+    /// ```
+    fn compose(self, g: Self::Target<C, Self::Param1>) -> Self::Target<C, Self::Param2>;
+
+    /// Compose with another arrow. This arrow is applied first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let g: Box<dyn FnMut(i32) -> String> = Box::new(|x| x.to_string());
+    /// let mut h = f.and_then(g);
+    /// assert_eq!("2", h(1));
+    /// ```
+    fn and_then(self, g: Self::Target<Self::Param2, C>) -> Self::Target<Self::Param1, C>;
+}
+
+/// An arrow is a [Category] which can lift plain functions and which is [Strong].
+/// See [the module level documentation](self) for more.
+pub trait Arrow<C, D>: Category<C> + Strong<C> {
+    /// Lift a plain function into the arrow. `Self` is only used to select the type
+    /// constructor, so its type parameters are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Box::<dyn FnMut(()) -> ()>::arr(|x: i32| x + 1);
+    /// assert_eq!(2, f(1));
+    ///
+    /// let mut k = Kleisli::<(), Option<()>>::arr(|x: i32| x + 1);
+    /// assert_eq!(Some(2), k.run(1));
+    /// ```
+    fn arr(f: impl FnMut(C) -> D + 'static) -> Self::Target<C, D>;
+
+    /// Combine two arrows to work on the components of a pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let g: Box<dyn FnMut(&str) -> usize> = Box::new(|s| s.len());
+    /// let mut h = f.split(g);
+    /// assert_eq!((2, 3), h((1, "foo")));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn split(self, g: Self::Target<C, D>) -> Self::Target<(Self::Param1, C), (Self::Param2, D)>;
+
+    /// Feed the same input to two arrows and pair their results. The input of this arrow is
+    /// obtained by converting the input of the other one, which is usually the same type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let g: Box<dyn FnMut(i32) -> String> = Box::new(|x| x.to_string());
+    /// let mut h = f.fanout(g);
+    /// assert_eq!((2, "1".to_string()), h(1));
+    /// ```
+    fn fanout(self, g: Self::Target<C, D>) -> Self::Target<C, (Self::Param2, D)>
+    where
+        C: Clone + Into<Self::Param1>;
+}
+
+if_std! {
+    use std::boxed::Box;
+
+    impl<A: 'static, B: 'static, C: 'static> Category<C> for Box<dyn FnMut(A) -> B> {
+        #[inline]
+        fn id() -> Box<dyn FnMut(C) -> C> {
+            Box::new(crate::combinator::id)
+        }
+
+        #[inline]
+        fn compose(mut self, mut g: Box<dyn FnMut(C) -> A>) -> Box<dyn FnMut(C) -> B> {
+            Box::new(move |c| self(g(c)))
+        }
+
+        #[inline]
+        fn and_then(mut self, mut g: Box<dyn FnMut(B) -> C>) -> Box<dyn FnMut(A) -> C> {
+            Box::new(move |a| g(self(a)))
+        }
+    }
+
+    impl<A: 'static, B: 'static, C: 'static, D: 'static> Arrow<C, D> for Box<dyn FnMut(A) -> B> {
+        #[inline]
+        fn arr(f: impl FnMut(C) -> D + 'static) -> Box<dyn FnMut(C) -> D> {
+            Box::new(f)
+        }
+
+        #[inline]
+        fn split(
+            mut self,
+            mut g: Box<dyn FnMut(C) -> D>,
+        ) -> Box<dyn FnMut((A, C)) -> (B, D)> {
+            Box::new(move |(a, c)| (self(a), g(c)))
+        }
+
+        #[inline]
+        fn fanout(mut self, mut g: Box<dyn FnMut(C) -> D>) -> Box<dyn FnMut(C) -> (B, D)>
+        where
+            C: Clone + Into<A>,
+        {
+            Box::new(move |c: C| (self(c.clone().into()), g(c)))
+        }
+    }
+}
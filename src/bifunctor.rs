@@ -1,6 +1,11 @@
 //! Bifunctor is a type constructor that takes two type arguments and is a functor in both
 //! arguments.
 
+use core::ops::ControlFlow;
+
+#[cfg(feature = "either")]
+use either::Either;
+
 use crate::higher::Higher2;
 
 /// Bifunctor takes two type parameters instead of one, and is a functor in both of these
@@ -25,14 +30,30 @@ impl<A, B, C, D> Bifunctor<C, D> for Result<A, B> {
     }
 }
 
+impl<A, B, C, D> Bifunctor<C, D> for ControlFlow<A, B> {
+    fn bimap(self, mut f: impl FnMut(A) -> C, mut g: impl FnMut(B) -> D) -> ControlFlow<C, D> {
+        match self {
+            ControlFlow::Break(x) => ControlFlow::Break(f(x)),
+            ControlFlow::Continue(x) => ControlFlow::Continue(g(x)),
+        }
+    }
+}
+
 impl<A, B, C, D> Bifunctor<C, D> for (A, B) {
     fn bimap(self, mut f: impl FnMut(A) -> C, mut g: impl FnMut(B) -> D) -> (C, D) {
         (f(self.0), g(self.1))
     }
 }
 
+#[cfg(feature = "either")]
+impl<A, B, C, D> Bifunctor<C, D> for Either<A, B> {
+    fn bimap(self, f: impl FnMut(A) -> C, g: impl FnMut(B) -> D) -> Either<C, D> {
+        self.map_either(f, g)
+    }
+}
+
 if_std! {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use std::hash::Hash;
 
     impl<A, B, C: Eq+Hash, D> Bifunctor<C, D> for HashMap<A, B> {
@@ -44,4 +65,14 @@ if_std! {
                 self.into_iter().map(|(k, v)| (f(k), g(v))).collect()
         }
     }
+
+    impl<A, B, C: Ord, D> Bifunctor<C, D> for BTreeMap<A, B> {
+        fn bimap(
+            self,
+            mut f: impl FnMut(A) -> C,
+            mut g: impl FnMut(B) -> D,
+        ) -> BTreeMap<C, D> {
+            self.into_iter().map(|(k, v)| (f(k), g(v))).collect()
+        }
+    }
 }
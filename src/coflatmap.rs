@@ -0,0 +1,38 @@
+//! CoflatMap.
+
+use crate::higher::Higher;
+
+/// Gives access to the `coflat_map` method, the dual of [FlatMap](super::flatmap::FlatMap).
+/// Together with [Comonad](super::comonad::Comonad) it allows computations which depend on a
+/// whole context rather than on a single value.
+pub trait CoflatMap<B>: Higher {
+    /// Applies a function to the whole context, and to each of its sub-contexts, to build a new
+    /// context of the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let actual = ne_vec![1, 2, 3].coflat_map(|xs| xs.len());
+    /// assert_eq!(ne_vec![3, 2, 1], actual);
+    /// ```
+    fn coflat_map<F>(self, f: F) -> Self::Target<B>
+    where
+        F: FnMut(Self) -> B,
+        Self: Sized;
+}
+
+if_std! {
+    use std::boxed::Box;
+
+    impl<A, B> CoflatMap<B> for Box<A> {
+        #[inline]
+        fn coflat_map<F>(self, mut f: F) -> Box<B>
+        where
+            F: FnMut(Box<A>) -> B,
+        {
+            Box::new(f(self))
+        }
+    }
+}
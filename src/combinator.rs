@@ -63,6 +63,143 @@ macro_rules! pipe {
     };
 }
 
+/// Compose effectful functions `A -> M<B>` left-to-right, threading the effects with
+/// [FlatMap::flat_map]. This is the monadic analog of [compose!] (also known as the fish
+/// operator `>=>`).
+///
+/// (f >=> g >=> h)(x) = f(x).flat_map(g).flat_map(h)
+///
+/// See [Kleisli] for a composable wrapper around such functions.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let parse = |s: &str| s.parse::<i32>().ok();
+/// let non_zero = |x: i32| if x != 0 { Some(x) } else { None };
+/// let reciprocal = |x: i32| Some(1.0 / x as f64);
+///
+/// let f = kcompose!(parse, non_zero, reciprocal);
+/// assert_eq!(Some(0.5), f("2"));
+/// assert_eq!(None, f("0"));
+/// assert_eq!(None, f("foo"));
+/// ```
+///
+/// [FlatMap::flat_map]: crate::flatmap::FlatMap::flat_map
+/// [Kleisli]: crate::data::kleisli::Kleisli
+#[macro_export]
+macro_rules! kcompose {
+    (acc $acc:expr, $next:expr $(, $tail:expr)*) => {
+        kcompose!(acc $crate::flatmap::FlatMap::flat_map($acc, |x| $next(x)) $(, $tail)*)
+    };
+    (acc $acc:expr) => {
+        $acc
+    };
+    ($head:expr $(, $tail:expr)* $(,)?) => {
+        |x| { kcompose!(acc $head(x) $(, $tail)*) }
+    };
+}
+
+/// Pipe a value through a mix of plain and fallible functions. Fallible stages are marked with
+/// `try` and return an effectful value such as [Option] or [Result]. Once the first fallible
+/// stage is reached, the following plain stages are applied with [Functor::map] and the fallible
+/// ones with [FlatMap::flat_map], so a failure short-circuits the rest of the pipeline like `?`.
+///
+/// If there is no fallible stage the result is the same as with [pipe!].
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let parse = |s: &str| s.parse::<i32>().map_err(|e| e.to_string());
+/// let non_zero = |x: i32| if x != 0 { Ok(x) } else { Err("zero".to_string()) };
+///
+/// let actual = pipe_try!(" 4 ", str::trim, try parse, |x| x * 2, try non_zero, |x| 16 / x);
+/// assert_eq!(Ok(2), actual);
+///
+/// let actual = pipe_try!(" 0 ", str::trim, try parse, |x| x * 2, try non_zero, |x| 16 / x);
+/// assert_eq!(Err("zero".to_string()), actual);
+/// ```
+///
+/// [Functor::map]: crate::functor::Functor::map
+/// [FlatMap::flat_map]: crate::flatmap::FlatMap::flat_map
+#[macro_export]
+macro_rules! pipe_try {
+    (@plain $acc:expr;) => {
+        $acc
+    };
+    (@plain $acc:expr; [try $f:expr] $($rest:tt)*) => {
+        $crate::pipe_try!(@lifted $f($acc); $($rest)*)
+    };
+    (@plain $acc:expr; [$f:expr] $($rest:tt)*) => {
+        $crate::pipe_try!(@plain $f($acc); $($rest)*)
+    };
+    (@lifted $acc:expr;) => {
+        $acc
+    };
+    (@lifted $acc:expr; [try $f:expr] $($rest:tt)*) => {
+        $crate::pipe_try!(@lifted $crate::flatmap::FlatMap::flat_map($acc, |x| $f(x)); $($rest)*)
+    };
+    (@lifted $acc:expr; [$f:expr] $($rest:tt)*) => {
+        $crate::pipe_try!(@lifted $crate::functor::Functor::map($acc, |x| $f(x)); $($rest)*)
+    };
+    (@group $x:expr; [$($g:tt)*] try $f:expr, $($rest:tt)+) => {
+        $crate::pipe_try!(@group $x; [$($g)* [try $f]] $($rest)+)
+    };
+    (@group $x:expr; [$($g:tt)*] try $f:expr $(,)?) => {
+        $crate::pipe_try!(@plain $x; $($g)* [try $f])
+    };
+    (@group $x:expr; [$($g:tt)*] $f:expr, $($rest:tt)+) => {
+        $crate::pipe_try!(@group $x; [$($g)* [$f]] $($rest)+)
+    };
+    (@group $x:expr; [$($g:tt)*] $f:expr $(,)?) => {
+        $crate::pipe_try!(@plain $x; $($g)* [$f])
+    };
+    ($x:expr, $($stages:tt)+) => {
+        $crate::pipe_try!(@group $x; [] $($stages)+)
+    };
+    ($x:expr) => {
+        $x
+    };
+}
+
+/// Compose a mix of plain and fallible functions right-to-left, like [compose!]. Fallible stages
+/// are marked with `try`; see [pipe_try!] for how the effects are threaded.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let parse = |s: &str| s.parse::<u32>().ok();
+/// let half = |x: u32| if x % 2 == 0 { Some(x / 2) } else { None };
+///
+/// let f = compose_try!(|x| x + 1, try half, try parse, str::trim);
+/// assert_eq!(Some(3), f(" 4 "));
+/// assert_eq!(None, f(" 3 "));
+/// assert_eq!(None, f("foo"));
+/// ```
+#[macro_export]
+macro_rules! compose_try {
+    (@rev [$($g:tt)*] try $f:expr, $($rest:tt)+) => {
+        $crate::compose_try!(@rev [[try $f] $($g)*] $($rest)+)
+    };
+    (@rev [$($g:tt)*] try $f:expr $(,)?) => {
+        |x| $crate::pipe_try!(@plain x; [try $f] $($g)*)
+    };
+    (@rev [$($g:tt)*] $f:expr, $($rest:tt)+) => {
+        $crate::compose_try!(@rev [[$f] $($g)*] $($rest)+)
+    };
+    (@rev [$($g:tt)*] $f:expr $(,)?) => {
+        |x| $crate::pipe_try!(@plain x; [$f] $($g)*)
+    };
+    ($($stages:tt)+) => {
+        $crate::compose_try!(@rev [] $($stages)+)
+    };
+}
+
 /// Curry a function of two arguments. The first argument is applied to the function. The second
 /// argument is returned as a closure. The returned closure can be applied to the second argument.
 /// The result is the same as applying the function to both arguments.
@@ -84,16 +221,57 @@ macro_rules! curry2 {
     };
 }
 
-curry_arity!(3);
-curry_arity!(4);
-curry_arity!(5);
-curry_arity!(6);
-curry_arity!(7);
-curry_arity!(8);
-curry_arity!(9);
-curry_arity!(10);
-curry_arity!(11);
-curry_arity!(12);
+curry_arity!(3..=6);
+#[cfg(feature = "arity-12")]
+curry_arity!(7..=12);
+#[cfg(feature = "arity-16")]
+curry_arity!(13..=16);
+
+/// Partially apply a function. The arguments given as `_` are left as holes and become the
+/// parameters of the returned closure, in order. Unlike `curryN!` the holes can be anywhere in
+/// the argument list.
+///
+/// The other arguments are evaluated once, when the closure is created, and moved into it.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let f = |x: i32, y: i32, z: i32| x * 100 + y * 10 + z;
+///
+/// let g = partial!(f, _, 2, _);
+/// assert_eq!(123, g(1, 3));
+///
+/// let actual = Some(5).map(partial!(i32::pow, _, 2));
+/// assert_eq!(Some(25), actual);
+///
+/// let mut calls = 0;
+/// let mut next = || {
+///     calls += 1;
+///     calls
+/// };
+/// let h = partial!(f, next(), _, next());
+/// assert_eq!(152, h(5));
+/// assert_eq!(162, h(6));
+/// assert_eq!(2, calls);
+/// ```
+#[macro_export]
+macro_rules! partial {
+    (@args $f:expr; ($($params:ident)*); ($($args:expr,)*); ($($lets:ident = $vals:expr,)*);) => {{
+        $(let $lets = $vals;)*
+        move |$($params),*| $f($($args),*)
+    }};
+    (@args $f:expr; ($($params:ident)*); ($($args:expr,)*); ($($lets:tt)*); _ $(, $($rest:tt)*)?) => {
+        $crate::partial!(@args $f; ($($params)* x); ($($args,)* x,); ($($lets)*); $($($rest)*)?)
+    };
+    (@args $f:expr; ($($params:ident)*); ($($args:expr,)*); ($($lets:tt)*); $arg:expr $(, $($rest:tt)*)?) => {
+        $crate::partial!(@args $f; ($($params)*); ($($args,)* a,); ($($lets)* a = $arg,); $($($rest)*)?)
+    };
+    ($f:expr, $($args:tt)*) => {
+        $crate::partial!(@args $f; (); (); (); $($args)*)
+    };
+}
 
 /// Flip arguments of a function *flip(f)(x, y) = f(y, x)* also known as C (Cardinal) combinator.
 ///
@@ -164,16 +342,11 @@ macro_rules! constant2 {
     };
 }
 
-constant_arity!(3);
-constant_arity!(4);
-constant_arity!(5);
-constant_arity!(6);
-constant_arity!(7);
-constant_arity!(8);
-constant_arity!(9);
-constant_arity!(10);
-constant_arity!(11);
-constant_arity!(12);
+constant_arity!(3..=6);
+#[cfg(feature = "arity-12")]
+constant_arity!(7..=12);
+#[cfg(feature = "arity-16")]
+constant_arity!(13..=16);
 
 /// The identity function *id(x) = x* also known as I (Idiot) combinator.
 ///
@@ -207,16 +380,11 @@ pub const fn tuple2<A, B>(a: A, b: B) -> (A, B) {
     (a, b)
 }
 
-tuple_arity!(3);
-tuple_arity!(4);
-tuple_arity!(5);
-tuple_arity!(6);
-tuple_arity!(7);
-tuple_arity!(8);
-tuple_arity!(9);
-tuple_arity!(10);
-tuple_arity!(11);
-tuple_arity!(12);
+tuple_arity!(3..=6);
+#[cfg(feature = "arity-12")]
+tuple_arity!(7..=12);
+#[cfg(feature = "arity-16")]
+tuple_arity!(13..=16);
 
 /// The no operation function.
 ///
@@ -243,17 +411,11 @@ pub const fn noop() {}
 #[inline(always)]
 pub fn noop1<T>(_: T) {}
 
-noop_arity!(2);
-noop_arity!(3);
-noop_arity!(4);
-noop_arity!(5);
-noop_arity!(6);
-noop_arity!(7);
-noop_arity!(8);
-noop_arity!(9);
-noop_arity!(10);
-noop_arity!(11);
-noop_arity!(12);
+noop_arity!(2..=6);
+#[cfg(feature = "arity-12")]
+noop_arity!(7..=12);
+#[cfg(feature = "arity-16")]
+noop_arity!(13..=16);
 
 /// The apply function *apply(f, x) = f(x)* also known as A (Apply) combinator.
 /// It is the same as function application.
@@ -271,6 +433,27 @@ pub fn apply<T, R>(f: impl FnOnce(T) -> R, x: T) -> R {
     f(x)
 }
 
+/// The tap function *tap(f)(x) = x* running the side effect `f(&x)` before returning `x`.
+/// Useful for logging inside pipelines.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let mut log = Vec::new();
+/// let actual = pipe!(2, |x| x + 1, tap(|x: &i32| log.push(*x)), |x| x * 2);
+/// assert_eq!(6, actual);
+/// assert_eq!(vec![3], log);
+/// ```
+#[inline]
+pub fn tap<T>(mut f: impl FnMut(&T)) -> impl FnMut(T) -> T {
+    move |x| {
+        f(&x);
+        x
+    }
+}
+
 /// The application function *apply_to(x, f) = f(x)* also known as T (Thrush) combinator.
 ///
 /// # Example
@@ -308,9 +491,29 @@ where
     f(x, g(x))
 }
 
+/// The duplication function *duplication(f, x) = f(x, x)* also known as W (Warbler) combinator.
+/// It passes the same argument twice to a binary function.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let square = |x: i32| duplication(|x, y| x * y, x);
+/// assert_eq!(49, square(7));
+/// ```
+#[inline]
+pub fn duplication<A: Copy, B, F>(f: F, x: A) -> B
+where
+    F: FnOnce(A, A) -> B,
+{
+    f(x, x)
+}
+
 /// Provides a means of passing an accumulating function and two branching functions. A value can be
 /// applied to the resulting function which will then be applied to each branching function, the
-/// results of which will be applied to the accumulating function.
+/// results of which will be applied to the accumulating function. It is also known as S' (Phoenix)
+/// combinator.
 ///
 /// # Example
 ///
@@ -331,6 +534,26 @@ where
     f(g(x), h(x))
 }
 
+/// The blackbird function *blackbird(f, g, x, y) = f(g(x, y))* also known as B1 combinator.
+/// It composes a unary function after a binary function.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let actual = blackbird(i32::abs, |x: i32, y: i32| x - y, 2, 5);
+/// assert_eq!(3, actual);
+/// ```
+#[inline]
+pub fn blackbird<A, B, C, D, F, G>(f: F, g: G, x: A, y: B) -> D
+where
+    F: FnOnce(C) -> D,
+    G: FnOnce(A, B) -> C,
+{
+    f(g(x, y))
+}
+
 /// This function, also called the Psi combinator, allows you to call a function on transformations
 /// of values. It can be considered the sister of [converge]. Where converge takes one argument and
 /// maps it through two unary functions, merging the resulting values with a binary function, psi
@@ -0,0 +1,51 @@
+//! Comonad.
+//!
+//! A comonad is the dual of a [Monad](super::monad::Monad): instead of putting values into a
+//! context with `pure`, it takes a value out of a context with `extract`, and instead of
+//! `flat_map` it has [coflat_map](super::coflatmap::CoflatMap::coflat_map). The operations must
+//! satisfy three laws:
+//!
+//! 1. Left identity: `fa.coflat_map(extract) == fa`
+//! 2. Right identity: `fa.coflat_map(f).extract() == f(fa)`
+//! 3. Associativity: `fa.coflat_map(f).coflat_map(g) == fa.coflat_map(|x| g(x.coflat_map(f)))`
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let deposits = ne_vec![5, 3, 8, 1];
+//! let remaining = deposits.coflat_map(|xs| xs.iter().sum::<i32>());
+//! assert_eq!(ne_vec![17, 12, 9, 1], remaining);
+//! assert_eq!(17, remaining.extract());
+//! ```
+
+use crate::higher::Higher;
+
+/// A comonad. Gives access to the `extract` method, which is expected to be used together with
+/// [CoflatMap](super::coflatmap::CoflatMap).
+/// See [the module level documentation](self) for more.
+pub trait Comonad: Higher {
+    /// Extracts the value at the focus of the context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(1, ne_vec![1, 2, 3].extract());
+    /// assert_eq!(1, Box::new(1).extract());
+    /// ```
+    fn extract(self) -> Self::Param;
+}
+
+if_std! {
+    use std::boxed::Box;
+
+    impl<A> Comonad for Box<A> {
+        #[inline]
+        fn extract(self) -> A {
+            *self
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use core::iter::FusedIterator;
+use std::{slice, vec};
+
+use super::*;
+
+/// An iterator over the elements of a [Chain].
+///
+/// This struct is created by [Chain::iter].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    current: slice::Iter<'a, T>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            if let Some(x) = self.current.next() {
+                self.remaining -= 1;
+                return Some(x);
+            }
+            match self.stack.pop()? {
+                Node::One(x) => {
+                    self.remaining -= 1;
+                    return Some(x);
+                }
+                Node::Many(xs) => self.current = xs.iter(),
+                Node::Append(lhs, rhs) => {
+                    self.stack.push(rhs);
+                    self.stack.push(lhs);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// An owning iterator over the elements of a [Chain].
+///
+/// This struct is created by the `into_iter` method on [Chain].
+pub struct IntoIter<T> {
+    stack: Vec<Node<T>>,
+    current: vec::IntoIter<T>,
+    remaining: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some(x) = self.current.next() {
+                self.remaining -= 1;
+                return Some(x);
+            }
+            match self.stack.pop()? {
+                Node::One(x) => {
+                    self.remaining -= 1;
+                    return Some(x);
+                }
+                Node::Many(xs) => self.current = xs.into_iter(),
+                Node::Append(lhs, rhs) => {
+                    self.stack.push(*rhs);
+                    self.stack.push(*lhs);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        drop_nodes(core::mem::take(&mut self.stack));
+    }
+}
+
+impl<T> IntoIterator for Chain<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(mut self) -> IntoIter<T> {
+        IntoIter {
+            stack: self.root.take().into_iter().collect(),
+            current: Vec::new().into_iter(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Chain<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> Chain<T> {
+    /// Returns an iterator over the elements of the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let chain = chain![1, 2].combine(chain![3]);
+    /// let mut iter = chain.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            stack: self.root.iter().collect(),
+            current: [].iter(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Chain<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Chain::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T> Extend<T> for Chain<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let this = core::mem::take(self);
+        *self = this.concat(iter.into_iter().collect());
+    }
+}
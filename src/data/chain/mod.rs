@@ -0,0 +1,320 @@
+//! A sequence with constant time concatenation.
+//!
+//! [`Chain<T>`][Chain] is a catenable list: [combining](Semigroup::combine) two chains, as well as
+//! [appending](Chain::append) or [prepending](Chain::prepend) a single element, is O(1). The
+//! elements are only traversed when the chain is iterated. This makes `Chain` a good fit for
+//! accumulating logs or errors, where [Vec] would copy its elements on every combination.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let log = (0..3).fold(Chain::new(), |acc, i| acc.combine(chain![i, i * 10]));
+//! assert_eq!(6, log.len());
+//! assert_eq!(vec![0, 0, 1, 10, 2, 20], log.into_iter().collect::<Vec<_>>());
+//! ```
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+use std::vec::Vec;
+
+use crate::monoid::Monoid;
+use crate::pure::Pure;
+use crate::semigroup::Semigroup;
+use crate::{
+    align_iter, and_then_iter, apply_iter, flatmap_iter, functor_iter, higher,
+    invariant_functor, semigroupal_iter, traverse_iter,
+};
+
+pub use iter::*;
+
+mod iter;
+#[cfg(feature = "rayon")]
+mod rayon;
+
+/// Internal tree representation of a non-empty chain.
+enum Node<T> {
+    One(T),
+    /// Invariant: the vector is never empty.
+    Many(Vec<T>),
+    Append(Box<Node<T>>, Box<Node<T>>),
+}
+
+/// A sequence with constant time concatenation.
+/// See the [module-level documentation](self) for more details.
+pub struct Chain<T> {
+    root: Option<Node<T>>,
+    len: usize,
+}
+
+impl<T> Chain<T> {
+    /// Constructs a new empty `Chain<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let chain = Chain::<i32>::new();
+    /// assert!(chain.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Chain { root: None, len: 0 }
+    }
+
+    /// Constructs a new `Chain<T>` with a single element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let chain = Chain::one(1);
+    /// assert_eq!(chain, chain![1]);
+    /// ```
+    #[inline]
+    pub const fn one(x: T) -> Self {
+        Chain {
+            root: Some(Node::One(x)),
+            len: 1,
+        }
+    }
+
+    /// Returns the number of elements in the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(3, chain![1, 2].append(3).len());
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the chain contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(Chain::<i32>::new().is_empty());
+    /// assert!(!chain![1].is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Concatenates two chains in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(chain![1, 2, 3], chain![1].concat(chain![2, 3]));
+    /// ```
+    #[inline]
+    pub fn concat(mut self, mut other: Self) -> Self {
+        let len = self.len + other.len;
+        let root = match (self.root.take(), other.root.take()) {
+            (Some(lhs), Some(rhs)) => Some(Node::Append(Box::new(lhs), Box::new(rhs))),
+            (lhs, None) => lhs,
+            (None, rhs) => rhs,
+        };
+        Chain { root, len }
+    }
+
+    /// Appends an element to the back of the chain in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(chain![1, 2], chain![1].append(2));
+    /// ```
+    #[inline]
+    pub fn append(self, x: T) -> Self {
+        self.concat(Chain::one(x))
+    }
+
+    /// Prepends an element to the front of the chain in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(chain![1, 2], chain![2].prepend(1));
+    /// ```
+    #[inline]
+    pub fn prepend(self, x: T) -> Self {
+        Chain::one(x).concat(self)
+    }
+
+    /// Returns the first element of the chain, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(&1), chain![2].prepend(1).first());
+    /// assert_eq!(None, Chain::<i32>::new().first());
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.iter().next()
+    }
+}
+
+impl<T> Drop for Chain<T> {
+    fn drop(&mut self) {
+        if let Some(root) = self.root.take() {
+            drop_nodes(std::vec![root]);
+        }
+    }
+}
+
+/// Drop the nodes iteratively to avoid overflowing the stack on deeply nested chains.
+fn drop_nodes<T>(mut stack: Vec<Node<T>>) {
+    while let Some(node) = stack.pop() {
+        if let Node::Append(lhs, rhs) = node {
+            stack.push(*lhs);
+            stack.push(*rhs);
+        }
+    }
+}
+
+impl<T> Default for Chain<T> {
+    #[inline]
+    fn default() -> Self {
+        Chain::new()
+    }
+}
+
+impl<T: Clone> Clone for Chain<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: Debug> Debug for Chain<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Chain<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for Chain<T> {}
+
+impl<T: PartialOrd> PartialOrd for Chain<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for Chain<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash> Hash for Chain<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        self.iter().for_each(|x| x.hash(state));
+    }
+}
+
+impl<T> From<Vec<T>> for Chain<T> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        let len = vec.len();
+        let root = if vec.is_empty() {
+            None
+        } else {
+            Some(Node::Many(vec))
+        };
+        Chain { root, len }
+    }
+}
+
+impl<T> From<Chain<T>> for Vec<T> {
+    #[inline]
+    fn from(chain: Chain<T>) -> Self {
+        chain.into_iter().collect()
+    }
+}
+
+/// Creates a [`Chain`] containing the arguments.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let chain = chain![1, 2, 3];
+/// assert_eq!(3, chain.len());
+///
+/// let empty: Chain<i32> = chain![];
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! chain {
+    () => (
+        $crate::data::chain::Chain::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::data::chain::Chain::from(vec![$($x),+])
+    );
+}
+
+higher!(Chain);
+invariant_functor!(Chain<T>);
+functor_iter!(Chain);
+semigroupal_iter!(Chain);
+apply_iter!(Chain);
+flatmap_iter!(Chain);
+and_then_iter!(Chain);
+traverse_iter!(Chain);
+align_iter!(Chain);
+
+impl<A> Pure for Chain<A> {
+    #[inline]
+    fn pure(x: A) -> Self {
+        Chain::one(x)
+    }
+}
+
+impl<T> Semigroup for Chain<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        self.concat(other)
+    }
+}
+
+impl<T> Monoid for Chain<T> {
+    #[inline]
+    fn empty() -> Self {
+        Chain::new()
+    }
+}
@@ -0,0 +1,35 @@
+use ::rayon::prelude::*;
+use ::rayon::vec;
+
+use super::*;
+
+/// Iterates the chain in parallel. The elements are first moved into a [Vec], as the tree
+/// representation of the chain cannot be split efficiently.
+impl<T: Send> IntoParallelIterator for Chain<T> {
+    type Iter = vec::IntoIter<T>;
+    type Item = T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        Vec::from(self).into_par_iter()
+    }
+}
+
+/// Iterates the chain in parallel. References to the elements are first collected into a [Vec],
+/// as the tree representation of the chain cannot be split efficiently.
+impl<'a, T: Sync> IntoParallelIterator for &'a Chain<T> {
+    type Iter = vec::IntoIter<&'a T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+impl<T: Send> FromParallelIterator<T> for Chain<T> {
+    #[inline]
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        Chain::from(par_iter.into_par_iter().collect::<Vec<_>>())
+    }
+}
@@ -0,0 +1,182 @@
+//! Comparators.
+//!
+//! [`Comparator<A>`][Comparator] wraps a function `(A, A) -> Ordering`. Comparators are
+//! contravariant in their input, so they can be adapted with [Comparator::contramap] and combined
+//! with [Divide::divide] and [Decidable::choose].
+//!
+//! Comparators also form a [Monoid] lexicographically, the same way [Ordering] does: combining
+//! two comparators compares with the first one and falls back to the second one on ties. This
+//! allows building multi-key orderings out of single-key ones.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::cmp::Ordering;
+//! use rust2fun::prelude::*;
+//!
+//! let mut by_len = Comparator::natural().contramap(|s: &str| s.len());
+//! assert_eq!(Ordering::Less, by_len.compare("b", "aa"));
+//! assert_eq!(Ordering::Equal, by_len.compare("a", "b"));
+//!
+//! let by_len = Comparator::natural().contramap(|s: &str| s.len());
+//! let mut cmp = by_len.combine(Comparator::natural());
+//! let mut words = vec!["bb", "c", "ab", "a"];
+//! words.sort_by(|x, y| cmp.compare(*x, *y));
+//! assert_eq!(vec!["a", "c", "ab", "bb"], words);
+//! ```
+
+use core::cmp::Ordering;
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+
+use crate::decidable::Decidable;
+use crate::divide::Divide;
+use crate::divisible::Conquer;
+use crate::higher;
+use crate::monoid::Monoid;
+use crate::semigroup::Semigroup;
+
+/// A function `(A, A) -> Ordering`.
+/// See [the module level documentation](self) for more.
+pub struct Comparator<A>(Box<dyn FnMut(A, A) -> Ordering>);
+
+impl<A> Comparator<A> {
+    /// Create a new comparator from the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut cmp = Comparator::new(|x: i32, y: i32| y.cmp(&x));
+    /// assert_eq!(Ordering::Greater, cmp.compare(1, 2));
+    /// ```
+    #[inline]
+    pub fn new(f: impl FnMut(A, A) -> Ordering + 'static) -> Self {
+        Comparator(Box::new(f))
+    }
+
+    /// Create a comparator using the natural order of `A`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut cmp = Comparator::natural();
+    /// assert_eq!(Ordering::Less, cmp.compare(1, 2));
+    /// ```
+    #[inline]
+    pub fn natural() -> Self
+    where
+        A: Ord,
+    {
+        Comparator::new(|x: A, y: A| x.cmp(&y))
+    }
+
+    /// Compare the given values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut cmp = Comparator::new(|x: &str, y: &str| x.len().cmp(&y.len()));
+    /// assert_eq!(Ordering::Equal, cmp.compare("a", "b"));
+    /// ```
+    #[inline]
+    pub fn compare(&mut self, x: A, y: A) -> Ordering {
+        (self.0)(x, y)
+    }
+
+    /// Transform a `Comparator<A>` into a `Comparator<B>` by providing a transformation from `B`
+    /// to `A`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut cmp = Comparator::natural().contramap(|x: i32| x.abs());
+    /// assert_eq!(Ordering::Greater, cmp.compare(-2, 1));
+    /// ```
+    #[inline]
+    pub fn contramap<B>(mut self, mut f: impl FnMut(B) -> A + 'static) -> Comparator<B>
+    where
+        A: 'static,
+    {
+        Comparator::new(move |x, y| {
+            let x = f(x);
+            let y = f(y);
+            self.compare(x, y)
+        })
+    }
+}
+
+impl<A> Debug for Comparator<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Comparator")
+    }
+}
+
+higher!(Comparator);
+
+impl<A: 'static, B: 'static, C: 'static> Divide<B, C> for Comparator<A> {
+    #[inline]
+    fn divide(
+        mut self,
+        mut fb: Comparator<B>,
+        mut f: impl FnMut(C) -> (A, B) + 'static,
+    ) -> Comparator<C> {
+        Comparator::new(move |x, y| {
+            let (xa, xb) = f(x);
+            let (ya, yb) = f(y);
+            self.compare(xa, ya).then_with(|| fb.compare(xb, yb))
+        })
+    }
+}
+
+impl<A> Conquer for Comparator<A> {
+    #[inline]
+    fn conquer() -> Self {
+        Comparator::new(|_, _| Ordering::Equal)
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Decidable<B, C> for Comparator<A> {
+    /// `Ok` values are ordered before `Err` values.
+    #[inline]
+    fn choose(
+        mut self,
+        mut fb: Comparator<B>,
+        mut f: impl FnMut(C) -> Result<A, B> + 'static,
+    ) -> Comparator<C> {
+        Comparator::new(move |x, y| match (f(x), f(y)) {
+            (Ok(x), Ok(y)) => self.compare(x, y),
+            (Err(x), Err(y)) => fb.compare(x, y),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+        })
+    }
+}
+
+impl<A: Clone + 'static> Semigroup for Comparator<A> {
+    #[inline]
+    fn combine(mut self, mut other: Self) -> Self {
+        Comparator::new(move |x: A, y: A| {
+            self.compare(x.clone(), y.clone())
+                .then_with(|| other.compare(x, y))
+        })
+    }
+}
+
+impl<A: Clone + 'static> Monoid for Comparator<A> {
+    #[inline]
+    fn empty() -> Self {
+        Comparator::conquer()
+    }
+}
@@ -0,0 +1,163 @@
+//! Continuation monad.
+//!
+//! [`Cont<R, A>`][Cont] is a computation in continuation-passing style: instead of returning a
+//! value of type `A`, it receives a continuation `A -> R` telling it what to do with that value,
+//! and produces the final result `R`. Having the continuation at hand allows computations to
+//! abort early or to resume several times, with [Cont::call_cc] capturing the current
+//! continuation as a function.
+//!
+//! [Cont::map] and [Cont::flat_map] are [inherent methods](super#inherent-methods) requiring
+//! `'static` functions. A continuation may be called more than once, so these functions are `Fn`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn safe_div(x: i32, y: i32) -> Cont<Result<i32, String>, i32> {
+//!     Cont::call_cc(move |exit| {
+//!         if y == 0 {
+//!             Cont::new(|_| Err("division by zero".to_string()))
+//!         } else {
+//!             exit(x / y)
+//!         }
+//!     })
+//! }
+//!
+//! assert_eq!(Ok(5), safe_div(10, 2).run_cont(Ok));
+//! assert_eq!(Err("division by zero".to_string()), safe_div(1, 0).run_cont(Ok));
+//! ```
+
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::higher::Higher;
+use crate::pure::Pure;
+
+/// A computation taking a continuation `A -> R` and producing the final result `R`.
+/// See [the module level documentation](self) for more.
+#[allow(clippy::type_complexity)]
+pub struct Cont<R, A>(Box<dyn FnOnce(Rc<dyn Fn(A) -> R>) -> R>);
+
+impl<R: 'static, A: 'static> Cont<R, A> {
+    /// Create a new computation from a function taking the continuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let twice = Cont::new(|k: std::rc::Rc<dyn Fn(i32) -> i32>| k(1) + k(2));
+    /// assert_eq!(30, twice.run_cont(|x| x * 10));
+    /// ```
+    #[inline]
+    pub fn new(f: impl FnOnce(Rc<dyn Fn(A) -> R>) -> R + 'static) -> Self {
+        Cont(Box::new(f))
+    }
+
+    /// Run the computation with the given final continuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!("42", Cont::pure(42).run_cont(|x: i32| x.to_string()));
+    /// ```
+    #[inline]
+    pub fn run_cont(self, k: impl Fn(A) -> R + 'static) -> R {
+        (self.0)(Rc::new(k))
+    }
+
+    /// Transform the value passed to the continuation with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(43, Cont::pure(42).map(|x| x + 1).run_cont(|x| x));
+    /// ```
+    #[inline]
+    pub fn map<B: 'static>(self, f: impl Fn(A) -> B + 'static) -> Cont<R, B> {
+        Cont::new(move |k: Rc<dyn Fn(B) -> R>| self.run_cont(move |a| k(f(a))))
+    }
+
+    /// Sequence the computation with another one depending on its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let cont = Cont::pure(20).flat_map(|x| Cont::pure(x + 22));
+    /// assert_eq!(42, cont.run_cont(|x| x));
+    /// ```
+    #[inline]
+    pub fn flat_map<B: 'static>(self, f: impl Fn(A) -> Cont<R, B> + 'static) -> Cont<R, B> {
+        Cont::new(move |k: Rc<dyn Fn(B) -> R>| {
+            self.run_cont(move |a| (f(a).0)(k.clone()))
+        })
+    }
+
+    /// Call a function with the current continuation. Calling the continuation (the "exit"
+    /// function) from within `f` ignores the rest of the computation built in `f` and continues
+    /// with the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let cont = Cont::call_cc(|exit| exit(1).flat_map(|_: ()| Cont::pure(2)));
+    /// assert_eq!(1, cont.run_cont(|x| x));
+    /// ```
+    #[inline]
+    pub fn call_cc<B: 'static>(
+        f: impl FnOnce(Rc<dyn Fn(A) -> Cont<R, B>>) -> Cont<R, A> + 'static,
+    ) -> Self {
+        Cont::new(move |k: Rc<dyn Fn(A) -> R>| {
+            let escape = k.clone();
+            let exit = Rc::new(move |a: A| {
+                let escape = escape.clone();
+                Cont::new(move |_| escape(a))
+            });
+            (f(exit).0)(k)
+        })
+    }
+}
+
+impl<R: 'static> Cont<R, R> {
+    /// Run the computation with the identity continuation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(42, Cont::pure(40).map(|x| x + 2).eval());
+    /// ```
+    #[inline]
+    pub fn eval(self) -> R {
+        self.run_cont(|r| r)
+    }
+}
+
+impl<R, A> Debug for Cont<R, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Cont")
+    }
+}
+
+impl<R, A> Higher for Cont<R, A> {
+    type Param = A;
+    type Target<T> = Cont<R, T>;
+}
+
+impl<R: 'static, A: 'static> Pure for Cont<R, A> {
+    #[inline]
+    fn pure(x: A) -> Self {
+        Cont::new(move |k: Rc<dyn Fn(A) -> R>| k(x))
+    }
+}
@@ -0,0 +1,174 @@
+//! Coyoneda.
+//!
+//! [`Coyoneda<FX, A>`][Coyoneda] pairs a value `F<X>` with a function `X -> A`. It is the free
+//! functor: any type implementing [Higher] can be mapped over with [Coyoneda::map] even if it
+//! doesn't implement [Functor] itself. The mapped functions are composed instead of being applied,
+//! so a chain of maps is fused into a single pass when the value is finally lowered back with
+//! [Coyoneda::lower].
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let xs = Coyoneda::lift(vec![1, 2, 3])
+//!     .map(|x| x + 1)
+//!     .map(|x| x * 2)
+//!     .map(|x| x.to_string());
+//! assert_eq!(vec!["4", "6", "8"], xs.lower());
+//! ```
+//!
+//! Intermediate types don't need to satisfy the constraints of the underlying functor:
+//!
+//! ```
+//! use std::collections::BinaryHeap;
+//! use rust2fun::prelude::*;
+//!
+//! struct NotOrd(i32);
+//!
+//! let heap = Coyoneda::lift(BinaryHeap::from(vec![1, 3, 2]))
+//!     .map(NotOrd)
+//!     .map(|x| x.0 * 10);
+//! assert_eq!(vec![10, 20, 30], heap.lower().into_sorted_vec());
+//! ```
+
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+
+use crate::functor::Functor;
+use crate::higher::Higher;
+
+/// A value `F<X>` with a pending function `X -> A`.
+/// See [the module level documentation](self) for more.
+pub struct Coyoneda<FX: Higher, A> {
+    fx: FX,
+    f: Box<dyn FnMut(FX::Param) -> A>,
+}
+
+impl<FX: Higher> Coyoneda<FX, FX::Param> {
+    /// Lift a value into Coyoneda with the identity function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Coyoneda::lift(Some(1));
+    /// assert_eq!(Some(1), x.lower());
+    /// ```
+    #[inline]
+    pub fn lift(fx: FX) -> Self {
+        Coyoneda {
+            fx,
+            f: Box::new(|x| x),
+        }
+    }
+}
+
+impl<FX: Higher, A> Coyoneda<FX, A> {
+    /// Create a new Coyoneda from the given value and function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Coyoneda::new(vec![1, 2], |x: i32| x * 10);
+    /// assert_eq!(vec![10, 20], x.lower());
+    /// ```
+    #[inline]
+    pub fn new(fx: FX, f: impl FnMut(FX::Param) -> A + 'static) -> Self {
+        Coyoneda { fx, f: Box::new(f) }
+    }
+
+    /// Compose the given function with the pending one without touching the underlying value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Coyoneda::lift(Some(1)).map(|x| x + 1).map(|x| x * 2);
+    /// assert_eq!(Some(4), x.lower());
+    /// ```
+    #[inline]
+    pub fn map<B>(self, mut g: impl FnMut(A) -> B + 'static) -> Coyoneda<FX, B>
+    where
+        FX::Param: 'static,
+        A: 'static,
+    {
+        let mut f = self.f;
+        Coyoneda {
+            fx: self.fx,
+            f: Box::new(move |x| g(f(x))),
+        }
+    }
+
+    /// Apply the pending function to the underlying value in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Coyoneda::lift(Ok::<_, String>(1)).map(|x| x.to_string());
+    /// assert_eq!(Ok("1".to_string()), x.lower());
+    /// ```
+    #[inline]
+    pub fn lower(self) -> FX::Target<A>
+    where
+        FX: Functor<A>,
+    {
+        self.fx.map(self.f)
+    }
+
+    /// Split Coyoneda into the underlying value and the pending function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let (fx, mut f) = Coyoneda::lift(Some(1)).map(|x| x + 1).into_parts();
+    /// assert_eq!(Some(2), fx.map(|x| f(x)));
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(self) -> (FX, Box<dyn FnMut(FX::Param) -> A>) {
+        (self.fx, self.f)
+    }
+}
+
+impl<FX: Higher, A> Debug for Coyoneda<FX, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Coyoneda")
+    }
+}
+
+impl<FX: Higher, A> Higher for Coyoneda<FX, A> {
+    type Param = A;
+    type Target<T> = Coyoneda<FX, T>;
+}
+
+impl<FX, A> IntoIterator for Coyoneda<FX, A>
+where
+    FX: Higher + IntoIterator<Item = <FX as Higher>::Param>,
+{
+    type Item = A;
+    type IntoIter = core::iter::Map<FX::IntoIter, Box<dyn FnMut(<FX as Higher>::Param) -> A>>;
+
+    /// Iterate over the underlying value applying the pending function lazily.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Coyoneda::lift(vec![1, 2, 3]).map(|x| x * 2);
+    /// assert_eq!(6, x.into_iter().take(2).sum::<i32>());
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.fx.into_iter().map(self.f)
+    }
+}
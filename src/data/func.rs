@@ -0,0 +1,194 @@
+//! Functions.
+//!
+//! [`Func<A, B>`][Func] wraps a function `A -> B`. If the result type `B` is a [Semigroup] or a
+//! [Monoid], so is the function: two functions are combined pointwise, by combining their results
+//! for the same input. This makes it possible to build up handlers with [Monoid::combine_all].
+//! For the monoid of functions `A -> A` under composition see [Endo].
+//!
+//! Functions are also a monad in their result, reading a shared input (also known as the reader
+//! monad): [Func::map] post-composes a function, [Func::ap] and [Func::flat_map] feed the same
+//! input to both functions, and [Func::contramap] pre-composes a function on the input.
+//! Like the other boxed types of this crate, these are inherent methods taking `'static`
+//! functions.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let rules = vec![
+//!     Func::new(|x: i32| if x < 0 { vec!["negative"] } else { vec![] }),
+//!     Func::new(|x: i32| if x % 2 != 0 { vec!["odd"] } else { vec![] }),
+//! ];
+//! let mut check = Monoid::combine_all(rules);
+//! assert_eq!(vec!["negative", "odd"], check.run(-3));
+//! assert_eq!(Vec::<&str>::new(), check.run(4));
+//!
+//! let width = Func::new(|(w, _): (u32, u32)| w);
+//! let mut area = width.flat_map(|w| Func::new(move |(_, h): (u32, u32)| w * h));
+//! assert_eq!(6, area.run((2, 3)));
+//! ```
+//!
+//! [Endo]: crate::data::monoids::Endo
+
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+
+use crate::higher::Higher;
+use crate::monoid::Monoid;
+use crate::pure::Pure;
+use crate::semigroup::Semigroup;
+
+/// A function `A -> B`.
+/// See [the module level documentation](self) for more.
+pub struct Func<A, B>(Box<dyn FnMut(A) -> B>);
+
+impl<A, B> Func<A, B> {
+    /// Create a new function wrapper from the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Func::new(|x: i32| x + 1);
+    /// assert_eq!(2, f.run(1));
+    /// ```
+    #[inline]
+    pub fn new(f: impl FnMut(A) -> B + 'static) -> Self {
+        Func(Box::new(f))
+    }
+
+    /// Apply the function to the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Func::new(|s: &str| s.len());
+    /// assert_eq!(3, f.run("foo"));
+    /// ```
+    #[inline]
+    pub fn run(&mut self, a: A) -> B {
+        (self.0)(a)
+    }
+
+    /// Unwrap the underlying function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Func::new(|x: i32| x * 2).into_inner();
+    /// assert_eq!(4, f(2));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> Box<dyn FnMut(A) -> B> {
+        self.0
+    }
+}
+
+impl<A: 'static, B: 'static> Func<A, B> {
+    /// Transform the result of the function with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Func::new(|x: i32| x + 1).map(|x| x.to_string());
+    /// assert_eq!("2", f.run(1));
+    /// ```
+    #[inline]
+    pub fn map<C>(mut self, mut f: impl FnMut(B) -> C + 'static) -> Func<A, C> {
+        Func::new(move |a| f(self.run(a)))
+    }
+
+    /// Transform the input of the function with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Func::new(|x: usize| x * 2).contramap(|s: &str| s.len());
+    /// assert_eq!(6, f.run("foo"));
+    /// ```
+    #[inline]
+    pub fn contramap<Z>(mut self, mut f: impl FnMut(Z) -> A + 'static) -> Func<Z, B> {
+        Func::new(move |z| self.run(f(z)))
+    }
+
+    /// Apply the function returned by `ff` to the result of `self`, both run on the same input.
+    /// This is the S (Starling) combinator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let ff = Func::new(|x: i32| move |y: i32| x * y);
+    /// let mut f = Func::new(|x: i32| x + 1).ap(ff);
+    /// assert_eq!(12, f.run(3));
+    /// ```
+    #[inline]
+    pub fn ap<C, F>(mut self, mut ff: Func<A, F>) -> Func<A, C>
+    where
+        A: Clone,
+        F: FnMut(B) -> C + 'static,
+    {
+        Func::new(move |a: A| ff.run(a.clone())(self.run(a)))
+    }
+
+    /// Run the function returned by `f` for the result of `self` on the same input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Func::new(|x: i32| x * 2).flat_map(|y| Func::new(move |x: i32| x + y));
+    /// assert_eq!(9, f.run(3));
+    /// ```
+    #[inline]
+    pub fn flat_map<C>(mut self, mut f: impl FnMut(B) -> Func<A, C> + 'static) -> Func<A, C>
+    where
+        A: Clone,
+    {
+        Func::new(move |a: A| f(self.run(a.clone())).run(a))
+    }
+}
+
+impl<A, B> Debug for Func<A, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Func")
+    }
+}
+
+impl<A, B> Higher for Func<A, B> {
+    type Param = B;
+    type Target<T> = Func<A, T>;
+}
+
+impl<A, B: Clone + 'static> Pure for Func<A, B> {
+    #[inline]
+    fn pure(x: B) -> Self {
+        Func::new(move |_| x.clone())
+    }
+}
+
+impl<A: Clone + 'static, B: Semigroup + 'static> Semigroup for Func<A, B> {
+    #[inline]
+    fn combine(mut self, mut other: Self) -> Self {
+        Func::new(move |a: A| self.run(a.clone()).combine(other.run(a)))
+    }
+}
+
+impl<A: Clone + 'static, B: Monoid + 'static> Monoid for Func<A, B> {
+    #[inline]
+    fn empty() -> Self {
+        Func::new(|_| B::empty())
+    }
+}
@@ -0,0 +1,242 @@
+//! Suspended side effects.
+//!
+//! [`IO<A>`][IO] describes a computation producing a value of type `A`, possibly performing side
+//! effects along the way. Nothing happens until the computation is [run](IO::run), so programs
+//! built out of `IO` values can be composed, reused and reasoned about like plain values.
+//!
+//! The interpreter runs in a loop with an explicit stack of continuations, so arbitrarily long
+//! chains of [IO::flat_map], including recursive ones built with [IO::suspend], don't overflow
+//! the stack.
+//!
+//! [IO::map] and [IO::flat_map] are [inherent methods](super#inherent-methods) requiring
+//! `'static` functions.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use rust2fun::prelude::*;
+//!
+//! let log = Rc::new(RefCell::new(Vec::new()));
+//! let say = {
+//!     let log = log.clone();
+//!     move |msg: String| {
+//!         let log = log.clone();
+//!         IO::delay(move || log.borrow_mut().push(msg))
+//!     }
+//! };
+//!
+//! let program = bind! {
+//!     for _ in inherent(say("What's your name?".to_string()));
+//!     for name in inherent(IO::delay(|| "Alice".to_string()));
+//!     for _ in inherent(say(format!("Hello, {name}!")));
+//!     name.len()
+//! };
+//! assert!(log.borrow().is_empty());
+//!
+//! assert_eq!(5, program.run());
+//! assert_eq!(vec!["What's your name?", "Hello, Alice!"], *log.borrow());
+//! ```
+
+use core::any::Any;
+use core::marker::PhantomData;
+use core::mem;
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::vec::Vec;
+
+use crate::higher;
+use crate::pure::Pure;
+
+type Value = Box<dyn Any>;
+type Continuation = Box<dyn FnOnce(Value) -> Raw>;
+
+/// Type-erased representation of a computation interpreted by [IO::run].
+enum Raw {
+    Pure(Value),
+    Delay(Box<dyn FnOnce() -> Value>),
+    FlatMap(Box<Raw>, Continuation),
+}
+
+/// A suspended computation producing a value of type `A`.
+/// See [the module level documentation](self) for more.
+pub struct IO<A> {
+    raw: Raw,
+    _marker: PhantomData<A>,
+}
+
+impl<A: 'static> IO<A> {
+    #[inline]
+    fn from_raw(raw: Raw) -> Self {
+        IO {
+            raw,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn into_raw(mut self) -> Raw {
+        mem::replace(&mut self.raw, Raw::Pure(Box::new(())))
+    }
+
+    /// Suspend a side effect producing a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use std::rc::Rc;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let counter = Rc::new(Cell::new(0));
+    /// let c = counter.clone();
+    /// let io = IO::delay(move || c.replace(c.get() + 1));
+    /// assert_eq!(0, counter.get());
+    ///
+    /// assert_eq!(0, io.run());
+    /// assert_eq!(1, counter.get());
+    /// ```
+    #[inline]
+    pub fn delay(f: impl FnOnce() -> A + 'static) -> Self {
+        IO::from_raw(Raw::Delay(Box::new(move || Box::new(f()) as Value)))
+    }
+
+    /// Suspend the construction of an `IO` value. This is useful to define recursive
+    /// computations without evaluating them eagerly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// fn sum(n: u64, acc: u64) -> IO<u64> {
+    ///     if n == 0 {
+    ///         IO::pure(acc)
+    ///     } else {
+    ///         IO::suspend(move || sum(n - 1, acc + n))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(50005000, sum(10000, 0).run());
+    /// ```
+    #[inline]
+    pub fn suspend(f: impl FnOnce() -> IO<A> + 'static) -> Self {
+        IO::from_raw(Raw::FlatMap(
+            Box::new(Raw::Pure(Box::new(()))),
+            Box::new(move |_| f().into_raw()),
+        ))
+    }
+
+    /// Transform the result of the computation with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!("42", IO::pure(42).map(|x| x.to_string()).run());
+    /// ```
+    #[inline]
+    pub fn map<B: 'static>(self, f: impl FnOnce(A) -> B + 'static) -> IO<B> {
+        self.flat_map(move |a| IO::pure(f(a)))
+    }
+
+    /// Sequence the computation with another one depending on its result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let io = IO::delay(|| 20).flat_map(|x| IO::delay(move || x + 22));
+    /// assert_eq!(42, io.run());
+    /// ```
+    #[inline]
+    pub fn flat_map<B: 'static>(self, f: impl FnOnce(A) -> IO<B> + 'static) -> IO<B> {
+        IO::from_raw(Raw::FlatMap(
+            Box::new(self.into_raw()),
+            Box::new(move |a| f(downcast(a)).into_raw()),
+        ))
+    }
+
+    /// Materialize a panic raised while running the computation into a [Result].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(42, IO::pure(42).attempt().run().unwrap());
+    ///
+    /// let io = IO::delay(|| -> i32 { panic!("boom") }).attempt();
+    /// assert_eq!(Some(&"boom"), io.run().unwrap_err().downcast_ref::<&str>());
+    /// ```
+    #[inline]
+    pub fn attempt(self) -> IO<Result<A, Box<dyn Any + Send>>> {
+        IO::delay(move || catch_unwind(AssertUnwindSafe(move || self.run())))
+    }
+
+    /// Run the computation, performing its side effects, and return the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let io = (0..100000).fold(IO::pure(0), |io, i| io.map(move |x| x + i));
+    /// assert_eq!(4999950000i64, io.run());
+    /// ```
+    pub fn run(self) -> A {
+        let mut stack: Vec<Continuation> = Vec::new();
+        let mut current = self.into_raw();
+        loop {
+            let value = match current {
+                Raw::Pure(value) => value,
+                Raw::Delay(f) => f(),
+                Raw::FlatMap(io, k) => {
+                    stack.push(k);
+                    current = *io;
+                    continue;
+                }
+            };
+            match stack.pop() {
+                Some(k) => current = k(value),
+                None => return downcast(value),
+            }
+        }
+    }
+}
+
+#[inline]
+fn downcast<A: 'static>(value: Value) -> A {
+    *value
+        .downcast()
+        .unwrap_or_else(|_| unreachable!("IO value of an unexpected type"))
+}
+
+impl<A> Drop for IO<A> {
+    fn drop(&mut self) {
+        // Drop nested computations iteratively to avoid overflowing the stack.
+        let mut current = mem::replace(&mut self.raw, Raw::Pure(Box::new(())));
+        while let Raw::FlatMap(io, _) = current {
+            current = *io;
+        }
+    }
+}
+
+impl<A> Debug for IO<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("IO")
+    }
+}
+
+higher!(IO);
+
+impl<A: 'static> Pure for IO<A> {
+    #[inline]
+    fn pure(x: A) -> Self {
+        IO::from_raw(Raw::Pure(Box::new(x)))
+    }
+}
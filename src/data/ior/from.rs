@@ -0,0 +1,29 @@
+use super::*;
+
+impl<A, B> From<Result<B, A>> for Ior<A, B> {
+    #[inline]
+    fn from(result: Result<B, A>) -> Self {
+        match result {
+            Ok(b) => Ior::Right(b),
+            Err(a) => Ior::Left(a),
+        }
+    }
+}
+
+impl<A, B> From<Validated<B, A>> for Ior<A, B> {
+    #[inline]
+    fn from(validated: Validated<B, A>) -> Self {
+        validated.into_result().into()
+    }
+}
+
+#[cfg(feature = "either")]
+impl<A, B> From<either::Either<A, B>> for Ior<A, B> {
+    #[inline]
+    fn from(either: either::Either<A, B>) -> Self {
+        match either {
+            either::Either::Right(b) => Ior::Right(b),
+            either::Either::Left(a) => Ior::Left(a),
+        }
+    }
+}
@@ -0,0 +1,378 @@
+//! Inclusive-or with the `Ior` type.
+//!
+//! [`Ior<A, B>`][Ior] represents a value that is either a [`Left`](Ior::Left) value, a
+//! [`Right`](Ior::Right) value, or [`Both`](Ior::Both) values at the same time.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let xs = vec![1, 2, 3];
+//! let ys = vec!["a"];
+//! assert_eq!(
+//!     vec![Ior::Both(1, "a"), Ior::Left(2), Ior::Left(3)],
+//!     xs.align(ys)
+//! );
+//! ```
+//!
+//! `Ior` is a monad over the right value which accumulates the left values using their
+//! [Semigroup] instance. This makes it the natural type for computations that may succeed with
+//! warnings: [`Left`](Ior::Left) stops the computation like an error, while
+//! [`Both`](Ior::Both) carries on with the right value and keeps the warnings.
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn parse(s: &str) -> Ior<Vec<String>, i32> {
+//!     match s.trim().parse::<i32>() {
+//!         Ok(x) if s.trim() != s => Ior::Both(vec![format!("trimmed '{}'", s)], x),
+//!         Ok(x) => Ior::Right(x),
+//!         Err(e) => Ior::Left(vec![e.to_string()]),
+//!     }
+//! }
+//!
+//! let sum = parse(" 1").flat_map(|x| parse("2 ").map(|y| x + y));
+//! assert_eq!(
+//!     Ior::Both(vec!["trimmed ' 1'".to_string(), "trimmed '2 '".to_string()], 3),
+//!     sum
+//! );
+//!
+//! let sum = parse("1").flat_map(|x| parse("foo").map(|y| x + y));
+//! assert_eq!(Ior::Left(vec!["invalid digit found in string".to_string()]), sum);
+//! ```
+
+use crate::and_then::AndThen;
+use crate::apply::Apply;
+use crate::bifunctor::Bifunctor;
+use crate::data::validated::Validated;
+use crate::flatmap::FlatMap;
+use crate::functor::Functor;
+use crate::higher::{Higher, Higher2};
+use crate::invariant_functor;
+use crate::pure::Pure;
+use crate::semigroup::Semigroup;
+use crate::semigroupal::Semigroupal;
+
+mod from;
+
+/// `Ior` is a type that represents a `Left` value, a `Right` value or `Both` of them.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ior<A, B> {
+    /// Contains only the left value.
+    Left(A),
+    /// Contains only the right value.
+    Right(B),
+    /// Contains both the left and the right values.
+    Both(A, B),
+}
+
+impl<A, B> Ior<A, B> {
+    /// Returns `true` if the `Ior` is [`Left`](Ior::Left).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<i32, &str> = Ior::Left(1);
+    /// assert_eq!(x.is_left(), true);
+    ///
+    /// let x: Ior<i32, &str> = Ior::Both(1, "a");
+    /// assert_eq!(x.is_left(), false);
+    /// ```
+    #[inline]
+    pub const fn is_left(&self) -> bool {
+        matches!(*self, Ior::Left(_))
+    }
+
+    /// Returns `true` if the `Ior` is [`Right`](Ior::Right).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<i32, &str> = Ior::Right("a");
+    /// assert_eq!(x.is_right(), true);
+    ///
+    /// let x: Ior<i32, &str> = Ior::Both(1, "a");
+    /// assert_eq!(x.is_right(), false);
+    /// ```
+    #[inline]
+    pub const fn is_right(&self) -> bool {
+        matches!(*self, Ior::Right(_))
+    }
+
+    /// Returns `true` if the `Ior` is [`Both`](Ior::Both).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<i32, &str> = Ior::Both(1, "a");
+    /// assert_eq!(x.is_both(), true);
+    ///
+    /// let x: Ior<i32, &str> = Ior::Left(1);
+    /// assert_eq!(x.is_both(), false);
+    /// ```
+    #[inline]
+    pub const fn is_both(&self) -> bool {
+        matches!(*self, Ior::Both(_, _))
+    }
+
+    /// Converts from `Ior<A, B>` to [`Option<A>`], discarding the right value, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<i32, &str> = Ior::Both(1, "a");
+    /// assert_eq!(x.left(), Some(1));
+    ///
+    /// let x: Ior<i32, &str> = Ior::Right("a");
+    /// assert_eq!(x.left(), None);
+    /// ```
+    #[inline]
+    pub fn left(self) -> Option<A> {
+        match self {
+            Ior::Left(a) | Ior::Both(a, _) => Some(a),
+            Ior::Right(_) => None,
+        }
+    }
+
+    /// Converts from `Ior<A, B>` to [`Option<B>`], discarding the left value, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<i32, &str> = Ior::Both(1, "a");
+    /// assert_eq!(x.right(), Some("a"));
+    ///
+    /// let x: Ior<i32, &str> = Ior::Left(1);
+    /// assert_eq!(x.right(), None);
+    /// ```
+    #[inline]
+    pub fn right(self) -> Option<B> {
+        match self {
+            Ior::Right(b) | Ior::Both(_, b) => Some(b),
+            Ior::Left(_) => None,
+        }
+    }
+
+    /// Converts from `Ior<A, B>` to a pair of [`Option`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<i32, &str> = Ior::Both(1, "a");
+    /// assert_eq!(x.pad(), (Some(1), Some("a")));
+    ///
+    /// let x: Ior<i32, &str> = Ior::Left(1);
+    /// assert_eq!(x.pad(), (Some(1), None));
+    /// ```
+    #[inline]
+    pub fn pad(self) -> (Option<A>, Option<B>) {
+        match self {
+            Ior::Left(a) => (Some(a), None),
+            Ior::Right(b) => (None, Some(b)),
+            Ior::Both(a, b) => (Some(a), Some(b)),
+        }
+    }
+
+    /// Swaps the left and the right values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<i32, &str> = Ior::Both(1, "a");
+    /// assert_eq!(x.swap(), Ior::Both("a", 1));
+    ///
+    /// let x: Ior<i32, &str> = Ior::Left(1);
+    /// assert_eq!(x.swap(), Ior::Right(1));
+    /// ```
+    #[inline]
+    pub fn swap(self) -> Ior<B, A> {
+        match self {
+            Ior::Left(a) => Ior::Right(a),
+            Ior::Right(b) => Ior::Left(b),
+            Ior::Both(a, b) => Ior::Both(b, a),
+        }
+    }
+
+    /// Creates an `Ior` from a pair of [`Option`]s. Returns [`None`] if both are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Ior::from_options(Some(1), Some("a")), Some(Ior::Both(1, "a")));
+    /// assert_eq!(Ior::from_options(None, Some("a")), Some(Ior::<i32, _>::Right("a")));
+    /// assert_eq!(Ior::<i32, &str>::from_options(None, None), None);
+    /// ```
+    #[inline]
+    pub fn from_options(left: Option<A>, right: Option<B>) -> Option<Self> {
+        match (left, right) {
+            (Some(a), Some(b)) => Some(Ior::Both(a, b)),
+            (Some(a), None) => Some(Ior::Left(a)),
+            (None, Some(b)) => Some(Ior::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    /// Converts the `Ior` into a [Result], discarding the left value of [`Both`](Ior::Both).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<&str, i32> = Ior::Both("warning", 1);
+    /// assert_eq!(Ok(1), x.into_result());
+    ///
+    /// let x: Ior<&str, i32> = Ior::Left("error");
+    /// assert_eq!(Err("error"), x.into_result());
+    /// ```
+    #[inline]
+    pub fn into_result(self) -> Result<B, A> {
+        match self {
+            Ior::Left(a) => Err(a),
+            Ior::Right(b) | Ior::Both(_, b) => Ok(b),
+        }
+    }
+
+    /// Converts the `Ior` into a [Validated], discarding the left value of
+    /// [`Both`](Ior::Both).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Ior<&str, i32> = Ior::Both("warning", 1);
+    /// assert_eq!(Valid(1), x.into_validated());
+    ///
+    /// let x: Ior<&str, i32> = Ior::Left("error");
+    /// assert_eq!(Invalid("error"), x.into_validated());
+    /// ```
+    #[inline]
+    pub fn into_validated(self) -> Validated<B, A> {
+        self.into_result().into()
+    }
+}
+
+impl<A, P> Higher for Ior<A, P> {
+    type Param = P;
+    type Target<T> = Ior<A, T>;
+}
+
+impl<A, B> Higher2 for Ior<A, B> {
+    type Param1 = A;
+    type Param2 = B;
+    type Target<TA, TB> = Ior<TA, TB>;
+}
+
+invariant_functor!(Ior<A, T>);
+
+impl<A, B, C> Functor<C> for Ior<A, B> {
+    #[inline]
+    fn map(self, mut f: impl FnMut(B) -> C) -> Ior<A, C> {
+        match self {
+            Ior::Left(a) => Ior::Left(a),
+            Ior::Right(b) => Ior::Right(f(b)),
+            Ior::Both(a, b) => Ior::Both(a, f(b)),
+        }
+    }
+}
+
+impl<A: Semigroup, B, C> Semigroupal<C> for Ior<A, B> {
+    #[inline]
+    fn product(self, fc: Ior<A, C>) -> Ior<A, (B, C)> {
+        match (self, fc) {
+            (Ior::Left(a), _) => Ior::Left(a),
+            (Ior::Right(_), Ior::Left(a)) => Ior::Left(a),
+            (Ior::Right(b), Ior::Right(c)) => Ior::Right((b, c)),
+            (Ior::Right(b), Ior::Both(a, c)) => Ior::Both(a, (b, c)),
+            (Ior::Both(a1, _), Ior::Left(a2)) => Ior::Left(a1.combine(a2)),
+            (Ior::Both(a, b), Ior::Right(c)) => Ior::Both(a, (b, c)),
+            (Ior::Both(a1, b), Ior::Both(a2, c)) => Ior::Both(a1.combine(a2), (b, c)),
+        }
+    }
+}
+
+impl<A: Semigroup, F, B, C> Apply<B, C> for Ior<A, F> {
+    #[inline]
+    fn ap(self, fb: Ior<A, B>) -> Ior<A, C>
+    where
+        F: FnOnce(B) -> C,
+    {
+        self.product(fb).map(|(f, b)| f(b))
+    }
+}
+
+impl<A: Semigroup, B> Pure for Ior<A, B> {
+    #[inline]
+    fn pure(x: B) -> Self {
+        Ior::Right(x)
+    }
+}
+
+impl<A: Semigroup, B, C> FlatMap<C> for Ior<A, B> {
+    #[inline]
+    fn flat_map<F>(self, mut f: F) -> Ior<A, C>
+    where
+        F: FnMut(B) -> Ior<A, C>,
+    {
+        match self {
+            Ior::Left(a) => Ior::Left(a),
+            Ior::Right(b) => f(b),
+            Ior::Both(a, b) => match f(b) {
+                Ior::Left(a2) => Ior::Left(a.combine(a2)),
+                Ior::Right(c) => Ior::Both(a, c),
+                Ior::Both(a2, c) => Ior::Both(a.combine(a2), c),
+            },
+        }
+    }
+}
+
+impl<A: Semigroup, B, C> AndThen<C> for Ior<A, B> {
+    #[inline]
+    fn and_then<F>(self, f: F) -> Ior<A, C>
+    where
+        F: FnMut(B) -> Ior<A, C>,
+    {
+        self.flat_map(f)
+    }
+}
+
+impl<A, B, C, D> Bifunctor<C, D> for Ior<A, B> {
+    #[inline]
+    fn bimap(self, mut f: impl FnMut(A) -> C, mut g: impl FnMut(B) -> D) -> Ior<C, D> {
+        match self {
+            Ior::Left(a) => Ior::Left(f(a)),
+            Ior::Right(b) => Ior::Right(g(b)),
+            Ior::Both(a, b) => Ior::Both(f(a), g(b)),
+        }
+    }
+}
+
+impl<A: Semigroup, B: Semigroup> Semigroup for Ior<A, B> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        let (a1, b1) = self.pad();
+        let (a2, b2) = other.pad();
+        Ior::from_options(a1.combine(a2), b1.combine(b2)).expect("Ior has at least one value")
+    }
+}
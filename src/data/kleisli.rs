@@ -0,0 +1,231 @@
+//! Kleisli arrows.
+//!
+//! [`Kleisli<A, FB>`][Kleisli] wraps a function `A -> F<B>` returning an effectful value. Kleisli
+//! arrows compose like plain functions, with [FlatMap] taking care of threading the effects,
+//! which makes them an [Arrow] for any monad.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let parse = Kleisli::new(|s: &str| s.parse::<i32>().ok());
+//! let reciprocal = Kleisli::new(|x: i32| if x != 0 { Some(1.0 / x as f64) } else { None });
+//! let mut f = parse.and_then(reciprocal);
+//! assert_eq!(Some(0.5), f.run("2"));
+//! assert_eq!(None, f.run("0"));
+//! assert_eq!(None, f.run("foo"));
+//! ```
+//!
+//! Plain effectful functions can also be composed without wrapping them, using [kcompose!](crate::kcompose).
+
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+
+use crate::arrow::{Arrow, Category};
+use crate::flatmap::FlatMap;
+use crate::functor::Functor;
+use crate::higher::{Higher, Higher2};
+use crate::profunctor::{Choice, Profunctor, Strong};
+use crate::pure::Pure;
+use crate::semigroupal::Semigroupal;
+
+/// A function `A -> F<B>` returning an effectful value `FB`.
+/// See [the module level documentation](self) for more.
+pub struct Kleisli<A, FB>(Box<dyn FnMut(A) -> FB>);
+
+impl<A, FB> Kleisli<A, FB> {
+    /// Create a new Kleisli arrow from the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut k = Kleisli::new(|x: i32| Some(x + 1));
+    /// assert_eq!(Some(2), k.run(1));
+    /// ```
+    #[inline]
+    pub fn new(f: impl FnMut(A) -> FB + 'static) -> Self {
+        Kleisli(Box::new(f))
+    }
+
+    /// Run the arrow on the given input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut k = Kleisli::new(|x: i32| vec![x, x * 2]);
+    /// assert_eq!(vec![1, 2], k.run(1));
+    /// ```
+    #[inline]
+    pub fn run(&mut self, a: A) -> FB {
+        (self.0)(a)
+    }
+
+    /// Unwrap the underlying function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut f = Kleisli::new(|x: i32| Some(x + 1)).into_inner();
+    /// assert_eq!(Some(2), f(1));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> Box<dyn FnMut(A) -> FB> {
+        self.0
+    }
+}
+
+impl<A, FB> Debug for Kleisli<A, FB> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Kleisli")
+    }
+}
+
+impl<A, FB: Higher> Higher2 for Kleisli<A, FB> {
+    type Param1 = A;
+    type Param2 = FB::Param;
+    type Target<T1, T2> = Kleisli<T1, FB::Target<T2>>;
+}
+
+impl<A, FB, C> Category<C> for Kleisli<A, FB>
+where
+    A: 'static,
+    FB: FlatMap<C> + Higher<Target<<FB as Higher>::Param> = FB> + 'static,
+    FB::Target<C>: Pure + 'static,
+    FB::Target<A>: FlatMap<<FB as Higher>::Param, Target<<FB as Higher>::Param> = FB> + 'static,
+    C: 'static,
+{
+    #[inline]
+    fn id() -> Kleisli<C, FB::Target<C>> {
+        Kleisli::new(Pure::pure)
+    }
+
+    #[inline]
+    fn compose(
+        mut self,
+        mut g: Kleisli<C, FB::Target<A>>,
+    ) -> Kleisli<C, FB::Target<FB::Param>> {
+        Kleisli::new(move |c| g.run(c).flat_map(|a| self.run(a)))
+    }
+
+    #[inline]
+    fn and_then(mut self, mut g: Kleisli<FB::Param, FB::Target<C>>) -> Kleisli<A, FB::Target<C>> {
+        Kleisli::new(move |a| self.run(a).flat_map(|b| g.run(b)))
+    }
+}
+
+impl<A, FB, C, D> Arrow<C, D> for Kleisli<A, FB>
+where
+    A: 'static,
+    FB: FlatMap<C>
+        + Semigroupal<D>
+        + Functor<<FB as Higher>::Param>
+        + Functor<(<FB as Higher>::Param, C)>
+        + Functor<(C, <FB as Higher>::Param)>
+        + Higher<Target<<FB as Higher>::Param> = FB>
+        + 'static,
+    FB::Target<A>: FlatMap<<FB as Higher>::Param, Target<<FB as Higher>::Param> = FB> + 'static,
+    FB::Target<C>: Pure + 'static,
+    FB::Target<D>: Pure + 'static,
+    FB::Target<(<FB as Higher>::Param, C)>: 'static,
+    FB::Target<(C, <FB as Higher>::Param)>: 'static,
+    FB::Target<(<FB as Higher>::Param, D)>: 'static,
+    C: Clone + 'static,
+    D: 'static,
+{
+    #[inline]
+    fn arr(mut f: impl FnMut(C) -> D + 'static) -> Kleisli<C, FB::Target<D>> {
+        Kleisli::new(move |c| Pure::pure(f(c)))
+    }
+
+    #[inline]
+    fn split(
+        mut self,
+        mut g: Kleisli<C, FB::Target<D>>,
+    ) -> Kleisli<(A, C), FB::Target<(FB::Param, D)>> {
+        Kleisli::new(move |(a, c)| self.run(a).product(g.run(c)))
+    }
+
+    #[inline]
+    fn fanout(
+        mut self,
+        mut g: Kleisli<C, FB::Target<D>>,
+    ) -> Kleisli<C, FB::Target<(FB::Param, D)>>
+    where
+        C: Clone + Into<A>,
+    {
+        Kleisli::new(move |c: C| self.run(c.clone().into()).product(g.run(c)))
+    }
+}
+
+impl<A, FB, C, D> Profunctor<C, D> for Kleisli<A, FB>
+where
+    A: 'static,
+    FB: Functor<D> + 'static,
+    FB::Target<D>: 'static,
+{
+    #[inline]
+    fn dimap(
+        mut self,
+        mut f: impl FnMut(C) -> A + 'static,
+        mut g: impl FnMut(FB::Param) -> D + 'static,
+    ) -> Kleisli<C, FB::Target<D>> {
+        Kleisli::new(move |c| self.run(f(c)).map(&mut g))
+    }
+}
+
+impl<A, FB, X> Strong<X> for Kleisli<A, FB>
+where
+    A: 'static,
+    FB: Functor<<FB as Higher>::Param>
+        + Functor<(<FB as Higher>::Param, X)>
+        + Functor<(X, <FB as Higher>::Param)>
+        + 'static,
+    FB::Target<(<FB as Higher>::Param, X)>: 'static,
+    FB::Target<(X, <FB as Higher>::Param)>: 'static,
+    X: Clone + 'static,
+{
+    #[inline]
+    fn first(mut self) -> Kleisli<(A, X), FB::Target<(FB::Param, X)>> {
+        Kleisli::new(move |(a, x): (A, X)| self.run(a).map(|b| (b, x.clone())))
+    }
+
+    #[inline]
+    fn second(mut self) -> Kleisli<(X, A), FB::Target<(X, FB::Param)>> {
+        Kleisli::new(move |(x, a): (X, A)| self.run(a).map(|b| (x.clone(), b)))
+    }
+}
+
+impl<A, FB, X> Choice<X> for Kleisli<A, FB>
+where
+    A: 'static,
+    FB: Functor<<FB as Higher>::Param>
+        + Functor<Result<X, <FB as Higher>::Param>>
+        + Functor<Result<<FB as Higher>::Param, X>>
+        + 'static,
+    FB::Target<Result<X, <FB as Higher>::Param>>: Pure + 'static,
+    FB::Target<Result<<FB as Higher>::Param, X>>: Pure + 'static,
+    X: 'static,
+{
+    #[inline]
+    fn left(mut self) -> Kleisli<Result<X, A>, FB::Target<Result<X, FB::Param>>> {
+        Kleisli::new(move |r| match r {
+            Ok(x) => Pure::pure(Ok(x)),
+            Err(a) => self.run(a).map(Err),
+        })
+    }
+
+    #[inline]
+    fn right(mut self) -> Kleisli<Result<A, X>, FB::Target<Result<FB::Param, X>>> {
+        Kleisli::new(move |r| match r {
+            Ok(a) => self.run(a).map(Ok),
+            Err(x) => Pure::pure(Err(x)),
+        })
+    }
+}
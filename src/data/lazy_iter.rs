@@ -0,0 +1,179 @@
+//! Lazy iterators.
+//!
+//! [`LazyIter<'a, A>`][LazyIter] wraps any [Iterator] producing values of type `A`. Unlike the
+//! instances for [Vec] and the other collections, which collect a new collection at every step,
+//! the operations on `LazyIter` build iterator adapters: nothing is computed and nothing is
+//! allocated until the elements are consumed.
+//!
+//! [LazyIter::map], [LazyIter::flat_map] and [LazyIter::cartesian_product] behave like
+//! [Functor::map], [FlatMap::flat_map] and [Semigroupal::product] for [Vec], but `LazyIter` has no
+//! instances of these typeclasses: they are [inherent methods](super#inherent-methods) requiring
+//! functions living as long as the iterator. [Semigroup] and [Monoid] chain iterators one after
+//! another.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::cell::Cell;
+//! use rust2fun::prelude::*;
+//!
+//! let calls = Cell::new(0);
+//! let mut xs = LazyIter::new(1..)
+//!     .map(|x| {
+//!         calls.set(calls.get() + 1);
+//!         x * 2
+//!     })
+//!     .flat_map(|x| [x, x + 1])
+//!     .combine(LazyIter::pure(0));
+//! assert_eq!(0, calls.get());
+//!
+//! assert_eq!(vec![2, 3, 4], xs.by_ref().take(3).collect::<Vec<_>>());
+//! assert_eq!(2, calls.get());
+//! ```
+//!
+//! [Functor::map]: crate::functor::Functor::map
+//! [FlatMap::flat_map]: crate::flatmap::FlatMap::flat_map
+//! [Semigroupal::product]: crate::semigroupal::Semigroupal::product
+
+use core::iter;
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use std::vec::Vec;
+
+use crate::higher::Higher;
+use crate::monoid::Monoid;
+use crate::pure::Pure;
+use crate::semigroup::Semigroup;
+
+/// A lazy iterator producing values of type `A`.
+/// See [the module level documentation](self) for more.
+pub struct LazyIter<'a, A>(Box<dyn Iterator<Item = A> + 'a>);
+
+impl<'a, A: 'a> LazyIter<'a, A> {
+    /// Create a new lazy iterator from the given iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = LazyIter::new(vec![1, 2, 3]);
+    /// assert_eq!(vec![1, 2, 3], xs.collect::<Vec<_>>());
+    /// ```
+    #[inline]
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        I::IntoIter: 'a,
+    {
+        LazyIter(Box::new(iter.into_iter()))
+    }
+
+    /// Lazily transform each element with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = LazyIter::new(1..4).map(|x| x.to_string());
+    /// assert_eq!(vec!["1", "2", "3"], xs.collect::<Vec<_>>());
+    /// ```
+    #[inline]
+    pub fn map<B>(self, f: impl FnMut(A) -> B + 'a) -> LazyIter<'a, B> {
+        LazyIter(Box::new(self.0.map(f)))
+    }
+
+    /// Lazily replace each element with the elements of the iterable returned by the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = LazyIter::new(1..4).flat_map(|x| LazyIter::new(0..x));
+    /// assert_eq!(vec![0, 0, 1, 0, 1, 2], xs.collect::<Vec<_>>());
+    /// ```
+    #[inline]
+    pub fn flat_map<B, I>(self, f: impl FnMut(A) -> I + 'a) -> LazyIter<'a, B>
+    where
+        I: IntoIterator<Item = B> + 'a,
+    {
+        LazyIter(Box::new(self.0.flat_map(f)))
+    }
+
+    /// Lazily pair each element with each element of `fb`. The elements of `fb` are buffered
+    /// once the first pair is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = LazyIter::new(1..3).cartesian_product(LazyIter::new("ab".chars()));
+    /// assert_eq!(vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')], xs.collect::<Vec<_>>());
+    /// ```
+    #[inline]
+    pub fn cartesian_product<B: Clone + 'a>(self, fb: LazyIter<'a, B>) -> LazyIter<'a, (A, B)>
+    where
+        A: Clone,
+    {
+        let mut fb = Some(fb);
+        let mut bs = Rc::new(Vec::new());
+        self.flat_map(move |a: A| {
+            if let Some(fb) = fb.take() {
+                bs = Rc::new(fb.collect());
+            }
+            let bs = bs.clone();
+            (0..bs.len()).map(move |i| (a.clone(), bs[i].clone()))
+        })
+    }
+}
+
+impl<'a, A> Iterator for LazyIter<'a, A> {
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, A> Debug for LazyIter<'a, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LazyIter")
+    }
+}
+
+impl<'a, A> Higher for LazyIter<'a, A> {
+    type Param = A;
+    type Target<T> = LazyIter<'a, T>;
+}
+
+impl<'a, A: 'a> Pure for LazyIter<'a, A> {
+    #[inline]
+    fn pure(x: A) -> Self {
+        LazyIter::new(iter::once(x))
+    }
+}
+
+impl<'a, A: 'a> Semigroup for LazyIter<'a, A> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        LazyIter(Box::new(self.0.chain(other.0)))
+    }
+}
+
+impl<'a, A: 'a> Monoid for LazyIter<'a, A> {
+    #[inline]
+    fn empty() -> Self {
+        LazyIter::new(iter::empty())
+    }
+}
@@ -1,11 +1,71 @@
 //! Data types.
+//!
+//! # Inherent methods
+//!
+//! Some data types keep the functions given to them until they are run. [IO], [Cont] and
+//! [StateT] box them, `Task` boxes them into a `Send` future, and [LazyIter] stores them in
+//! iterator adapters. These functions must outlive the value, so they have to be `'static`, or
+//! live as long as the iterator for `LazyIter`. [Functor::map](crate::functor::Functor::map) and
+//! [FlatMap::flat_map](crate::flatmap::FlatMap::flat_map) accept functions of any lifetime, so
+//! these types can't implement the typeclasses. They provide `map` and `flat_map` as inherent
+//! methods instead, which can still be used in the [bind!](crate::bind) notation with the
+//! `inherent` syntax.
 
+pub use ior::*;
+pub use monoids::*;
+pub use ne_slice::*;
+pub use nested::*;
+pub use total::*;
 pub use validated::*;
+pub use writer_t::*;
+
+#[cfg(feature = "smallvec")]
+pub use ne_small_vec::*;
+#[cfg(feature = "async")]
+pub use task::Task;
 
 if_std! {
+    pub use chain::*;
+    pub use comparator::*;
+    pub use cont::*;
+    pub use coyoneda::*;
+    pub use func::*;
+    pub use io::*;
+    pub use kleisli::*;
+    pub use lazy_iter::*;
+    pub use ne_chain::*;
+    pub use ne_map::*;
+    pub use ne_set::*;
     pub use ne_vec::*;
+    pub use predicate::*;
+    pub use state_t::*;
+    pub use yoneda::*;
 
+    pub mod chain;
+    pub mod comparator;
+    pub mod cont;
+    pub mod coyoneda;
+    pub mod func;
+    pub mod io;
+    pub mod kleisli;
+    pub mod lazy_iter;
+    pub mod ne_chain;
+    pub mod ne_map;
+    pub mod ne_set;
     pub mod ne_vec;
+    pub mod predicate;
+    pub mod state_t;
+    pub mod yoneda;
 }
 
+pub mod ior;
+pub mod monoids;
+pub mod ne_slice;
+#[cfg(feature = "smallvec")]
+pub mod ne_small_vec;
+pub mod nested;
+#[cfg(feature = "async")]
+pub mod task;
+pub mod total;
 pub mod validated;
+pub mod writer_t;
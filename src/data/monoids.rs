@@ -0,0 +1,394 @@
+//! Monoid wrappers.
+//!
+//! Many types form a [Semigroup] or a [Monoid] in more than one way: numbers can be added or
+//! multiplied, booleans can be combined with `||` or `&&`, any ordered type can keep the minimum
+//! or the maximum, etc. Since a type can only have one instance of a trait, the wrappers in this
+//! module select an alternative instance for the wrapped type.
+//!
+//! | Wrapper | `combine`                          | `empty`              |
+//! |---------|------------------------------------|----------------------|
+//! | [Sum]     | `a + b`                          | `0`                  |
+//! | [Product] | `a * b`                          | `1`                  |
+//! | [Min]     | the smallest of `a` and `b`      | the maximum value    |
+//! | [Max]     | the largest of `a` and `b`       | the minimum value    |
+//! | [First]   | `a`                              | -                    |
+//! | [Last]    | `b`                              | -                    |
+//! | [Any]     | `a \|\| b`                       | `false`              |
+//! | [All]     | `a && b`                         | `true`               |
+//! | [Dual]    | `b.combine(a)`                   | `empty()`            |
+//! | [Endo]    | function composition `a ∘ b`     | the identity function |
+//! | [Union]   | the union of sets `a ∪ b`        | the empty set        |
+//! | [Intersection] | the intersection of sets `a ∩ b` | -               |
+//!
+//! [First] and [Last] have no identity element, but wrapped in an [Option] they form a [Monoid]
+//! which keeps the first or the last present value respectively. [Union] and [Intersection] are
+//! implemented for [HashSet](std::collections::HashSet) and [BTreeSet](std::collections::BTreeSet).
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let xs = vec![3, 1, 4, 1, 5];
+//! assert_eq!(Product(60), Monoid::combine_all(xs.iter().copied().map(Product)));
+//! assert_eq!(Max(5), Monoid::combine_all(xs.iter().copied().map(Max)));
+//! assert_eq!(Any(true), Monoid::combine_all(xs.iter().map(|x| Any(*x > 4))));
+//! assert_eq!(All(false), Monoid::combine_all(xs.iter().map(|x| All(*x > 1))));
+//! assert_eq!(Some(Last(5)), Monoid::combine_all(xs.iter().copied().map(|x| Some(Last(x)))));
+//! ```
+
+use core::ops::{Add, Mul};
+
+use crate::monoid::Monoid;
+use crate::semigroup::{CommutativeSemigroup, Semigroup};
+
+/// Monoid under addition.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Sum<T>(pub T);
+
+/// Monoid under multiplication.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Product<T>(pub T);
+
+/// Monoid keeping the smallest value.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Min<T>(pub T);
+
+/// Monoid keeping the largest value.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Max<T>(pub T);
+
+/// Semigroup keeping the first value.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct First<T>(pub T);
+
+/// Semigroup keeping the last value.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Last<T>(pub T);
+
+/// Boolean monoid under disjunction.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Any(pub bool);
+
+/// Boolean monoid under conjunction.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct All(pub bool);
+
+/// The dual of a semigroup, combining values in the reversed order.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Dual<T>(pub T);
+
+/// Semigroup of sets under union.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Union<S>(pub S);
+
+/// Semigroup of sets under intersection.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Intersection<S>(pub S);
+
+macro_rules! into_inner {
+    ($($name:ident<$t:ident>),+) => {$(
+        impl<$t> $name<$t> {
+            /// Unwrap the value.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use rust2fun::prelude::*;
+            ///
+            #[doc = concat!("assert_eq!(1, ", stringify!($name), "(1).into_inner());")]
+            /// ```
+            #[inline]
+            pub fn into_inner(self) -> $t {
+                self.0
+            }
+        }
+    )+};
+}
+
+into_inner!(
+    Sum<T>,
+    Product<T>,
+    Min<T>,
+    Max<T>,
+    First<T>,
+    Last<T>,
+    Dual<T>,
+    Union<S>,
+    Intersection<S>
+);
+
+impl<T: Add<Output = T>> Semigroup for Sum<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        Sum(self.0 + other.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Semigroup for Product<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        Product(self.0 * other.0)
+    }
+}
+
+impl<T: PartialOrd> Semigroup for Min<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        if other.0 < self.0 {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: PartialOrd> Semigroup for Max<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        if other.0 > self.0 {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<T> Semigroup for First<T> {
+    #[inline]
+    fn combine(self, _other: Self) -> Self {
+        self
+    }
+}
+
+impl<T> Semigroup for Last<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        other
+    }
+}
+
+impl Semigroup for Any {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        Any(self.0 || other.0)
+    }
+}
+
+impl Semigroup for All {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        All(self.0 && other.0)
+    }
+}
+
+impl<T: Semigroup> Semigroup for Dual<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        Dual(other.0.combine(self.0))
+    }
+}
+
+impl<T: Ord> CommutativeSemigroup for Min<T> {}
+impl<T: Ord> CommutativeSemigroup for Max<T> {}
+impl CommutativeSemigroup for Any {}
+impl CommutativeSemigroup for All {}
+impl<T: CommutativeSemigroup> CommutativeSemigroup for Dual<T> {}
+
+macro_rules! monoid_numeric {
+    ($($t:ty)*) => ($(
+        impl Monoid for Sum<$t> {
+            #[inline]
+            fn empty() -> Self { Sum(0 as $t) }
+        }
+
+        impl Monoid for Product<$t> {
+            #[inline]
+            fn empty() -> Self { Product(1 as $t) }
+        }
+
+        impl CommutativeSemigroup for Sum<$t> {}
+        impl CommutativeSemigroup for Product<$t> {}
+    )*)
+}
+
+monoid_numeric! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+macro_rules! monoid_bounded {
+    ($($t:ident)*) => ($(
+        impl Monoid for Min<$t> {
+            #[inline]
+            fn empty() -> Self { Min($t::MAX) }
+        }
+
+        impl Monoid for Max<$t> {
+            #[inline]
+            fn empty() -> Self { Max($t::MIN) }
+        }
+    )*)
+}
+
+monoid_bounded! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 char }
+
+impl Monoid for Min<bool> {
+    #[inline]
+    fn empty() -> Self {
+        Min(true)
+    }
+}
+
+impl Monoid for Max<bool> {
+    #[inline]
+    fn empty() -> Self {
+        Max(false)
+    }
+}
+
+macro_rules! monoid_float {
+    ($($t:ident)*) => ($(
+        impl Monoid for Min<$t> {
+            #[inline]
+            fn empty() -> Self { Min($t::INFINITY) }
+        }
+
+        impl Monoid for Max<$t> {
+            #[inline]
+            fn empty() -> Self { Max($t::NEG_INFINITY) }
+        }
+    )*)
+}
+
+monoid_float! { f32 f64 }
+
+impl Monoid for Any {
+    #[inline]
+    fn empty() -> Self {
+        Any(false)
+    }
+}
+
+impl Monoid for All {
+    #[inline]
+    fn empty() -> Self {
+        All(true)
+    }
+}
+
+impl<T: Monoid> Monoid for Dual<T> {
+    #[inline]
+    fn empty() -> Self {
+        Dual(T::empty())
+    }
+}
+
+if_std! {
+    use std::boxed::Box;
+    use std::collections::{BTreeSet, HashSet};
+    use std::fmt::{Debug, Formatter};
+    use std::hash::Hash;
+
+    /// Monoid of endofunctions `A -> A` under composition.
+    /// See [the module level documentation](self) for more.
+    pub struct Endo<A>(pub Box<dyn FnMut(A) -> A>);
+
+    impl<A> Endo<A> {
+        /// Create a new endofunction from the given function.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust2fun::prelude::*;
+        ///
+        /// let mut f = Endo::new(|x: i32| x + 1).combine(Endo::new(|x| x * 2));
+        /// assert_eq!(7, f.run(3));
+        /// ```
+        #[inline]
+        pub fn new(f: impl FnMut(A) -> A + 'static) -> Self {
+            Endo(Box::new(f))
+        }
+
+        /// Apply the function to the given value.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust2fun::prelude::*;
+        ///
+        /// let mut f = Monoid::combine_all((0..3).map(|_| Endo::new(|x: i32| x + 1)));
+        /// assert_eq!(3, f.run(0));
+        /// ```
+        #[inline]
+        pub fn run(&mut self, a: A) -> A {
+            (self.0)(a)
+        }
+    }
+
+    impl<A> Debug for Endo<A> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            f.write_str("Endo")
+        }
+    }
+
+    impl<A: 'static> Semigroup for Endo<A> {
+        #[inline]
+        fn combine(mut self, mut other: Self) -> Self {
+            Endo::new(move |a| self.run(other.run(a)))
+        }
+    }
+
+    impl<A: 'static> Monoid for Endo<A> {
+        #[inline]
+        fn empty() -> Self {
+            Endo::new(|a| a)
+        }
+    }
+
+    macro_rules! semigroup_set {
+        ($name:ident, $ct:tt $(+ $dt:tt )*) => {
+            impl<T: $ct $(+ $dt )*> Semigroup for Union<$name<T>> {
+                #[inline]
+                fn combine(self, other: Self) -> Self {
+                    let (mut acc, other) = if self.0.len() >= other.0.len() {
+                        (self.0, other.0)
+                    } else {
+                        (other.0, self.0)
+                    };
+                    acc.extend(other);
+                    Union(acc)
+                }
+            }
+
+            impl<T: $ct $(+ $dt )*> Semigroup for Intersection<$name<T>> {
+                #[inline]
+                fn combine(mut self, other: Self) -> Self {
+                    self.0.retain(|x| other.0.contains(x));
+                    self
+                }
+            }
+
+            impl<T: $ct $(+ $dt )*> Monoid for Union<$name<T>> {
+                #[inline]
+                fn empty() -> Self {
+                    Union($name::new())
+                }
+            }
+
+            impl<T: $ct $(+ $dt )*> CommutativeSemigroup for Union<$name<T>> {}
+            impl<T: $ct $(+ $dt )*> CommutativeSemigroup for Intersection<$name<T>> {}
+        };
+    }
+
+    semigroup_set!(HashSet, Eq + Hash);
+    semigroup_set!(BTreeSet, Ord);
+}
@@ -0,0 +1,356 @@
+//! A non-empty sequence with constant time concatenation.
+//!
+//! [`NEChain<T>`][NEChain] is a [Chain] which is guaranteed to have at least one element.
+//! Like [Chain], [combining](Semigroup::combine) two chains, as well as [appending](NEChain::append)
+//! or [prepending](NEChain::prepend) a single element, is O(1). Being a [Semigroup] which never
+//! copies its elements on combination, it is a good fit for accumulating errors with
+//! [Validated](crate::data::validated::Validated). See [ValidatedNec].
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn positive(x: i32) -> ValidatedNec<i32, String> {
+//!     if x > 0 { Valid(x) } else { Invalid(NEChain::new(format!("{x} is not positive"))) }
+//! }
+//!
+//! let actual = positive(-1).map2(positive(-2), |x, y| x + y);
+//! let errors = actual.unwrap_err().into_iter().collect::<Vec<_>>();
+//! assert_eq!(vec!["-1 is not positive", "-2 is not positive"], errors);
+//! ```
+//!
+//! [ValidatedNec]: crate::data::validated::ValidatedNec
+
+use core::fmt::{Debug, Formatter};
+use std::vec::Vec;
+
+use crate::data::chain::{self, Chain};
+use crate::data::ne_vec::NEVec;
+use crate::pure::Pure;
+use crate::reducible::Reducible;
+use crate::semigroup::Semigroup;
+use crate::{
+    and_then_iter, apply_iter, flatmap_iter, functor_iter, higher, invariant_functor,
+    semigroupal_iter,
+};
+
+/// A non-empty sequence with constant time concatenation.
+/// See the [module-level documentation](self) for more details.
+#[allow(clippy::len_without_is_empty)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NEChain<T>(Chain<T>);
+
+impl<T> NEChain<T> {
+    /// Constructs a new `NEChain<T>` with a single element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nechain = NEChain::new(1);
+    /// assert_eq!(1, nechain.len());
+    /// assert_eq!(&1, nechain.first());
+    /// ```
+    #[inline]
+    pub const fn new(x: T) -> Self {
+        NEChain(Chain::one(x))
+    }
+
+    /// Converts a [Chain] into a `NEChain` if it is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(ne_chain![1, 2]), NEChain::from_chain(chain![1, 2]));
+    /// assert_eq!(None, NEChain::<i32>::from_chain(chain![]));
+    /// ```
+    #[inline]
+    pub fn from_chain(chain: Chain<T>) -> Option<Self> {
+        if chain.is_empty() {
+            None
+        } else {
+            Some(NEChain(chain))
+        }
+    }
+
+    /// Returns a reference to the underlying [Chain].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(&chain![1, 2], ne_chain![1, 2].as_chain());
+    /// ```
+    #[inline]
+    pub const fn as_chain(&self) -> &Chain<T> {
+        &self.0
+    }
+
+    /// Converts the `NEChain` into the underlying [Chain].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(chain![1, 2], ne_chain![1, 2].into_chain());
+    /// ```
+    #[inline]
+    pub fn into_chain(self) -> Chain<T> {
+        self.0
+    }
+
+    /// Returns the number of elements in the chain, which is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(3, ne_chain![1, 2, 3].len());
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the first element of the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(&0, ne_chain![1, 2].prepend(0).first());
+    /// ```
+    #[inline]
+    pub fn first(&self) -> &T {
+        self.0.first().unwrap_or_else(|| non_empty_invariant_failed())
+    }
+
+    /// Concatenates two chains in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(ne_chain![1, 2, 3], ne_chain![1].concat(ne_chain![2, 3]));
+    /// ```
+    #[inline]
+    pub fn concat(self, other: Self) -> Self {
+        NEChain(self.0.concat(other.0))
+    }
+
+    /// Concatenates a possibly empty [Chain] to the back of this one in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(ne_chain![1, 2, 3], ne_chain![1].concat_chain(chain![2, 3]));
+    /// assert_eq!(ne_chain![1], ne_chain![1].concat_chain(chain![]));
+    /// ```
+    #[inline]
+    pub fn concat_chain(self, other: Chain<T>) -> Self {
+        NEChain(self.0.concat(other))
+    }
+
+    /// Appends an element to the back of the chain in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(ne_chain![1, 2], NEChain::new(1).append(2));
+    /// ```
+    #[inline]
+    pub fn append(self, x: T) -> Self {
+        NEChain(self.0.append(x))
+    }
+
+    /// Prepends an element to the front of the chain in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(ne_chain![1, 2], NEChain::new(2).prepend(1));
+    /// ```
+    #[inline]
+    pub fn prepend(self, x: T) -> Self {
+        NEChain(self.0.prepend(x))
+    }
+
+    /// Returns an iterator over the elements of the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nechain = ne_chain![1, 2];
+    /// let mut iter = nechain.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> chain::Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T: Debug> Debug for NEChain<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Default> Default for NEChain<T> {
+    #[inline]
+    fn default() -> Self {
+        NEChain::new(T::default())
+    }
+}
+
+impl<T> IntoIterator for NEChain<T> {
+    type Item = T;
+    type IntoIter = chain::IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NEChain<T> {
+    type Item = &'a T;
+    type IntoIter = chain::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T> FromIterator<T> for NEChain<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        NEChain::from_chain(iter.into_iter().collect()).unwrap_or_else(|| non_empty_invariant_failed())
+    }
+}
+
+impl<T> Extend<T> for NEChain<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> From<NEVec<T>> for NEChain<T> {
+    #[inline]
+    fn from(nevec: NEVec<T>) -> Self {
+        NEChain(Chain::from(nevec.into_vec()))
+    }
+}
+
+impl<T> From<NEChain<T>> for NEVec<T> {
+    #[inline]
+    fn from(nechain: NEChain<T>) -> Self {
+        nechain.into_iter().collect()
+    }
+}
+
+impl<T> From<NEChain<T>> for Chain<T> {
+    #[inline]
+    fn from(nechain: NEChain<T>) -> Self {
+        nechain.0
+    }
+}
+
+impl<T> From<NEChain<T>> for Vec<T> {
+    #[inline]
+    fn from(nechain: NEChain<T>) -> Self {
+        nechain.0.into()
+    }
+}
+
+impl<T> TryFrom<Chain<T>> for NEChain<T> {
+    type Error = Chain<T>;
+
+    #[inline]
+    fn try_from(chain: Chain<T>) -> Result<Self, Self::Error> {
+        if chain.is_empty() {
+            Err(chain)
+        } else {
+            Ok(NEChain(chain))
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn non_empty_invariant_failed() -> ! {
+    panic!("NEChain cannot be empty");
+}
+
+/// Creates a [`NEChain`] containing the arguments. At least one argument is required.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let nechain = ne_chain![1, 2, 3];
+/// assert_eq!(3, nechain.len());
+/// assert_eq!(&1, nechain.first());
+/// ```
+#[macro_export]
+macro_rules! ne_chain {
+    ($head:expr) => (
+        $crate::data::ne_chain::NEChain::new($head)
+    );
+    ($head:expr, $($tail:expr),+ $(,)?) => (
+        $crate::data::ne_chain::NEChain::from($crate::ne_vec![$head, $($tail),+])
+    );
+}
+
+higher!(NEChain);
+invariant_functor!(NEChain<T>);
+functor_iter!(NEChain);
+semigroupal_iter!(NEChain);
+apply_iter!(NEChain);
+flatmap_iter!(NEChain);
+and_then_iter!(NEChain);
+
+impl<A> Pure for NEChain<A> {
+    #[inline]
+    fn pure(x: A) -> Self {
+        NEChain::new(x)
+    }
+}
+
+impl<T> Semigroup for NEChain<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        self.concat(other)
+    }
+}
+
+impl<A> Reducible for NEChain<A> {
+    #[inline]
+    fn reduce_left_to<B>(self, f: impl FnOnce(A) -> B, g: impl FnMut(B, A) -> B) -> B {
+        let mut iter = self.into_iter();
+        let head = iter.next().unwrap_or_else(|| non_empty_invariant_failed());
+        iter.fold(f(head), g)
+    }
+}
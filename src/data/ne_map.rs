@@ -0,0 +1,472 @@
+//! A non-empty hash map.
+//!
+//! [`NEMap<K, V>`][NEMap] is a [HashMap] which is guaranteed to have at least one entry. Since
+//! entries can't be removed, the map stays non-empty throughout its lifetime. Like [NEVec], it is
+//! useful to express in the type system that there is at least one value for some key, e.g. to
+//! accumulate errors per field.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let errors = ne_map!["name" => vec!["is empty"]]
+//!     .combine(ne_map!["age" => vec!["is negative"], "name" => vec!["is too short"]]);
+//!
+//! assert_eq!(2, errors.len());
+//! assert_eq!(Some(&vec!["is negative"]), errors.get("age"));
+//! assert_eq!(2, errors.get("name").unwrap().len());
+//! ```
+//!
+//! [NEVec]: crate::data::ne_vec::NEVec
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use std::collections::hash_map::{IntoIter, Iter, Keys, Values};
+use std::collections::HashMap;
+
+use crate::functor::Functor;
+use crate::higher::{Higher, Higher2};
+use crate::invariant::Invariant;
+use crate::non_empty_traverse::NonEmptyTraverse;
+use crate::semigroup::Semigroup;
+use crate::semigroupal::Semigroupal;
+use crate::traverse::Traverse;
+
+/// A non-empty hash map.
+/// See the [module-level documentation](self) for more details.
+#[allow(clippy::len_without_is_empty)]
+#[derive(Clone, Debug)]
+pub struct NEMap<K, V>(HashMap<K, V>);
+
+impl<K: Eq + Hash, V> NEMap<K, V> {
+    /// Constructs a new `NEMap<K, V>` with a single entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nemap = NEMap::new("a", 1);
+    /// assert_eq!(1, nemap.len());
+    /// assert_eq!(Some(&1), nemap.get("a"));
+    /// ```
+    #[inline]
+    pub fn new(key: K, value: V) -> Self {
+        let mut map = HashMap::with_capacity(1);
+        map.insert(key, value);
+        NEMap(map)
+    }
+
+    /// Converts a [HashMap] into a `NEMap` if it is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let map = HashMap::from([("a", 1)]);
+    /// assert_eq!(Some(ne_map!["a" => 1]), NEMap::from_map(map));
+    /// assert_eq!(None, NEMap::<&str, i32>::from_map(HashMap::new()));
+    /// ```
+    #[inline]
+    pub fn from_map(map: HashMap<K, V>) -> Option<Self> {
+        if map.is_empty() {
+            None
+        } else {
+            Some(NEMap(map))
+        }
+    }
+
+    /// Inserts a key-value pair into the map. If the map already had this key present, the value
+    /// is updated and the old value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nemap = NEMap::new("a", 1);
+    /// assert_eq!(None, nemap.insert("b", 2));
+    /// assert_eq!(Some(2), nemap.insert("b", 3));
+    /// assert_eq!(ne_map!["a" => 1, "b" => 3], nemap);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nemap = ne_map![1 => "a"];
+    /// assert_eq!(Some(&"a"), nemap.get(&1));
+    /// assert_eq!(None, nemap.get(&2));
+    /// ```
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.get(key)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nemap = ne_map![1 => "a"];
+    /// if let Some(x) = nemap.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(ne_map![1 => "b"], nemap);
+    /// ```
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nemap = ne_map![1 => "a"];
+    /// assert!(nemap.contains_key(&1));
+    /// assert!(!nemap.contains_key(&2));
+    /// ```
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.contains_key(key)
+    }
+}
+
+impl<K, V> NEMap<K, V> {
+    /// Returns the number of entries in the map, which is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(2, ne_map![1 => "a", 2 => "b"].len());
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns a reference to the underlying [HashMap].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(&HashMap::from([(1, "a")]), ne_map![1 => "a"].as_map());
+    /// ```
+    #[inline]
+    pub const fn as_map(&self) -> &HashMap<K, V> {
+        &self.0
+    }
+
+    /// Converts the `NEMap` into the underlying [HashMap].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(HashMap::from([(1, "a")]), ne_map![1 => "a"].into_map());
+    /// ```
+    #[inline]
+    pub fn into_map(self) -> HashMap<K, V> {
+        self.0
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nemap = ne_map![1 => "a", 2 => "b"];
+    /// let mut entries = nemap.iter().collect::<Vec<_>>();
+    /// entries.sort();
+    /// assert_eq!(vec![(&1, &"a"), (&2, &"b")], entries);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.0.iter()
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nemap = ne_map![1 => "a", 2 => "b"];
+    /// let mut keys = nemap.keys().copied().collect::<Vec<_>>();
+    /// keys.sort();
+    /// assert_eq!(vec![1, 2], keys);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.0.keys()
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nemap = ne_map![1 => "a", 2 => "b"];
+    /// let mut values = nemap.values().copied().collect::<Vec<_>>();
+    /// values.sort();
+    /// assert_eq!(vec!["a", "b"], values);
+    /// ```
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.0.values()
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for NEMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for NEMap<K, V> {}
+
+impl<K, V> IntoIterator for NEMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a NEMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<K: Eq + Hash, V> FromIterator<(K, V)> for NEMap<K, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        NEMap::from_map(iter.into_iter().collect()).unwrap_or_else(|| non_empty_invariant_failed())
+    }
+}
+
+impl<K: Eq + Hash, V> Extend<(K, V)> for NEMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<K: Eq + Hash, V> From<(K, V)> for NEMap<K, V> {
+    #[inline]
+    fn from((key, value): (K, V)) -> Self {
+        NEMap::new(key, value)
+    }
+}
+
+impl<K, V> From<NEMap<K, V>> for HashMap<K, V> {
+    #[inline]
+    fn from(nemap: NEMap<K, V>) -> Self {
+        nemap.0
+    }
+}
+
+impl<K, V> TryFrom<HashMap<K, V>> for NEMap<K, V> {
+    type Error = HashMap<K, V>;
+
+    #[inline]
+    fn try_from(map: HashMap<K, V>) -> Result<Self, Self::Error> {
+        if map.is_empty() {
+            Err(map)
+        } else {
+            Ok(NEMap(map))
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: serde::Serialize, V: serde::Serialize> serde::Serialize for NEMap<K, V> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for NEMap<K, V>
+where
+    K: serde::Deserialize<'de> + Eq + Hash,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let map = HashMap::deserialize(deserializer)?;
+        NEMap::try_from(map).map_err(|_| D::Error::invalid_length(0, &"a non-empty map"))
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn non_empty_invariant_failed() -> ! {
+    panic!("NEMap cannot be empty");
+}
+
+/// Creates a [`NEMap`] containing the given key-value pairs. At least one pair is required.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let nemap = ne_map![1 => "a", 2 => "b"];
+/// assert_eq!(2, nemap.len());
+/// assert_eq!(Some(&"b"), nemap.get(&2));
+/// ```
+#[macro_export]
+macro_rules! ne_map {
+    ($($k:expr => $v:expr),+ $(,)?) => (
+        <$crate::data::ne_map::NEMap<_, _> as core::iter::FromIterator<_>>::from_iter([$(($k, $v)),+])
+    );
+}
+
+impl<K, V> Higher for NEMap<K, V> {
+    type Param = V;
+    type Target<T> = NEMap<K, T>;
+}
+
+impl<K, V> Higher2 for NEMap<K, V> {
+    type Param1 = K;
+    type Param2 = V;
+    type Target<TK, TV> = NEMap<TK, TV>;
+}
+
+impl<A, B, K: Eq + Hash> Invariant<B> for NEMap<K, A> {
+    #[inline]
+    fn imap<F, G>(self, f: F, g: G) -> NEMap<K, B>
+    where
+        F: FnMut(A) -> B,
+        G: FnMut(B) -> A,
+    {
+        NEMap(self.0.imap(f, g))
+    }
+}
+
+impl<A, B, K: Eq + Hash> Functor<B> for NEMap<K, A> {
+    #[inline]
+    fn map(self, f: impl FnMut(A) -> B) -> NEMap<K, B> {
+        NEMap(self.0.map(f))
+    }
+}
+
+impl<K: Eq + Hash, V: Semigroup> Semigroup for NEMap<K, V> {
+    #[inline]
+    fn combine(mut self, other: Self) -> Self {
+        for (k, v) in other {
+            let v = match self.0.remove(&k) {
+                Some(v_acc) => v_acc.combine(v),
+                None => v,
+            };
+            self.0.insert(k, v);
+        }
+
+        self
+    }
+}
+
+impl<K, A, GB> NonEmptyTraverse<GB> for NEMap<K, A>
+where
+    K: Eq + Hash + Clone,
+    GB: Functor<NEMap<K, <GB as Higher>::Param>>,
+    GB::Target<NEMap<K, <GB as Higher>::Param>>:
+        Semigroupal<<GB as Higher>::Param, Target<<GB as Higher>::Param> = GB>,
+    <GB::Target<NEMap<K, <GB as Higher>::Param>> as Higher>::Target<(
+        NEMap<K, <GB as Higher>::Param>,
+        <GB as Higher>::Param,
+    )>: Functor<
+        NEMap<K, <GB as Higher>::Param>,
+        Target<NEMap<K, <GB as Higher>::Param>> = GB::Target<NEMap<K, <GB as Higher>::Param>>,
+    >,
+{
+    #[inline]
+    fn non_empty_traverse<F>(self, mut f: F) -> GB::Target<NEMap<K, GB::Param>>
+    where
+        F: FnMut(A) -> GB,
+    {
+        let mut iter = self.0.into_iter();
+        let (k, a) = iter.next().unwrap_or_else(|| non_empty_invariant_failed());
+        let head = f(a).map(|b| NEMap::new(k.clone(), b));
+        iter.fold(head, |acc, (k, a)| {
+            acc.product(f(a)).map(|(mut bs, b)| {
+                bs.insert(k.clone(), b);
+                bs
+            })
+        })
+    }
+}
+
+impl<K, A, GB> Traverse<GB> for NEMap<K, A>
+where
+    K: Eq + Hash + Clone,
+    GB: Functor<NEMap<K, <GB as Higher>::Param>>,
+    GB::Target<NEMap<K, <GB as Higher>::Param>>:
+        Semigroupal<<GB as Higher>::Param, Target<<GB as Higher>::Param> = GB>,
+    <GB::Target<NEMap<K, <GB as Higher>::Param>> as Higher>::Target<(
+        NEMap<K, <GB as Higher>::Param>,
+        <GB as Higher>::Param,
+    )>: Functor<
+        NEMap<K, <GB as Higher>::Param>,
+        Target<NEMap<K, <GB as Higher>::Param>> = GB::Target<NEMap<K, <GB as Higher>::Param>>,
+    >,
+{
+    #[inline]
+    fn traverse<F>(self, f: F) -> GB::Target<NEMap<K, GB::Param>>
+    where
+        F: FnMut(A) -> GB,
+    {
+        self.non_empty_traverse(f)
+    }
+}
@@ -0,0 +1,364 @@
+//! A non-empty hash set.
+//!
+//! [`NESet<T>`][NESet] is a [HashSet] which is guaranteed to have at least one element. Since
+//! elements can't be removed, the set stays non-empty throughout its lifetime. This allows APIs
+//! to require e.g. "at least one permission" at the type level.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! #[derive(Debug, PartialEq, Eq, Hash)]
+//! enum Permission {
+//!     Read,
+//!     Write,
+//! }
+//!
+//! fn grant(permissions: NESet<Permission>) -> usize {
+//!     permissions.len()
+//! }
+//!
+//! let permissions = ne_set![Permission::Read].combine(ne_set![Permission::Read, Permission::Write]);
+//! assert_eq!(2, grant(permissions));
+//! ```
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use std::collections::hash_set::{IntoIter, Iter};
+use std::collections::HashSet;
+
+use crate::reducible::Reducible;
+use crate::semigroup::{CommutativeSemigroup, Semigroup};
+use crate::{functor_iter, higher, invariant_functor};
+
+/// A non-empty hash set.
+/// See the [module-level documentation](self) for more details.
+#[allow(clippy::len_without_is_empty)]
+#[derive(Clone, Debug)]
+pub struct NESet<T>(HashSet<T>);
+
+impl<T: Eq + Hash> NESet<T> {
+    /// Constructs a new `NESet<T>` with a single element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let neset = NESet::new(1);
+    /// assert_eq!(1, neset.len());
+    /// assert!(neset.contains(&1));
+    /// ```
+    #[inline]
+    pub fn new(x: T) -> Self {
+        let mut set = HashSet::with_capacity(1);
+        set.insert(x);
+        NESet(set)
+    }
+
+    /// Converts a [HashSet] into a `NESet` if it is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(ne_set![1, 2]), NESet::from_set(HashSet::from([1, 2])));
+    /// assert_eq!(None, NESet::<i32>::from_set(HashSet::new()));
+    /// ```
+    #[inline]
+    pub fn from_set(set: HashSet<T>) -> Option<Self> {
+        if set.is_empty() {
+            None
+        } else {
+            Some(NESet(set))
+        }
+    }
+
+    /// Adds a value to the set. Returns whether the value was newly inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut neset = NESet::new(1);
+    /// assert!(neset.insert(2));
+    /// assert!(!neset.insert(2));
+    /// assert_eq!(ne_set![1, 2], neset);
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Returns `true` if the set contains the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let neset = ne_set![1, 2];
+    /// assert!(neset.contains(&1));
+    /// assert!(!neset.contains(&3));
+    /// ```
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.contains(value)
+    }
+
+    /// Returns the union of two sets, which is never empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(ne_set![1, 2, 3], ne_set![1, 2].union(ne_set![2, 3]));
+    /// ```
+    #[inline]
+    pub fn union(mut self, other: Self) -> Self {
+        self.0.extend(other.0);
+        self
+    }
+
+    /// Returns the intersection of two sets, or `None` if they are disjoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(ne_set![2]), ne_set![1, 2].intersection(ne_set![2, 3]));
+    /// assert_eq!(None, ne_set![1].intersection(ne_set![2]));
+    /// ```
+    #[inline]
+    pub fn intersection(mut self, other: Self) -> Option<Self> {
+        self.0.retain(|x| other.0.contains(x));
+        NESet::from_set(self.0)
+    }
+
+    /// Returns the elements of `self` which are not in `other`, or `None` if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(ne_set![1]), ne_set![1, 2].difference(ne_set![2, 3]));
+    /// assert_eq!(None, ne_set![1].difference(ne_set![1, 2]));
+    /// ```
+    #[inline]
+    pub fn difference(mut self, other: Self) -> Option<Self> {
+        self.0.retain(|x| !other.0.contains(x));
+        NESet::from_set(self.0)
+    }
+
+    /// Returns `true` if `self` has no elements in common with `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(ne_set![1].is_disjoint(&ne_set![2]));
+    /// assert!(!ne_set![1, 2].is_disjoint(&ne_set![2]));
+    /// ```
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0.is_disjoint(&other.0)
+    }
+
+    /// Returns `true` if `self` is a subset of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(ne_set![1].is_subset(&ne_set![1, 2]));
+    /// assert!(!ne_set![1, 3].is_subset(&ne_set![1, 2]));
+    /// ```
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.is_subset(&other.0)
+    }
+}
+
+impl<T> NESet<T> {
+    /// Returns the number of elements in the set, which is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(2, ne_set![1, 2, 2].len());
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns a reference to the underlying [HashSet].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(&HashSet::from([1]), ne_set![1].as_set());
+    /// ```
+    #[inline]
+    pub const fn as_set(&self) -> &HashSet<T> {
+        &self.0
+    }
+
+    /// Converts the `NESet` into the underlying [HashSet].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(HashSet::from([1]), ne_set![1].into_set());
+    /// ```
+    #[inline]
+    pub fn into_set(self) -> HashSet<T> {
+        self.0
+    }
+
+    /// An iterator visiting all elements in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let neset = ne_set![1, 2];
+    /// let mut elements = neset.iter().copied().collect::<Vec<_>>();
+    /// elements.sort();
+    /// assert_eq!(vec![1, 2], elements);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.0.iter()
+    }
+}
+
+impl<T: Eq + Hash> PartialEq for NESet<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq + Hash> Eq for NESet<T> {}
+
+impl<T> IntoIterator for NESet<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NESet<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for NESet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        NESet::from_set(iter.into_iter().collect()).unwrap_or_else(|| non_empty_invariant_failed())
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for NESet<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> From<NESet<T>> for HashSet<T> {
+    #[inline]
+    fn from(neset: NESet<T>) -> Self {
+        neset.0
+    }
+}
+
+impl<T> TryFrom<HashSet<T>> for NESet<T> {
+    type Error = HashSet<T>;
+
+    #[inline]
+    fn try_from(set: HashSet<T>) -> Result<Self, Self::Error> {
+        if set.is_empty() {
+            Err(set)
+        } else {
+            Ok(NESet(set))
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn non_empty_invariant_failed() -> ! {
+    panic!("NESet cannot be empty");
+}
+
+/// Creates a [`NESet`] containing the arguments. At least one argument is required.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let neset = ne_set![1, 2, 2];
+/// assert_eq!(2, neset.len());
+/// ```
+#[macro_export]
+macro_rules! ne_set {
+    ($($x:expr),+ $(,)?) => (
+        <$crate::data::ne_set::NESet<_> as core::iter::FromIterator<_>>::from_iter([$($x),+])
+    );
+}
+
+higher!(NESet);
+invariant_functor!(NESet<T>, Eq + Hash);
+functor_iter!(NESet, Eq + Hash);
+
+impl<T: Eq + Hash> Semigroup for NESet<T> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl<T: Eq + Hash> CommutativeSemigroup for NESet<T> {}
+
+impl<A> Reducible for NESet<A> {
+    #[inline]
+    fn reduce_left_to<B>(self, f: impl FnOnce(A) -> B, g: impl FnMut(B, A) -> B) -> B {
+        let mut iter = self.0.into_iter();
+        let head = iter.next().unwrap_or_else(|| non_empty_invariant_failed());
+        iter.fold(f(head), g)
+    }
+}
@@ -0,0 +1,342 @@
+//! A borrowed non-empty slice.
+//!
+//! [`NESlice<'a, T>`][NESlice] is a view into a slice which is guaranteed to have at least one
+//! element, so that [`first`](NESlice::first) and [`last`](NESlice::last) never fail. It
+//! dereferences to a plain slice, which allows to use it with any slice-based API without
+//! copying.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn max<T: Ord>(xs: NESlice<'_, T>) -> &T {
+//!     xs.iter().max().unwrap_or(xs.first())
+//! }
+//!
+//! let xs = [3, 1, 4, 1, 5];
+//! assert_eq!(&5, max(NESlice::from_slice(&xs).unwrap()));
+//! assert_eq!(None, NESlice::<i32>::from_slice(&[]));
+//! ```
+
+use core::iter::FusedIterator;
+use core::num::NonZeroUsize;
+use core::ops::Deref;
+use core::slice;
+
+/// A non-empty borrowed slice.
+/// See the [module-level documentation](self) for more details.
+#[allow(clippy::len_without_is_empty)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NESlice<'a, T>(&'a [T]);
+
+impl<T> Clone for NESlice<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NESlice<'_, T> {}
+
+impl<'a, T> NESlice<'a, T> {
+    /// Creates a `NESlice` from a slice. Returns `None` if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(3, xs.len());
+    ///
+    /// assert_eq!(None, NESlice::<i32>::from_slice(&[]));
+    /// ```
+    #[inline]
+    pub const fn from_slice(slice: &'a [T]) -> Option<Self> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(NESlice(slice))
+        }
+    }
+
+    /// Creates a `NESlice` from a reference to a non-empty array. Unlike
+    /// [`from_slice`](NESlice::from_slice) this never fails, and it can be used to declare
+    /// static non-empty data. Calling it with an empty array fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// static PRIMES: NESlice<'static, u32> = NESlice::from_array(&[2, 3, 5, 7]);
+    /// assert_eq!(&2, PRIMES.first());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rust2fun::prelude::*;
+    ///
+    /// const EMPTY: NESlice<'static, u32> = NESlice::from_array(&[]);
+    /// ```
+    #[inline]
+    pub const fn from_array<const N: usize>(array: &'a [T; N]) -> Self {
+        const { assert!(N > 0, "NESlice cannot be empty") };
+        NESlice(array)
+    }
+
+    /// Creates a `NESlice` of length one from a reference to a single element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_ref(&1);
+    /// assert_eq!([1], *xs);
+    /// ```
+    #[inline]
+    pub const fn from_ref(x: &'a T) -> Self {
+        NESlice(slice::from_ref(x))
+    }
+
+    /// Returns the number of elements in the slice. This is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(3, xs.len());
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the first element of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(&1, xs.first());
+    /// ```
+    #[inline]
+    pub fn first(&self) -> &'a T {
+        &self.0[0]
+    }
+
+    /// Returns the last element of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(&3, xs.last());
+    /// ```
+    #[inline]
+    pub fn last(&self) -> &'a T {
+        &self.0[self.0.len() - 1]
+    }
+
+    /// Returns the first element and the rest of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!((&1, &[2, 3][..]), xs.split_first());
+    /// ```
+    #[inline]
+    pub fn split_first(&self) -> (&'a T, &'a [T]) {
+        (self.first(), &self.0[1..])
+    }
+
+    /// Returns the last element and the rest of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!((&3, &[1, 2][..]), xs.split_last());
+    /// ```
+    #[inline]
+    pub fn split_last(&self) -> (&'a T, &'a [T]) {
+        (self.last(), &self.0[..self.0.len() - 1])
+    }
+
+    /// Returns the underlying slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(&[1, 2, 3], xs.as_slice());
+    /// ```
+    #[inline]
+    pub const fn as_slice(&self) -> &'a [T] {
+        self.0
+    }
+
+    /// Returns an iterator over the elements of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// assert_eq!(6, xs.iter().sum::<i32>());
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'a, T> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`. The windows overlap
+    /// and are never empty. If the slice is shorter than `size`, the iterator returns no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+    /// let mut iter = xs.windows(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(&[1, 2], iter.next().unwrap().as_slice());
+    /// assert_eq!(&[2, 3], iter.next().unwrap().as_slice());
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn windows(&self, size: NonZeroUsize) -> Windows<'a, T> {
+        Windows(self.0.windows(size.get()))
+    }
+}
+
+/// An iterator over overlapping non-empty windows of a slice.
+///
+/// This struct is created by [`NESlice::windows`].
+#[derive(Clone, Debug)]
+pub struct Windows<'a, T>(slice::Windows<'a, T>);
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = NESlice<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(NESlice)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(NESlice)
+    }
+}
+
+impl<T> DoubleEndedIterator for Windows<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(NESlice)
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+impl<T> Deref for NESlice<'_, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
+
+impl<T> AsRef<[T]> for NESlice<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.0
+    }
+}
+
+impl<'a, T> IntoIterator for NESlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T> From<&'a T> for NESlice<'a, T> {
+    #[inline]
+    fn from(x: &'a T) -> Self {
+        NESlice::from_ref(x)
+    }
+}
+
+impl<'a, T, const N: usize> TryFrom<&'a [T; N]> for NESlice<'a, T> {
+    type Error = &'a [T; N];
+
+    #[inline]
+    fn try_from(array: &'a [T; N]) -> Result<Self, Self::Error> {
+        NESlice::from_slice(array).ok_or(array)
+    }
+}
+
+impl<'a, T> TryFrom<&'a [T]> for NESlice<'a, T> {
+    type Error = &'a [T];
+
+    #[inline]
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        NESlice::from_slice(slice).ok_or(slice)
+    }
+}
+
+impl<'a, T> From<NESlice<'a, T>> for &'a [T] {
+    #[inline]
+    fn from(slice: NESlice<'a, T>) -> Self {
+        slice.0
+    }
+}
+
+if_std! {
+    use crate::data::ne_vec::NEVec;
+
+    impl<T: Clone> NESlice<'_, T> {
+        /// Copies the slice into a new [NEVec].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust2fun::prelude::*;
+        ///
+        /// let xs = NESlice::from_slice(&[1, 2, 3]).unwrap();
+        /// assert_eq!(ne_vec![1, 2, 3], xs.to_ne_vec());
+        /// ```
+        #[inline]
+        pub fn to_ne_vec(&self) -> NEVec<T> {
+            NEVec::from(*self)
+        }
+    }
+}
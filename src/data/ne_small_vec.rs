@@ -0,0 +1,389 @@
+//! A non-empty small vector.
+//!
+//! [`NESmallVec<T, N>`][NESmallVec] is a [SmallVec] which is guaranteed to have at least one
+//! element. Like `SmallVec`, it stores up to `N` elements inline and only allocates on the heap
+//! when it grows beyond that, which makes it a good fit for error accumulators in hot validation
+//! paths, where there usually are one or two errors.
+//!
+//! This module is only available with the `smallvec` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! type Errors = NESmallVec<&'static str, 2>;
+//!
+//! let errors = Errors::new("too short").combine(Errors::new("no digits"));
+//! assert_eq!(&"too short", errors.head());
+//! assert_eq!(["no digits"], errors.tail());
+//! assert!(!errors.spilled());
+//! ```
+
+use core::ops::Deref;
+
+use smallvec::SmallVec;
+
+use crate::and_then::AndThen;
+use crate::apply::Apply;
+use crate::flatmap::FlatMap;
+use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::invariant::Invariant;
+use crate::pure::Pure;
+use crate::reducible::Reducible;
+use crate::semigroup::Semigroup;
+use crate::semigroupal::Semigroupal;
+
+/// A non-empty [SmallVec] storing up to `N` elements inline.
+/// See [the module level documentation](self) for more.
+#[allow(clippy::len_without_is_empty)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NESmallVec<T, const N: usize> {
+    vec: SmallVec<[T; N]>,
+}
+
+impl<T, const N: usize> NESmallVec<T, N> {
+    /// Constructs a new `NESmallVec` with the given head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let v = NESmallVec::<_, 4>::new(1);
+    /// assert_eq!([1], *v);
+    /// ```
+    #[inline]
+    pub fn new(head: T) -> Self {
+        let mut vec = SmallVec::new();
+        vec.push(head);
+        NESmallVec { vec }
+    }
+
+    /// Converts a [SmallVec] into a `NESmallVec` if it is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    /// use smallvec::{smallvec, SmallVec};
+    ///
+    /// let v: SmallVec<[i32; 4]> = smallvec![1, 2];
+    /// assert_eq!([1, 2], *NESmallVec::from_smallvec(v).unwrap());
+    /// assert_eq!(None, NESmallVec::from_smallvec(SmallVec::<[i32; 4]>::new()));
+    /// ```
+    #[inline]
+    pub fn from_smallvec(vec: SmallVec<[T; N]>) -> Option<Self> {
+        if vec.is_empty() {
+            None
+        } else {
+            Some(NESmallVec { vec })
+        }
+    }
+
+    /// Collects an iterator into a `NESmallVec` if it is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!([1, 2, 3], *NESmallVec::<_, 2>::try_from_iter(1..4).unwrap());
+    /// assert_eq!(None, NESmallVec::<i32, 2>::try_from_iter(None));
+    /// ```
+    #[inline]
+    pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+        Self::from_smallvec(iter.into_iter().collect())
+    }
+
+    /// Returns the first element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(&1, NESmallVec::<_, 2>::new(1).head());
+    /// ```
+    #[inline]
+    pub fn head(&self) -> &T {
+        &self.vec[0]
+    }
+
+    /// Returns all elements but the first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let v = NESmallVec::<_, 2>::try_from_iter(1..4).unwrap();
+    /// assert_eq!([2, 3], v.tail());
+    /// ```
+    #[inline]
+    pub fn tail(&self) -> &[T] {
+        &self.vec[1..]
+    }
+
+    /// Returns the last element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let v = NESmallVec::<_, 2>::try_from_iter(1..4).unwrap();
+    /// assert_eq!(&3, v.last());
+    /// ```
+    #[inline]
+    pub fn last(&self) -> &T {
+        &self.vec[self.vec.len() - 1]
+    }
+
+    /// Returns the number of elements, which is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(1, NESmallVec::<_, 2>::new(1).len());
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut v = NESmallVec::<_, 2>::new(1);
+    /// v.push(2);
+    /// assert!(!v.spilled());
+    /// v.push(3);
+    /// assert!(v.spilled());
+    /// ```
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.vec.spilled()
+    }
+
+    /// Appends an element to the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut v = NESmallVec::<_, 2>::new(1);
+    /// v.push(2);
+    /// assert_eq!([1, 2], *v);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.vec.push(value);
+    }
+
+    /// Removes the last element and returns it, or `None` if it is the only element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut v = NESmallVec::<_, 2>::try_from_iter([1, 2]).unwrap();
+    /// assert_eq!(Some(2), v.pop());
+    /// assert_eq!(None, v.pop());
+    /// assert_eq!([1], *v);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.vec.len() > 1 {
+            self.vec.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the elements as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(&[1], NESmallVec::<_, 2>::new(1).as_slice());
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Converts the `NESmallVec` into the underlying [SmallVec].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let v = NESmallVec::<_, 2>::new(1).into_smallvec();
+    /// assert_eq!([1], *v);
+    /// ```
+    #[inline]
+    pub fn into_smallvec(self) -> SmallVec<[T; N]> {
+        self.vec
+    }
+}
+
+impl<T, const N: usize> Deref for NESmallVec<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T, const N: usize> Extend<T> for NESmallVec<T, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+
+impl<T, const N: usize> IntoIterator for NESmallVec<T, N> {
+    type Item = T;
+    type IntoIter = smallvec::IntoIter<[T; N]>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a NESmallVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+impl<T, const N: usize> From<NESmallVec<T, N>> for SmallVec<[T; N]> {
+    #[inline]
+    fn from(value: NESmallVec<T, N>) -> Self {
+        value.vec
+    }
+}
+
+impl<T, const N: usize> TryFrom<SmallVec<[T; N]>> for NESmallVec<T, N> {
+    type Error = SmallVec<[T; N]>;
+
+    #[inline]
+    fn try_from(vec: SmallVec<[T; N]>) -> Result<Self, Self::Error> {
+        if vec.is_empty() {
+            Err(vec)
+        } else {
+            Ok(NESmallVec { vec })
+        }
+    }
+}
+
+impl<P, const N: usize> Higher for NESmallVec<P, N> {
+    type Param = P;
+    type Target<T> = NESmallVec<T, N>;
+}
+
+impl<A, B, const N: usize> Invariant<B> for NESmallVec<A, N> {
+    #[inline]
+    fn imap<F, G>(self, f: F, _g: G) -> NESmallVec<B, N>
+    where
+        F: FnMut(A) -> B,
+        G: FnMut(B) -> A,
+    {
+        self.map(f)
+    }
+}
+
+impl<A, B, const N: usize> Functor<B> for NESmallVec<A, N> {
+    #[inline]
+    fn map(self, f: impl FnMut(A) -> B) -> NESmallVec<B, N> {
+        NESmallVec {
+            vec: self.vec.map(f),
+        }
+    }
+}
+
+impl<A: Clone, B: Clone, const N: usize> Semigroupal<B> for NESmallVec<A, N> {
+    #[inline]
+    fn product(self, fb: NESmallVec<B, N>) -> NESmallVec<(A, B), N> {
+        NESmallVec {
+            vec: self.vec.product(fb.vec),
+        }
+    }
+}
+
+impl<F, A: Clone, B, const N: usize> Apply<A, B> for NESmallVec<F, N> {
+    #[inline]
+    fn ap(self, fa: NESmallVec<A, N>) -> NESmallVec<B, N>
+    where
+        F: FnMut(A) -> B,
+    {
+        NESmallVec {
+            vec: self.vec.ap(fa.vec),
+        }
+    }
+}
+
+impl<A, B, const N: usize> FlatMap<B> for NESmallVec<A, N> {
+    #[inline]
+    fn flat_map<F>(self, f: F) -> NESmallVec<B, N>
+    where
+        F: FnMut(A) -> NESmallVec<B, N>,
+    {
+        NESmallVec {
+            vec: self.vec.into_iter().flat_map(f).collect(),
+        }
+    }
+}
+
+impl<A, B, const N: usize> AndThen<B> for NESmallVec<A, N> {
+    #[inline]
+    fn and_then<F>(self, f: F) -> NESmallVec<B, N>
+    where
+        F: FnMut(A) -> NESmallVec<B, N>,
+    {
+        self.flat_map(f)
+    }
+}
+
+impl<T, const N: usize> Pure for NESmallVec<T, N> {
+    #[inline]
+    fn pure(x: T) -> Self {
+        NESmallVec::new(x)
+    }
+}
+
+impl<T, const N: usize> Semigroup for NESmallVec<T, N> {
+    #[inline]
+    fn combine(mut self, other: Self) -> Self {
+        self.vec.extend(other.vec);
+        self
+    }
+}
+
+impl<A, const N: usize> Reducible for NESmallVec<A, N> {
+    #[inline]
+    fn reduce_left_to<B>(self, f: impl FnOnce(A) -> B, g: impl FnMut(B, A) -> B) -> B {
+        let mut iter = self.vec.into_iter();
+        let head = iter.next().expect("NESmallVec cannot be empty");
+        iter.fold(f(head), g)
+    }
+}
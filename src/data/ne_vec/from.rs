@@ -19,14 +19,48 @@ impl<T: Clone> From<&NEVec<T>> for Vec<T> {
 impl<T> From<NEVec<T>> for (T, Vec<T>) {
     #[inline]
     fn from(nevec: NEVec<T>) -> Self {
-        (nevec.head, nevec.tail)
+        let (head, tail) = nevec.into_head_tail();
+        (head, tail.collect())
     }
 }
 
 impl<T> From<(T, Vec<T>)> for NEVec<T> {
     #[inline]
     fn from((head, tail): (T, Vec<T>)) -> Self {
-        Self { head, tail }
+        let mut nevec = NEVec::with_tail_capacity(head, tail.len());
+        nevec.extend(tail);
+        nevec
+    }
+}
+
+impl<T: Clone> From<NESlice<'_, T>> for NEVec<T> {
+    #[inline]
+    fn from(slice: NESlice<'_, T>) -> Self {
+        Self { vec: slice.to_vec() }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for NEVec<T> {
+    /// Converts a non-empty array into a `NEVec`.
+    /// Converting an empty array fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(ne_vec![1, 2, 3], NEVec::from([1, 2, 3]));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = NEVec::<i32>::from([]);
+    /// ```
+    #[inline]
+    fn from(array: [T; N]) -> Self {
+        const { assert!(N > 0, "NEVec cannot be empty") };
+        Self { vec: Vec::from(array) }
     }
 }
 
@@ -34,23 +68,14 @@ impl<T> TryFrom<Vec<T>> for NEVec<T> {
     type Error = Vec<T>;
 
     #[inline]
-    fn try_from(mut vec: Vec<T>) -> Result<Self, Self::Error> {
-        if vec.is_empty() { Err(vec) } else {
-            Ok(Self {
-                head: vec.remove(0),
-                tail: vec,
-            })
-        }
+    fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+        if vec.is_empty() { Err(vec) } else { Ok(Self { vec }) }
     }
 }
 
 impl<T> FromIterator<T> for NEVec<T> {
     #[inline]
     fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
-        let mut iter = iter.into_iter();
-        iter.next().map(|head| {
-            let tail = iter.collect();
-            NEVec { head, tail }
-        }).unwrap_or_else(|| non_empty_invariant_failed())
+        NEVec::from_vec(iter.into_iter().collect()).unwrap_or_else(|| non_empty_invariant_failed())
     }
 }
\ No newline at end of file
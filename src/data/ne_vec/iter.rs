@@ -1,30 +1,41 @@
-use core::iter;
 use std::{slice, vec};
 
 use super::*;
+use crate::data::ne_slice::Windows;
 
 impl<T> IntoIterator for NEVec<T> {
     type Item = T;
-    type IntoIter = iter::Chain<iter::Once<T>, vec::IntoIter<Self::Item>>;
+    type IntoIter = vec::IntoIter<T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        iter::once(self.head).chain(self.tail)
+        self.vec.into_iter()
     }
 }
 
 impl<'a, T> IntoIterator for &'a NEVec<T> {
     type Item = &'a T;
-    type IntoIter = iter::Chain<iter::Once<&'a T>, slice::Iter<'a, T>>;
+    type IntoIter = slice::Iter<'a, T>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter {
-        iter::once(&self.head).chain(self.tail.iter())
+        self.vec.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NEVec<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter_mut()
     }
 }
 
 impl<T> NEVec<T> {
-    /// Returns an iterator over the elements of the NEVec. The iterator is double-ended.
+    /// Returns an iterator over the elements of the NEVec. The iterator is double-ended,
+    /// exact-sized and fused.
     ///
     /// # Examples
     ///
@@ -46,7 +57,69 @@ impl<T> NEVec<T> {
     /// assert_eq!(iter.next_back(), None);
     /// ```
     #[inline]
-    pub fn iter(&self) -> iter::Chain<iter::Once<&T>, slice::Iter<T>> {
-        iter::once(&self.head).chain(self.tail.iter())
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.vec.iter()
+    }
+
+    /// Returns an iterator that allows modifying each element of the NEVec. The iterator is
+    /// double-ended, exact-sized and fused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut v = ne_vec![1, 2, 3];
+    /// for x in v.iter_mut() {
+    ///     *x *= 2;
+    /// }
+    /// assert_eq!(v, [2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.vec.iter_mut()
     }
-}
\ No newline at end of file
+
+    /// Returns an iterator over all contiguous windows of length `size`. The windows overlap
+    /// and are never empty. If the NEVec is shorter than `size`, the iterator returns no values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let v = ne_vec![1, 2, 3];
+    /// let sums: Vec<i32> = v
+    ///     .windows(NonZeroUsize::new(2).unwrap())
+    ///     .map(|w| w.iter().sum())
+    ///     .collect();
+    /// assert_eq!(vec![3, 5], sums);
+    /// ```
+    #[inline]
+    pub fn windows(&self, size: NonZeroUsize) -> Windows<'_, T> {
+        self.as_ne_slice().windows(size)
+    }
+}
+/// Extension trait for collecting an iterator into a [NEVec] without panicking.
+pub trait CollectNonEmpty: Iterator {
+    /// Collects the iterator into a [NEVec]. Returns `None` if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(ne_vec![2, 4]), [1, 2, 3, 4].into_iter().filter(|x| x % 2 == 0).collect_nonempty());
+    /// assert_eq!(None, [1, 3].into_iter().filter(|x| x % 2 == 0).collect_nonempty());
+    /// ```
+    #[inline]
+    fn collect_nonempty(self) -> Option<NEVec<Self::Item>>
+    where
+        Self: Sized,
+    {
+        NEVec::try_from_iter(self)
+    }
+}
+
+impl<I: Iterator> CollectNonEmpty for I {}
@@ -1,18 +1,14 @@
 //! A non-empty growable vector.
 //!
 //! This vector is guaranteed to have at least one element.
-//! The first element is known as the head, and the remaining elements are known as the tail:
-//! ```
-//! # #[allow(dead_code)]
-//! pub struct NEVec<T> {
-//!     pub head: T,
-//!     pub tail: Vec<T>,
-//! }
-//! ```
-//!
+//! The first element is known as the head, and the remaining elements are known as the tail.
 //! The tail may be empty.
 //! The length of the NEVec is always at least one.
 //!
+//! The elements are stored in a single contiguous buffer, so a `NEVec` can be used wherever a
+//! slice is expected: it dereferences to a slice, and [`NEVec::as_ne_slice`] borrows it as a
+//! [NESlice] which keeps the non-empty guarantee.
+//!
 //! # Examples
 //!
 //! You can create a `NEVec` with [`NEVec::new`]:
@@ -38,12 +34,14 @@
 //! use rust2fun::prelude::*;
 //!
 //! let mut nevec = ne_vec![1];
-//! nevec.tail.push(2);
-//! nevec.tail.push(3);
+//! nevec.push(2);
+//! nevec.push(3);
 //! assert_eq!(nevec, [1, 2, 3]);
 //!
-//! assert_eq!(nevec.tail.pop(), Some(3));
-//! assert_eq!(nevec, [1, 2]);
+//! assert_eq!(nevec.pop(), Some(3));
+//! assert_eq!(nevec.pop(), Some(2));
+//! assert_eq!(nevec.pop(), None);
+//! assert_eq!(nevec, [1]);
 //! ```
 //!
 //! Non-empty vectors implement many of the same methods as [`Vec`] like ['NEVec::len`],
@@ -90,36 +88,49 @@
 //! ```
 //!
 //! [`ne_vec!`]: crate::ne_vec
+//! [NESlice]: crate::data::ne_slice::NESlice
+use core::cmp::Ordering;
 use core::num::NonZeroUsize;
-use std::ops::{Index, IndexMut};
+use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::vec;
 use std::vec::Vec;
-use std::{mem, ptr, vec};
 
+use crate::data::ne_slice::NESlice;
+
+use crate::coflatmap::CoflatMap;
+use crate::comonad::Comonad;
 use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::non_empty_traverse::NonEmptyTraverse;
 use crate::pure::Pure;
+use crate::reducible::Reducible;
 use crate::semigroup::Semigroup;
+use crate::semigroupal::Semigroupal;
 use crate::{
-    and_then_flat_map, apply_iter, flatmap_iter, higher, invariant_functor, semigroup_extend,
+    and_then_iter, apply_iter, flatmap_iter, higher, invariant_functor, semigroup_extend,
     semigroupal_iter,
 };
 
+pub use iter::CollectNonEmpty;
+
 mod from;
 mod iter;
 mod partial_eq;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "serde")]
+mod serde;
+mod sort;
 
-/// A non-empty vector. The first element is `head`, and the remaining elements are `tail`.
+/// A non-empty vector. The first element is the [`head`](NEVec::head), and the remaining
+/// elements are the [`tail`](NEVec::tail).
 /// The length of the NEVec is always at least one. The tail may be empty.
 ///
 /// See the [module-level documentation](self) for more details.
 #[allow(clippy::len_without_is_empty)]
 #[derive(Clone, Debug, Eq, Hash, PartialOrd, Ord)]
 pub struct NEVec<T> {
-    /// The first element of the NEVec, known as the head.
-    /// This is always present.
-    pub head: T,
-    /// The remaining elements of the NEVec, known as the tail.
-    /// This may be empty.
-    pub tail: Vec<T>,
+    vec: Vec<T>,
 }
 
 impl<T> NEVec<T> {
@@ -132,16 +143,13 @@ impl<T> NEVec<T> {
     /// use rust2fun::prelude::*;
     ///
     /// let nevec = NEVec::new(1);
-    /// assert_eq!(nevec.head, 1);
-    /// assert_eq!(nevec.tail, []);
+    /// assert_eq!(nevec.head(), &1);
+    /// assert_eq!(nevec.tail(), []);
     /// assert_eq!(nevec, [1]);
     /// ```
     #[inline]
-    pub const fn new(head: T) -> Self {
-        Self {
-            head,
-            tail: Vec::new(),
-        }
+    pub fn new(head: T) -> Self {
+        Self { vec: vec![head] }
     }
 
     /// Constructs a new `NEVec<T>` with the given `head` and the capacity for `tail`.
@@ -156,17 +164,38 @@ impl<T> NEVec<T> {
     /// use rust2fun::prelude::*;
     ///
     /// let nevec = NEVec::with_tail_capacity(1, 2);
-    /// assert_eq!(nevec.head, 1);
-    /// assert_eq!(nevec.tail, []);
-    /// assert_eq!(nevec.tail.capacity(), 2);
+    /// assert_eq!(nevec.head(), &1);
+    /// assert_eq!(nevec.tail(), []);
+    /// assert!(nevec.capacity() >= 3);
     /// assert_eq!(nevec, [1]);
     /// ```
     #[inline]
     pub fn with_tail_capacity(head: T, tail_capacity: usize) -> Self {
-        Self {
-            head,
-            tail: Vec::with_capacity(tail_capacity),
-        }
+        let mut vec = Vec::with_capacity(tail_capacity + 1);
+        vec.push(head);
+        Self { vec }
+    }
+
+    /// Constructs a new `NEVec<T>` with the given `head` and the total `capacity`, including
+    /// the head. The tail will be empty.
+    /// The capacity is a lower bound; the `NEVec<T>` may hold more, but will not reallocate
+    /// until it exceeds this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = NEVec::with_capacity(1, 10);
+    /// assert_eq!(nevec, [1]);
+    /// assert!(nevec.capacity() >= 10);
+    ///
+    /// let nevec = NEVec::with_capacity(1, 0);
+    /// assert!(nevec.capacity() >= 1);
+    /// ```
+    #[inline]
+    pub fn with_capacity(head: T, capacity: usize) -> Self {
+        Self::with_tail_capacity(head, capacity.saturating_sub(1))
     }
 
     /// Constructs a new `NEVec<T>` with the given element repeated `n` times.
@@ -178,8 +207,8 @@ impl<T> NEVec<T> {
     /// use rust2fun::prelude::*;
     ///
     /// let nevec = NEVec::from_elem(1, NonZeroUsize::new(3).unwrap());
-    /// assert_eq!(nevec.head, 1);
-    /// assert_eq!(nevec.tail, [1, 1]);
+    /// assert_eq!(nevec.head(), &1);
+    /// assert_eq!(nevec.tail(), [1, 1]);
     /// assert_eq!(nevec, [1, 1, 1]);
     /// ```
     #[inline]
@@ -188,8 +217,7 @@ impl<T> NEVec<T> {
         T: Clone,
     {
         Self {
-            head: elem.clone(),
-            tail: vec![elem; n.get() - 1],
+            vec: vec![elem; n.get()],
         }
     }
 
@@ -209,17 +237,33 @@ impl<T> NEVec<T> {
     /// assert_eq!(NEVec::<bool>::from_vec(vec![]), None);
     /// ```
     #[inline]
-    pub fn from_vec(mut vec: Vec<T>) -> Option<Self> {
+    pub fn from_vec(vec: Vec<T>) -> Option<Self> {
         if vec.is_empty() {
             None
         } else {
-            Some(Self {
-                head: vec.remove(0),
-                tail: vec,
-            })
+            Some(Self { vec })
         }
     }
 
+    /// Constructs a new `NEVec<T>` from the elements of an iterator.
+    /// Returns `None` if the iterator is empty.
+    ///
+    /// Unlike [`FromIterator`], which panics on an empty iterator, this never panics.
+    /// See also [`CollectNonEmpty::collect_nonempty`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(ne_vec![1, 2, 3]), NEVec::try_from_iter(1..4));
+    /// assert_eq!(None, NEVec::try_from_iter(1..1));
+    /// ```
+    #[inline]
+    pub fn try_from_iter(iter: impl IntoIterator<Item = T>) -> Option<Self> {
+        Self::from_vec(iter.into_iter().collect())
+    }
+
     /// Constructs a new `NEVec<T>` from a given slice.
     /// Returns `None` if the given slice is empty.
     /// Otherwise, returns `Some(nevec)`, where `nevec` is the `NEVec<T>` constructed from the
@@ -238,10 +282,290 @@ impl<T> NEVec<T> {
     where
         T: Clone,
     {
-        slice.split_first().map(|(h, t)| Self {
-            head: h.clone(),
-            tail: t.to_vec(),
-        })
+        Self::from_vec(slice.to_vec())
+    }
+
+    /// Returns the first element of the NEVec, known as the head.
+    /// This is always present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!(nevec.head(), &1);
+    /// ```
+    #[inline]
+    pub fn head(&self) -> &T {
+        &self.vec[0]
+    }
+
+    /// Returns a mutable reference to the head of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2, 3];
+    /// *nevec.head_mut() = 4;
+    /// assert_eq!(nevec, [4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn head_mut(&mut self) -> &mut T {
+        &mut self.vec[0]
+    }
+
+    /// Returns the remaining elements of the NEVec, known as the tail.
+    /// This may be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!(nevec.tail(), [2, 3]);
+    ///
+    /// let nevec = ne_vec![1];
+    /// assert!(nevec.tail().is_empty());
+    /// ```
+    #[inline]
+    pub fn tail(&self) -> &[T] {
+        &self.vec[1..]
+    }
+
+    /// Returns a mutable slice of the tail of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2, 3];
+    /// nevec.tail_mut()[0] = 4;
+    /// assert_eq!(nevec, [1, 4, 3]);
+    /// ```
+    #[inline]
+    pub fn tail_mut(&mut self) -> &mut [T] {
+        &mut self.vec[1..]
+    }
+
+    /// Extracts a slice containing all the elements of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!(nevec.as_slice(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Extracts a mutable slice containing all the elements of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![3, 1, 2];
+    /// nevec.as_mut_slice().reverse();
+    /// assert_eq!(nevec, [2, 1, 3]);
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+
+    /// Borrows the NEVec as a [NESlice], keeping the non-empty guarantee.
+    ///
+    /// [NESlice]: crate::data::ne_slice::NESlice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// let slice = nevec.as_ne_slice();
+    /// assert_eq!(slice.last(), &3);
+    /// ```
+    #[inline]
+    pub fn as_ne_slice(&self) -> NESlice<'_, T> {
+        NESlice::from_slice(&self.vec).unwrap_or_else(|| non_empty_invariant_failed())
+    }
+
+    /// Returns the total number of elements the NEVec can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = NEVec::with_tail_capacity(1, 10);
+    /// assert!(nevec.capacity() >= 11);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be inserted.
+    /// The NEVec may reserve more space to speculatively avoid frequent reallocations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1];
+    /// nevec.reserve(10);
+    /// assert!(nevec.capacity() >= 11);
+    /// ```
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more elements to be inserted.
+    /// The allocator may still give the NEVec more space than it requests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1];
+    /// nevec.reserve_exact(10);
+    /// assert!(nevec.capacity() >= 11);
+    /// ```
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.vec.reserve_exact(additional);
+    }
+
+    /// Shrinks the capacity of the NEVec as much as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = NEVec::with_capacity(1, 10);
+    /// nevec.push(2);
+    /// nevec.shrink_to_fit();
+    /// assert!(nevec.capacity() >= 2);
+    /// ```
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+
+    /// Appends an element to the back of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2];
+    /// nevec.push(3);
+    /// assert_eq!(nevec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.vec.push(value);
+    }
+
+    /// Removes the last element of the tail and returns it, or `None` if the tail is empty.
+    /// The head is never removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2];
+    /// assert_eq!(nevec.pop(), Some(2));
+    /// assert_eq!(nevec.pop(), None);
+    /// assert_eq!(nevec, [1]);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.vec.len() > 1 {
+            self.vec.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Moves all the elements of `other` to the back of the NEVec, leaving `other` empty.
+    ///
+    /// Note that `other` is a [`Vec`] rather than a `NEVec`, as it is left empty afterwards.
+    /// To append a `NEVec`, use [`Extend::extend`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2];
+    /// let mut other = vec![3, 4];
+    /// nevec.append(&mut other);
+    /// assert_eq!(nevec, [1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// ```
+    #[inline]
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.vec.append(other);
+    }
+
+    /// Shortens the NEVec, keeping the first `len` elements and dropping the rest.
+    /// Has no effect if `len` is greater than the current length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2, 3, 4];
+    /// nevec.truncate(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(nevec, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.vec.truncate(len.get());
+    }
+
+    /// Removes all the elements of the tail, leaving only the head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2, 3];
+    /// nevec.clear_tail();
+    /// assert_eq!(nevec, [1]);
+    /// ```
+    #[inline]
+    pub fn clear_tail(&mut self) {
+        self.vec.truncate(1);
     }
 
     /// Removes the element at the given index and returns it.
@@ -297,27 +621,14 @@ impl<T> NEVec<T> {
             panic!("swap_remove index (is {index}) should be < len (is {len})");
         }
 
-        let dst = if index == 0 {
-            if self.tail.is_empty() {
-                non_empty_invariant_failed();
-            }
-
-            &mut self.head
-        } else {
-            if index >= self.len() {
-                assert_failed(index, self.len());
-            };
-
-            unsafe { self.tail.as_mut_ptr().add(index - 1) }
-        };
-
-        unsafe {
-            let result = ptr::read(dst);
-            let new_tail_len = self.tail.len() - 1;
-            ptr::copy(self.tail.as_ptr().add(new_tail_len), dst, 1);
-            self.tail.set_len(new_tail_len);
-            result
+        if index >= self.len() {
+            assert_failed(index, self.len());
+        }
+        if self.len() == 1 {
+            non_empty_invariant_failed();
         }
+
+        self.vec.swap_remove(index)
     }
 
     /// Inserts an element at position `index` within the NEVec, shifting all
@@ -354,13 +665,11 @@ impl<T> NEVec<T> {
             panic!("insertion index (is {index}) should be <= len (is {len})");
         }
 
-        if index == 0 {
-            self.tail.insert(0, mem::replace(&mut self.head, element));
-        } else if index <= self.len() {
-            self.tail.insert(index - 1, element);
-        } else {
+        if index > self.len() {
             assert_failed(index, self.len());
         }
+
+        self.vec.insert(index, element);
     }
 
     /// Removes and returns the element at position `index` within the NEVec,
@@ -412,64 +721,189 @@ impl<T> NEVec<T> {
             panic!("removal index (is {index}) should be < len (is {len})");
         }
 
-        if index == 0 {
-            if self.tail.is_empty() {
-                non_empty_invariant_failed();
-            }
-
-            mem::replace(&mut self.head, self.tail.remove(0))
-        } else if index < self.len() {
-            self.tail.remove(index - 1)
-        } else {
+        if index >= self.len() {
             assert_failed(index, self.len());
         }
+        if self.len() == 1 {
+            non_empty_invariant_failed();
+        }
+
+        self.vec.remove(index)
     }
 
-    /// Returns the number of elements in the NEVec, including the head.
-    /// This is always at least one.
+    /// Retains only the elements specified by the predicate. As all the elements may be removed,
+    /// the NEVec is consumed and the result is a [`Vec`].
     ///
     /// # Examples
     ///
     /// ```
     /// use rust2fun::prelude::*;
     ///
-    /// let nevec = ne_vec![1, 2, 3];
-    /// assert_eq!(nevec.len(), 3);
+    /// let nevec = ne_vec![1, 2, 3, 4];
+    /// assert_eq!(vec![2, 4], nevec.retain(|x| x % 2 == 0));
+    ///
+    /// let nevec = ne_vec![1, 3];
+    /// assert!(nevec.retain(|x| x % 2 == 0).is_empty());
     /// ```
     #[inline]
-    pub fn len(&self) -> usize {
-        self.tail.len() + 1
+    pub fn retain(self, f: impl FnMut(&T) -> bool) -> Vec<T> {
+        let mut vec = self.vec;
+        vec.retain(f);
+        vec
     }
 
-    /// Returns the first element of the NEVec. This is always the head.
+    /// Retains only the elements specified by the predicate.
+    /// Returns `None` if no element satisfies the predicate.
     ///
     /// # Examples
     ///
     /// ```
     /// use rust2fun::prelude::*;
     ///
-    /// let nevec = ne_vec![1, 2, 3];
-    /// assert_eq!(nevec.first(), &1);
+    /// let nevec = ne_vec![1, 2, 3, 4];
+    /// assert_eq!(Some(ne_vec![2, 4]), nevec.filter(|x| x % 2 == 0));
+    ///
+    /// let nevec = ne_vec![1, 3];
+    /// assert_eq!(None, nevec.filter(|x| x % 2 == 0));
     /// ```
     #[inline]
-    pub fn first(&self) -> &T {
-        &self.head
+    pub fn filter(self, f: impl FnMut(&T) -> bool) -> Option<NEVec<T>> {
+        NEVec::from_vec(self.retain(f))
     }
 
-    /// Returns a mutable reference to the first element of the NEVec. This is always the head.
+    /// Filters and maps the elements at the same time, keeping the values for which `f`
+    /// returns `Some`. Returns `None` if `f` returns `None` for every element.
     ///
     /// # Examples
     ///
     /// ```
     /// use rust2fun::prelude::*;
     ///
-    /// let mut nevec = ne_vec![1, 2, 3];
+    /// let nevec = ne_vec!["1", "two", "3"];
+    /// assert_eq!(Some(ne_vec![1, 3]), nevec.filter_map_ne(|s| s.parse::<i32>().ok()));
+    ///
+    /// let nevec = ne_vec!["one", "two"];
+    /// assert_eq!(None, nevec.filter_map_ne(|s| s.parse::<i32>().ok()));
+    /// ```
+    #[inline]
+    pub fn filter_map_ne<U>(self, f: impl FnMut(T) -> Option<U>) -> Option<NEVec<U>> {
+        NEVec::from_vec(self.vec.into_iter().filter_map(f).collect())
+    }
+
+    /// Removes consecutive repeated elements in the NEVec according to the [`PartialEq`] trait
+    /// implementation. The head is always kept.
+    ///
+    /// If the NEVec is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 1, 2, 3, 2, 2];
+    /// nevec.dedup();
+    /// assert_eq!(nevec, [1, 2, 3, 2]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes all but the first of consecutive elements in the NEVec that resolve to the same
+    /// key. The head is always kept.
+    ///
+    /// If the NEVec is sorted, this removes all duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![10, 20, 21, 30, 20];
+    /// nevec.dedup_by_key(|i| *i / 10);
+    /// assert_eq!(nevec, [10, 20, 30, 20]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<F, K>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Removes all but the first of consecutive elements in the NEVec satisfying a given
+    /// equality relation. The head is always kept.
+    ///
+    /// The `same_bucket` function is passed references to two elements from the NEVec and
+    /// must determine if the elements compare equal. The elements are passed in opposite order
+    /// from their order in the NEVec, so if `same_bucket(a, b)` returns `true`, `a` is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec!["foo", "FOO", "bar", "Bar", "baz", "bar"];
+    /// nevec.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(nevec, ["foo", "bar", "baz", "bar"]);
+    /// ```
+    #[inline]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.vec.dedup_by(same_bucket);
+    }
+
+    /// Returns the number of elements in the NEVec, including the head.
+    /// This is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!(nevec.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns the first element of the NEVec. This is always the head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!(nevec.first(), &1);
+    /// ```
+    #[inline]
+    pub fn first(&self) -> &T {
+        self.head()
+    }
+
+    /// Returns a mutable reference to the first element of the NEVec. This is always the head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![1, 2, 3];
     /// *nevec.first_mut() = 4;
     /// assert_eq!(nevec, [4, 2, 3]);
     /// ```
     #[inline]
     pub fn first_mut(&mut self) -> &mut T {
-        &mut self.head
+        self.head_mut()
     }
 
     /// Returns the last element of the NEVec. If the NEVec has length `1`, this is the head.
@@ -488,7 +922,7 @@ impl<T> NEVec<T> {
     /// ```
     #[inline]
     pub fn last(&self) -> &T {
-        self.tail.last().unwrap_or(&self.head)
+        &self.vec[self.vec.len() - 1]
     }
 
     /// Returns a mutable reference to the last element of the NEVec. If the NEVec has length `1`,
@@ -509,7 +943,166 @@ impl<T> NEVec<T> {
     /// ```
     #[inline]
     pub fn last_mut(&mut self) -> &mut T {
-        self.tail.last_mut().unwrap_or(&mut self.head)
+        let last = self.vec.len() - 1;
+        &mut self.vec[last]
+    }
+
+    /// Returns the element that gives the maximum value from the specified function.
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(-3, ne_vec![1, -3, 2].max_by_key(|x: &i32| x.abs()));
+    /// ```
+    #[inline]
+    pub fn max_by_key<K: Ord>(self, f: impl FnMut(&T) -> K) -> T {
+        self.maximum_by_key(f)
+    }
+
+    /// Returns the element that gives the minimum value from the specified function.
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(-1, ne_vec![-1, -3, 2].min_by_key(|x: &i32| x.abs()));
+    /// ```
+    #[inline]
+    pub fn min_by_key<K: Ord>(self, f: impl FnMut(&T) -> K) -> T {
+        self.minimum_by_key(f)
+    }
+
+    /// Reduces the elements to a single one by repeatedly applying `f`, starting from the head.
+    /// Unlike [`Iterator::reduce`], the result is never `None`.
+    /// This is the same as [`Reducible::reduce_left`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(6, ne_vec![1, 2, 3].fold1(|x, y| x + y));
+    /// ```
+    #[inline]
+    pub fn fold1(self, f: impl FnMut(T, T) -> T) -> T {
+        self.reduce_left(f)
+    }
+
+    /// Returns the head and the tail of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!((&1, &[2, 3][..]), nevec.split_first());
+    /// ```
+    #[inline]
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.as_ne_slice().split_first()
+    }
+
+    /// Returns the last element and all the rest of the elements of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!((&3, &[1, 2][..]), nevec.split_last());
+    /// ```
+    #[inline]
+    pub fn split_last(&self) -> (&T, &[T]) {
+        self.as_ne_slice().split_last()
+    }
+
+    /// Splits the NEVec into two at the given index. The first part contains the elements
+    /// `[0, mid)` and is never empty, the second part contains the elements `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// let (left, right) = nevec.split_at(NonZeroUsize::new(1).unwrap());
+    /// assert_eq!(left, ne_vec![1]);
+    /// assert_eq!(right, vec![2, 3]);
+    /// ```
+    #[inline]
+    pub fn split_at(mut self, mid: NonZeroUsize) -> (NEVec<T>, Vec<T>) {
+        let rest = self.vec.split_off(mid.get());
+        (self, rest)
+    }
+
+    /// Splits the NEVec into runs of consecutive elements for which `same_group` returns `true`
+    /// when called on the last element of the current group and the next element.
+    /// Neither the groups nor the outer NEVec can be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 1, 2, 3, 3, 3, 1];
+    /// let groups = nevec.group_by(|x, y| x == y);
+    /// assert_eq!(groups, ne_vec![ne_vec![1, 1], ne_vec![2], ne_vec![3, 3, 3], ne_vec![1]]);
+    /// ```
+    pub fn group_by(self, mut same_group: impl FnMut(&T, &T) -> bool) -> NEVec<NEVec<T>> {
+        let (head, tail) = self.into_head_tail();
+        let mut groups = NEVec::new(NEVec::new(head));
+        for x in tail {
+            let group = groups.last_mut();
+            if same_group(group.last(), &x) {
+                group.push(x);
+            } else {
+                groups.push(NEVec::new(x));
+            }
+        }
+
+        groups
+    }
+
+    /// Splits the NEVec into chunks of `size` elements. The last chunk may be shorter if the
+    /// length of the NEVec is not divisible by `size`.
+    /// Neither the chunks nor the outer NEVec can be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3, 4, 5];
+    /// let chunks = nevec.chunks(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(chunks, ne_vec![ne_vec![1, 2], ne_vec![3, 4], ne_vec![5]]);
+    /// ```
+    pub fn chunks(self, size: NonZeroUsize) -> NEVec<NEVec<T>> {
+        let size = size.get();
+        let (head, tail) = self.into_head_tail();
+        let mut chunks = NEVec::new(NEVec::new(head));
+        for x in tail {
+            let chunk = chunks.last_mut();
+            if chunk.len() < size {
+                chunk.push(x);
+            } else {
+                chunks.push(NEVec::new(x));
+            }
+        }
+
+        chunks
     }
 
     /// Returns a reference to an element, or `None` if out of bounds.
@@ -527,11 +1120,7 @@ impl<T> NEVec<T> {
     /// ```
     #[inline]
     pub fn get(&self, index: usize) -> Option<&T> {
-        if index == 0 {
-            Some(&self.head)
-        } else {
-            self.tail.get(index - 1)
-        }
+        self.vec.get(index)
     }
 
     /// Returns a mutable reference to an element, or `None` if out of bounds.
@@ -549,11 +1138,109 @@ impl<T> NEVec<T> {
     /// ```
     #[inline]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index == 0 {
-            Some(&mut self.head)
-        } else {
-            self.tail.get_mut(index - 1)
-        }
+        self.vec.get_mut(index)
+    }
+
+    /// Returns `true` if the NEVec contains an element with the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert!(nevec.contains(&1));
+    /// assert!(nevec.contains(&3));
+    /// assert!(!nevec.contains(&4));
+    /// ```
+    #[inline]
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.vec.contains(x)
+    }
+
+    /// Searches for an element satisfying the predicate, returning its index.
+    /// The index of the head is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!(Some(0), nevec.position(|x| *x == 1));
+    /// assert_eq!(Some(2), nevec.position(|x| *x > 2));
+    /// assert_eq!(None, nevec.position(|x| *x > 3));
+    /// ```
+    #[inline]
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.vec.iter().position(predicate)
+    }
+
+    /// Binary searches this sorted NEVec for a given element. The index of the head is `0`.
+    ///
+    /// If the value is found then [`Result::Ok`] is returned, containing the index of the
+    /// matching element. If the value is not found then [`Result::Err`] is returned, containing
+    /// the index where a matching element could be inserted while maintaining sorted order.
+    /// See [`slice::binary_search`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 3, 5];
+    /// assert_eq!(Ok(0), nevec.binary_search(&1));
+    /// assert_eq!(Ok(2), nevec.binary_search(&5));
+    /// assert_eq!(Err(0), nevec.binary_search(&0));
+    /// assert_eq!(Err(2), nevec.binary_search(&4));
+    /// ```
+    #[inline]
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.vec.binary_search(x)
+    }
+
+    /// Binary searches this sorted NEVec with a comparator function.
+    /// See [`NEVec::binary_search`] for the meaning of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 3, 5];
+    /// assert_eq!(Ok(1), nevec.binary_search_by(|x| x.cmp(&3)));
+    /// assert_eq!(Err(3), nevec.binary_search_by(|x| x.cmp(&6)));
+    /// ```
+    #[inline]
+    pub fn binary_search_by(&self, f: impl FnMut(&T) -> Ordering) -> Result<usize, usize> {
+        self.vec.binary_search_by(f)
+    }
+
+    /// Binary searches this NEVec, sorted by the given key, for the given key value.
+    /// See [`NEVec::binary_search`] for the meaning of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![(1, 'a'), (3, 'b'), (5, 'c')];
+    /// assert_eq!(Ok(2), nevec.binary_search_by_key(&5, |&(k, _)| k));
+    /// assert_eq!(Err(1), nevec.binary_search_by_key(&2, |&(k, _)| k));
+    /// ```
+    #[inline]
+    pub fn binary_search_by_key<B: Ord>(
+        &self,
+        b: &B,
+        f: impl FnMut(&T) -> B,
+    ) -> Result<usize, usize> {
+        self.vec.binary_search_by_key(b, f)
     }
 
     /// Copies `self` into a new [`Vec`].
@@ -575,10 +1262,7 @@ impl<T> NEVec<T> {
     where
         T: Clone,
     {
-        let mut vec = Vec::with_capacity(self.len());
-        vec.push(self.head.clone());
-        vec.extend_from_slice(&self.tail);
-        vec
+        self.vec.clone()
     }
 
     /// Converts `self` into a [`Vec`].
@@ -596,10 +1280,140 @@ impl<T> NEVec<T> {
     /// ```
     #[inline]
     pub fn into_vec(self) -> Vec<T> {
-        let mut vec = Vec::with_capacity(self.len());
-        vec.push(self.head);
-        vec.extend(self.tail);
-        vec
+        self.vec
+    }
+
+    /// Zips two NEVecs into a NEVec of pairs. The result has the length of the shorter input,
+    /// which is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let zipped = ne_vec![1, 2, 3].zip(ne_vec!["a", "b"]);
+    /// assert_eq!(zipped, [(1, "a"), (2, "b")]);
+    /// ```
+    #[inline]
+    pub fn zip<U>(self, other: NEVec<U>) -> NEVec<(T, U)> {
+        self.zip_with(other, |t, u| (t, u))
+    }
+
+    /// Zips two NEVecs with the given function. The result has the length of the shorter input,
+    /// which is always at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let sums = ne_vec![1, 2, 3].zip_with(ne_vec![10, 20, 30, 40], |x, y| x + y);
+    /// assert_eq!(sums, [11, 22, 33]);
+    /// ```
+    #[inline]
+    pub fn zip_with<U, V>(self, other: NEVec<U>, mut f: impl FnMut(T, U) -> V) -> NEVec<V> {
+        NEVec {
+            vec: self
+                .vec
+                .into_iter()
+                .zip(other.vec)
+                .map(|(t, u)| f(t, u))
+                .collect(),
+        }
+    }
+
+    /// Splits the NEVec into its head and an iterator over its tail.
+    fn into_head_tail(self) -> (T, vec::IntoIter<T>) {
+        let mut iter = self.vec.into_iter();
+        let head = iter.next().unwrap_or_else(|| non_empty_invariant_failed());
+        (head, iter)
+    }
+}
+
+impl<A, B> NEVec<(A, B)> {
+    /// Converts a NEVec of pairs into a pair of NEVecs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let (xs, ys) = ne_vec![(1, "a"), (2, "b")].unzip();
+    /// assert_eq!(xs, [1, 2]);
+    /// assert_eq!(ys, ["a", "b"]);
+    /// ```
+    #[inline]
+    pub fn unzip(self) -> (NEVec<A>, NEVec<B>) {
+        let (a, b) = self.vec.into_iter().unzip();
+        (NEVec { vec: a }, NEVec { vec: b })
+    }
+}
+
+impl<T> NEVec<NEVec<T>> {
+    /// Flattens a NEVec of NEVecs into a single NEVec, allocating the result once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nested = ne_vec![ne_vec![1, 2], ne_vec![3], ne_vec![4, 5]];
+    /// assert_eq!(nested.flatten(), [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn flatten(self) -> NEVec<T> {
+        let mut vec = Vec::with_capacity(self.iter().map(NEVec::len).sum());
+        for nevec in self.vec {
+            vec.extend(nevec.vec);
+        }
+        NEVec { vec }
+    }
+
+    /// Concatenates the inner NEVecs into a single NEVec of cloned elements, allocating the
+    /// result once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nested = ne_vec![ne_vec![1, 2], ne_vec![3]];
+    /// assert_eq!(nested.concat(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn concat(&self) -> NEVec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.iter().map(NEVec::len).sum());
+        for nevec in self.iter() {
+            vec.extend_from_slice(nevec);
+        }
+        NEVec { vec }
+    }
+}
+
+impl<T: Clone> NEVec<NESlice<'_, T>> {
+    /// Concatenates the non-empty slices into a single NEVec of cloned elements, allocating
+    /// the result once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let xs = [1, 2];
+    /// let ys = [3];
+    /// let slices = ne_vec![xs.as_slice(), ys.as_slice()].map(|s| NESlice::from_slice(s).unwrap());
+    /// assert_eq!(slices.concat(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn concat(&self) -> NEVec<T> {
+        let mut vec = Vec::with_capacity(self.iter().map(NESlice::len).sum());
+        for slice in self.iter() {
+            vec.extend_from_slice(slice);
+        }
+        NEVec { vec }
     }
 }
 
@@ -613,7 +1427,37 @@ impl<T: Default> Default for NEVec<T> {
 impl<T> Extend<T> for NEVec<T> {
     #[inline]
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        self.tail.extend(iter);
+        self.vec.extend(iter);
+    }
+}
+
+impl<T> Deref for NEVec<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> DerefMut for NEVec<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+}
+
+impl<T> AsRef<[T]> for NEVec<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> AsMut<[T]> for NEVec<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.vec
     }
 }
 
@@ -622,22 +1466,14 @@ impl<T> Index<usize> for NEVec<T> {
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        if index == 0 {
-            &self.head
-        } else {
-            &self.tail[index - 1]
-        }
+        &self.vec[index]
     }
 }
 
 impl<T> IndexMut<usize> for NEVec<T> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index == 0 {
-            &mut self.head
-        } else {
-            &mut self.tail[index - 1]
-        }
+        &mut self.vec[index]
     }
 }
 
@@ -658,8 +1494,8 @@ fn non_empty_invariant_failed() -> ! {
 /// use rust2fun::prelude::*;
 ///
 /// let nevec = ne_vec![1, 2, 3];
-/// assert_eq!(nevec.head, 1);
-/// assert_eq!(nevec.tail, [2, 3]);
+/// assert_eq!(nevec.head(), &1);
+/// assert_eq!(nevec.tail(), [2, 3]);
 /// ```
 ///
 /// - Create a [`NEVec`] from a given element and size:
@@ -689,10 +1525,8 @@ macro_rules! ne_vec {
         $crate::data::ne_vec::NEVec::new($head)
     );
     ($head:expr, $($tail:expr),* $(,)?) => (
-        $crate::data::ne_vec::NEVec {
-            head: $head,
-            tail: vec![$($tail),*],
-        }
+        $crate::data::ne_vec::NEVec::from_vec(vec![$head, $($tail),*])
+            .expect("NEVec cannot be empty")
     );
     ($elem:expr; $n:expr) => (
         $crate::data::ne_vec::NEVec::from_elem(
@@ -707,14 +1541,13 @@ flatmap_iter!(NEVec);
 semigroupal_iter!(NEVec);
 semigroup_extend!(NEVec);
 invariant_functor!(NEVec<T>);
-and_then_flat_map!(NEVec<T>);
+and_then_iter!(NEVec);
 
 impl<A, B> Functor<B> for NEVec<A> {
     #[inline]
-    fn map(self, mut f: impl FnMut(A) -> B) -> NEVec<B> {
+    fn map(self, f: impl FnMut(A) -> B) -> NEVec<B> {
         NEVec {
-            head: f(self.head),
-            tail: self.tail.map(f),
+            vec: self.vec.map(f),
         }
     }
 }
@@ -725,3 +1558,66 @@ impl<T> Pure for NEVec<T> {
         NEVec::new(x)
     }
 }
+
+impl<A: Clone, B> CoflatMap<B> for NEVec<A> {
+    #[inline]
+    fn coflat_map<F>(self, mut f: F) -> NEVec<B>
+    where
+        F: FnMut(NEVec<A>) -> B,
+    {
+        NEVec {
+            vec: (0..self.len())
+                .map(|i| {
+                    f(NEVec {
+                        vec: self.vec[i..].to_vec(),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<T> Comonad for NEVec<T> {
+    #[inline]
+    fn extract(self) -> T {
+        self.into_head_tail().0
+    }
+}
+
+impl<A> Reducible for NEVec<A> {
+    #[inline]
+    fn reduce_left_to<B>(self, f: impl FnOnce(A) -> B, g: impl FnMut(B, A) -> B) -> B {
+        let (head, tail) = self.into_head_tail();
+        tail.fold(f(head), g)
+    }
+}
+
+impl<A, GB> NonEmptyTraverse<GB> for NEVec<A>
+where
+    GB: Functor<NEVec<<GB as Higher>::Param>>,
+    GB::Target<NEVec<<GB as Higher>::Param>>:
+        Semigroupal<<GB as Higher>::Param, Target<<GB as Higher>::Param> = GB>,
+    <GB::Target<NEVec<<GB as Higher>::Param>> as Higher>::Target<(
+        NEVec<<GB as Higher>::Param>,
+        <GB as Higher>::Param,
+    )>: Functor<
+        NEVec<<GB as Higher>::Param>,
+        Target<NEVec<<GB as Higher>::Param>> = GB::Target<NEVec<<GB as Higher>::Param>>,
+    >,
+{
+    #[inline]
+    fn non_empty_traverse<F>(self, mut f: F) -> GB::Target<NEVec<GB::Param>>
+    where
+        F: FnMut(A) -> GB,
+    {
+        let tail_capacity = self.len() - 1;
+        let (head, tail) = self.into_head_tail();
+        let head = f(head).map(|b| NEVec::with_tail_capacity(b, tail_capacity));
+        tail.fold(head, |acc, a| {
+            acc.product(f(a)).map(|(mut bs, b)| {
+                bs.push(b);
+                bs
+            })
+        })
+    }
+}
@@ -8,7 +8,7 @@ macro_rules! __impl_slice_eq {
         {
             #[inline]
             fn eq(&self, other: &$rhs) -> bool {
-                self.len() == other.len() && self.head == other[0] && self.tail == &other[1..]
+                self.as_slice() == &other[..]
             }
         }
 
@@ -36,6 +36,6 @@ impl<T, U> PartialEq<NEVec<U>> for NEVec<T>
 {
     #[inline]
     fn eq(&self, other: &NEVec<U>) -> bool {
-        self.head == other.head && self.tail == other.tail
+        self.as_slice() == other.as_slice()
     }
 }
\ No newline at end of file
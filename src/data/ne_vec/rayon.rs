@@ -0,0 +1,82 @@
+use ::rayon::prelude::*;
+use ::rayon::{slice, vec};
+
+use super::*;
+
+impl<T: Send> IntoParallelIterator for NEVec<T> {
+    type Iter = vec::IntoIter<T>;
+    type Item = T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.vec.into_par_iter()
+    }
+}
+
+impl<'a, T: Sync> IntoParallelIterator for &'a NEVec<T> {
+    type Iter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.vec.par_iter()
+    }
+}
+
+impl<'a, T: Send> IntoParallelIterator for &'a mut NEVec<T> {
+    type Iter = slice::IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.vec.par_iter_mut()
+    }
+}
+
+/// Collects a parallel iterator into a NEVec.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty. Use [NEVec::try_from_par_iter] to handle that case.
+impl<T: Send> FromParallelIterator<T> for NEVec<T> {
+    #[inline]
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self {
+        NEVec::try_from_par_iter(par_iter).unwrap_or_else(|| non_empty_invariant_failed())
+    }
+}
+
+impl<T: Send> NEVec<T> {
+    /// Constructs a new `NEVec<T>` from the elements of a parallel iterator.
+    /// Returns `None` if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(ne_vec![1, 2, 3]), NEVec::try_from_par_iter((1..4).into_par_iter()));
+    /// assert_eq!(None, NEVec::try_from_par_iter((1..1).into_par_iter()));
+    /// ```
+    #[inline]
+    pub fn try_from_par_iter(par_iter: impl IntoParallelIterator<Item = T>) -> Option<Self> {
+        NEVec::from_vec(par_iter.into_par_iter().collect())
+    }
+
+    /// Maps each element in parallel, preserving the structure of the NEVec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let nevec = ne_vec![1, 2, 3];
+    /// assert_eq!(ne_vec![2, 4, 6], nevec.par_map(|x| x * 2));
+    /// ```
+    #[inline]
+    pub fn par_map<U: Send>(self, f: impl Fn(T) -> U + Sync + Send) -> NEVec<U> {
+        NEVec {
+            vec: self.vec.into_par_iter().map(f).collect(),
+        }
+    }
+}
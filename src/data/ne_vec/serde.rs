@@ -0,0 +1,18 @@
+use ::serde::de::Error;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::*;
+
+impl<T: Serialize> Serialize for NEVec<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NEVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::deserialize(deserializer)?;
+        NEVec::from_vec(vec).ok_or_else(|| D::Error::invalid_length(0, &"a non-empty sequence"))
+    }
+}
@@ -0,0 +1,195 @@
+use core::cmp::Ordering;
+
+use super::*;
+
+impl<T> NEVec<T> {
+    /// Sorts the NEVec.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and O(n * log(n)) worst-case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![-5, 4, 1, -3, 2];
+    /// nevec.sort();
+    /// assert_eq!(nevec, [-5, -3, 1, 2, 4]);
+    /// ```
+    #[inline]
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the NEVec with a comparator function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and O(n * log(n)) worst-case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![5, 4, 1, 3, 2];
+    /// nevec.sort_by(|a, b| a.cmp(b));
+    /// assert_eq!(nevec, [1, 2, 3, 4, 5]);
+    ///
+    /// // reverse sorting
+    /// nevec.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(nevec, [5, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.vec.sort_by(compare);
+    }
+
+    /// Sorts the NEVec with a key extraction function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and O(m * n * log(n))
+    /// worst-case, where the key function is O(m).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![-5i32, 4, 1, -3, 2];
+    /// nevec.sort_by_key(|k| k.abs());
+    /// assert_eq!(nevec, [1, 2, -3, 4, -5]);
+    /// ```
+    #[inline]
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the NEVec, but might not preserve the order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![-5, 4, 1, -3, 2];
+    /// nevec.sort_unstable();
+    /// assert_eq!(nevec, [-5, -3, 1, 2, 4]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp);
+    }
+
+    /// Sorts the NEVec with a comparator function, but might not preserve the order of equal
+    /// elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![5, 4, 1, 3, 2];
+    /// nevec.sort_unstable_by(|a, b| a.cmp(b));
+    /// assert_eq!(nevec, [1, 2, 3, 4, 5]);
+    ///
+    /// // reverse sorting
+    /// nevec.sort_unstable_by(|a, b| b.cmp(a));
+    /// assert_eq!(nevec, [5, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.vec.sort_unstable_by(compare);
+    }
+
+    /// Sorts the NEVec with a key extraction function, but might not preserve the order of
+    /// equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut nevec = ne_vec![-5i32, 4, 1, -3, 2];
+    /// nevec.sort_unstable_by_key(|k| k.abs());
+    /// assert_eq!(nevec, [1, 2, -3, 4, -5]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_unstable_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Checks if the elements of the NEVec are sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(ne_vec![1, 2, 2, 9].is_sorted());
+    /// assert!(!ne_vec![1, 3, 2, 4].is_sorted());
+    /// assert!(ne_vec![0].is_sorted());
+    /// ```
+    #[inline]
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Checks if the elements of the NEVec are sorted using the given comparator function,
+    /// which returns `true` if its arguments are in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(ne_vec![1, 2, 2, 9].is_sorted_by(|a, b| a <= b));
+    /// assert!(!ne_vec![1, 2, 2, 9].is_sorted_by(|a, b| a < b));
+    /// ```
+    #[inline]
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.vec.windows(2).all(|w| compare(&w[0], &w[1]))
+    }
+
+    /// Checks if the elements of the NEVec are sorted using the given key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(ne_vec!["c", "bb", "aaa"].is_sorted_by_key(|s| s.len()));
+    /// assert!(!ne_vec![-2i32, -1, 0, 3].is_sorted_by_key(|n| n.abs()));
+    /// ```
+    #[inline]
+    pub fn is_sorted_by_key<K, F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(&T) -> K,
+        K: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| f(a) <= f(b))
+    }
+}
@@ -0,0 +1,186 @@
+//! Nested functors.
+//!
+//! [`Nested<FGA, GA>`][Nested] wraps a value `FGA = F<G<A>>` of two nested type constructors
+//! and treats it as a single layer `Nested<F, G><A>`. Functors, applicatives and traversables
+//! compose, so `Nested` implements [Functor], [Apply], [Pure] and [Traverse] whenever both
+//! layers do.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let xs = Nested::new(vec![Some(1), None, Some(3)]);
+//! assert_eq!(vec![Some(2), None, Some(6)], xs.map(|x| x * 2).into_inner());
+//!
+//! let a = Nested::new(vec![Some(1), Some(2)]);
+//! let b = Nested::new(vec![Some(10), None]);
+//! let c = Nested::new(vec![Some(100)]);
+//! assert_eq!(
+//!     vec![Some(111), None, Some(112), None],
+//!     a.map3(b, c, |x, y, z| x + y + z).into_inner()
+//! );
+//! ```
+//!
+//! [Apply]: crate::apply::Apply
+
+use crate::apply::Apply;
+use crate::fn_k::{map_k, FnK};
+use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::invariant::Invariant;
+use crate::pure::Pure;
+use crate::semigroupal::Semigroupal;
+use crate::traverse::Traverse;
+
+/// A value `F<G<A>>` of two nested type constructors treated as a single layer.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash, Default)]
+pub struct Nested<FGA, GA>(FGA, core::marker::PhantomData<GA>);
+
+impl<FGA: Higher<Param = GA>, GA> Nested<FGA, GA> {
+    /// Wrap the nested value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Nested::new(Some(vec![1, 2]));
+    /// assert_eq!(Some(vec![1, 2]), x.into_inner());
+    /// ```
+    #[inline]
+    pub fn new(value: FGA) -> Self {
+        Nested(value, core::marker::PhantomData)
+    }
+
+    /// Unwrap the nested value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Nested::new(vec![Ok::<_, ()>(1)]).map(|x| x + 1);
+    /// assert_eq!(vec![Ok(2)], x.into_inner());
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> FGA {
+        self.0
+    }
+
+    /// Apply a functor transformation to the inner layer, turning `Nested<F, G>` into
+    /// `Nested<F, H>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x = Nested::new(vec![Ok(1), Err("err")]).map_k(OkToOption);
+    /// assert_eq!(vec![Some(2), None], x.map(|x| x * 2).into_inner());
+    /// ```
+    #[inline]
+    pub fn map_k<HA>(self, f: impl FnK<GA, HA>) -> Nested<FGA::Target<HA>, HA>
+    where
+        FGA: Functor<HA>,
+        GA: Higher,
+        HA: Higher<Param = GA::Param>,
+    {
+        Nested::new(map_k(self.0, f))
+    }
+}
+
+impl<FGA: Higher, GA: Higher> Higher for Nested<FGA, GA> {
+    type Param = GA::Param;
+    type Target<T> = Nested<FGA::Target<GA::Target<T>>, GA::Target<T>>;
+}
+
+impl<FGA, GA, B> Invariant<B> for Nested<FGA, GA>
+where
+    FGA: Functor<GA::Target<B>, Param = GA>,
+    GA: Functor<B>,
+{
+    #[inline]
+    fn imap<F, G>(self, f: F, _g: G) -> Self::Target<B>
+    where
+        F: FnMut(GA::Param) -> B,
+        G: FnMut(B) -> GA::Param,
+    {
+        self.map(f)
+    }
+}
+
+impl<FGA, GA, B> Functor<B> for Nested<FGA, GA>
+where
+    FGA: Functor<GA::Target<B>, Param = GA>,
+    GA: Functor<B>,
+{
+    #[inline]
+    fn map(self, mut f: impl FnMut(GA::Param) -> B) -> Self::Target<B> {
+        Nested::new(self.0.map(|ga| ga.map(&mut f)))
+    }
+}
+
+impl<FGA, GA, B> Semigroupal<B> for Nested<FGA, GA>
+where
+    FGA: Semigroupal<GA::Target<B>, Param = GA>,
+    FGA::Target<(GA, GA::Target<B>)>: Functor<
+        GA::Target<(GA::Param, B)>,
+        Target<GA::Target<(GA::Param, B)>> = FGA::Target<GA::Target<(GA::Param, B)>>,
+    >,
+    GA: Semigroupal<B>,
+{
+    #[inline]
+    fn product(self, fb: Self::Target<B>) -> Self::Target<(GA::Param, B)> {
+        Nested::new(self.0.product(fb.0).map(|(ga, gb)| ga.product(gb)))
+    }
+}
+
+impl<FGA, GA, A, B> Apply<A, B> for Nested<FGA, GA>
+where
+    FGA: Functor<GA::Target<B>, Param = GA> + Semigroupal<GA::Target<A>>,
+    FGA::Target<(GA, GA::Target<A>)>:
+        Functor<GA::Target<B>, Target<GA::Target<B>> = FGA::Target<GA::Target<B>>>,
+    GA: Apply<A, B>,
+{
+    #[inline]
+    fn ap(self, fa: Self::Target<A>) -> Self::Target<B>
+    where
+        GA::Param: FnMut(A) -> B,
+    {
+        Nested::new(self.0.product(fa.0).map(|(gf, ga)| gf.ap(ga)))
+    }
+}
+
+impl<FGA, GA> Pure for Nested<FGA, GA>
+where
+    FGA: Pure + Higher<Param = GA>,
+    GA: Pure,
+{
+    #[inline]
+    fn pure(x: GA::Param) -> Self {
+        Nested::new(FGA::pure(GA::pure(x)))
+    }
+}
+
+impl<FGA, GA, HB> Traverse<HB> for Nested<FGA, GA>
+where
+    FGA: Traverse<HB::Target<GA::Target<HB::Param>>, Param = GA>,
+    GA: Traverse<HB>,
+    HB: Higher,
+    HB::Target<GA::Target<HB::Param>>: Higher<Param = GA::Target<HB::Param>>,
+    <HB::Target<GA::Target<HB::Param>> as Higher>::Target<FGA::Target<GA::Target<HB::Param>>>:
+        Functor<
+            Self::Target<HB::Param>,
+            Target<Self::Target<HB::Param>> = HB::Target<Self::Target<HB::Param>>,
+        >,
+{
+    #[inline]
+    fn traverse<F>(self, mut f: F) -> HB::Target<Self::Target<HB::Param>>
+    where
+        F: FnMut(GA::Param) -> HB,
+    {
+        self.0.traverse(|ga| ga.traverse(&mut f)).map(Nested::new)
+    }
+}
@@ -0,0 +1,300 @@
+//! Predicates.
+//!
+//! [`Predicate<A>`][Predicate] wraps a function `A -> bool`. Predicates are contravariant in their
+//! input, so they can be adapted with [Predicate::contramap] and combined with [Divide::divide]
+//! and [Decidable::choose]. Predicates on the same input can be combined with the boolean
+//! operators [Predicate::and], [Predicate::or], [Predicate::xor] and `!`, or as a [Monoid] using
+//! the [Conjunction] and [Disjunction] wrappers.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let positive = Predicate::new(|x: i32| x > 0);
+//! let mut p = positive.contramap(|s: &str| s.len() as i32 - 3);
+//! assert!(p.test("four"));
+//! assert!(!p.test("one"));
+//!
+//! let even = Predicate::new(|x: i32| x % 2 == 0);
+//! let mut p = !even.or(Predicate::new(|x| x > 10));
+//! assert!(p.test(3));
+//! assert!(!p.test(4));
+//! assert!(!p.test(11));
+//! ```
+
+use core::ops::Not;
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+
+use crate::decidable::Decidable;
+use crate::divide::Divide;
+use crate::divisible::Conquer;
+use crate::higher;
+use crate::monoid::Monoid;
+use crate::semigroup::Semigroup;
+
+/// A function `A -> bool`.
+/// See [the module level documentation](self) for more.
+pub struct Predicate<A>(Box<dyn FnMut(A) -> bool>);
+
+impl<A> Predicate<A> {
+    /// Create a new predicate from the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut p = Predicate::new(|x: i32| x % 2 == 0);
+    /// assert!(p.test(2));
+    /// ```
+    #[inline]
+    pub fn new(f: impl FnMut(A) -> bool + 'static) -> Self {
+        Predicate(Box::new(f))
+    }
+
+    /// Test the given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut p = Predicate::new(|s: &str| s.is_empty());
+    /// assert!(p.test(""));
+    /// assert!(!p.test("foo"));
+    /// ```
+    #[inline]
+    pub fn test(&mut self, a: A) -> bool {
+        (self.0)(a)
+    }
+
+    /// Transform a `Predicate<A>` into a `Predicate<B>` by providing a transformation from `B`
+    /// to `A`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut p = Predicate::new(|x: usize| x > 2).contramap(|s: &str| s.len());
+    /// assert!(p.test("foo"));
+    /// assert!(!p.test("be"));
+    /// ```
+    #[inline]
+    pub fn contramap<B>(mut self, mut f: impl FnMut(B) -> A + 'static) -> Predicate<B>
+    where
+        A: 'static,
+    {
+        Predicate::new(move |b| self.test(f(b)))
+    }
+
+    /// Combine two predicates with the logical `&&`. The second predicate is not tested if the
+    /// first one fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut p = Predicate::new(|x: i32| x > 0).and(Predicate::new(|x| x < 10));
+    /// assert!(p.test(5));
+    /// assert!(!p.test(0));
+    /// assert!(!p.test(10));
+    /// ```
+    #[inline]
+    pub fn and(mut self, mut other: Predicate<A>) -> Predicate<A>
+    where
+        A: Clone + 'static,
+    {
+        Predicate::new(move |a: A| self.test(a.clone()) && other.test(a))
+    }
+
+    /// Combine two predicates with the logical `||`. The second predicate is not tested if the
+    /// first one succeeds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut p = Predicate::new(|x: i32| x < 0).or(Predicate::new(|x| x > 10));
+    /// assert!(p.test(-1));
+    /// assert!(p.test(11));
+    /// assert!(!p.test(5));
+    /// ```
+    #[inline]
+    pub fn or(mut self, mut other: Predicate<A>) -> Predicate<A>
+    where
+        A: Clone + 'static,
+    {
+        Predicate::new(move |a: A| self.test(a.clone()) || other.test(a))
+    }
+
+    /// Combine two predicates with the logical exclusive or.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut p = Predicate::new(|x: i32| x > 0).xor(Predicate::new(|x| x % 2 == 0));
+    /// assert!(p.test(1));
+    /// assert!(p.test(-2));
+    /// assert!(!p.test(2));
+    /// assert!(!p.test(-1));
+    /// ```
+    #[inline]
+    pub fn xor(mut self, mut other: Predicate<A>) -> Predicate<A>
+    where
+        A: Clone + 'static,
+    {
+        Predicate::new(move |a: A| self.test(a.clone()) ^ other.test(a))
+    }
+}
+
+impl<A: 'static> Not for Predicate<A> {
+    type Output = Predicate<A>;
+
+    /// Negate the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut p = !Predicate::new(|s: &str| s.is_empty());
+    /// assert!(p.test("foo"));
+    /// assert!(!p.test(""));
+    /// ```
+    #[inline]
+    fn not(mut self) -> Predicate<A> {
+        Predicate::new(move |a| !self.test(a))
+    }
+}
+
+impl<A> Debug for Predicate<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Predicate")
+    }
+}
+
+higher!(Predicate);
+
+impl<A: 'static, B: 'static, C: 'static> Divide<B, C> for Predicate<A> {
+    #[inline]
+    fn divide(
+        mut self,
+        mut fb: Predicate<B>,
+        mut f: impl FnMut(C) -> (A, B) + 'static,
+    ) -> Predicate<C> {
+        Predicate::new(move |c| {
+            let (a, b) = f(c);
+            self.test(a) && fb.test(b)
+        })
+    }
+}
+
+impl<A> Conquer for Predicate<A> {
+    #[inline]
+    fn conquer() -> Self {
+        Predicate::new(|_| true)
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Decidable<B, C> for Predicate<A> {
+    #[inline]
+    fn choose(
+        mut self,
+        mut fb: Predicate<B>,
+        mut f: impl FnMut(C) -> Result<A, B> + 'static,
+    ) -> Predicate<C> {
+        Predicate::new(move |c| match f(c) {
+            Ok(a) => self.test(a),
+            Err(b) => fb.test(b),
+        })
+    }
+}
+
+/// Monoid of predicates under conjunction. The empty predicate is always `true`.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let checks = vec![
+///     Predicate::new(|s: &str| !s.is_empty()),
+///     Predicate::new(|s: &str| s.len() < 5),
+/// ];
+/// let mut p = Monoid::combine_all(checks.into_iter().map(Conjunction)).into_inner();
+/// assert!(p.test("foo"));
+/// assert!(!p.test(""));
+/// assert!(!p.test("foobar"));
+/// ```
+#[derive(Debug)]
+pub struct Conjunction<A>(pub Predicate<A>);
+
+/// Monoid of predicates under disjunction. The empty predicate is always `false`.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let checks = vec![
+///     Predicate::new(|x: i32| x < 0),
+///     Predicate::new(|x: i32| x > 10),
+/// ];
+/// let mut p = Monoid::combine_all(checks.into_iter().map(Disjunction)).into_inner();
+/// assert!(p.test(-1));
+/// assert!(p.test(11));
+/// assert!(!p.test(5));
+/// ```
+#[derive(Debug)]
+pub struct Disjunction<A>(pub Predicate<A>);
+
+impl<A> Conjunction<A> {
+    /// Unwrap the predicate.
+    #[inline]
+    pub fn into_inner(self) -> Predicate<A> {
+        self.0
+    }
+}
+
+impl<A> Disjunction<A> {
+    /// Unwrap the predicate.
+    #[inline]
+    pub fn into_inner(self) -> Predicate<A> {
+        self.0
+    }
+}
+
+impl<A: Clone + 'static> Semigroup for Conjunction<A> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        Conjunction(self.0.and(other.0))
+    }
+}
+
+impl<A: Clone + 'static> Monoid for Conjunction<A> {
+    #[inline]
+    fn empty() -> Self {
+        Conjunction(Predicate::new(|_| true))
+    }
+}
+
+impl<A: Clone + 'static> Semigroup for Disjunction<A> {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        Disjunction(self.0.or(other.0))
+    }
+}
+
+impl<A: Clone + 'static> Monoid for Disjunction<A> {
+    #[inline]
+    fn empty() -> Self {
+        Disjunction(Predicate::new(|_| false))
+    }
+}
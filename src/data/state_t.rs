@@ -0,0 +1,328 @@
+//! State monad transformer.
+//!
+//! [`StateT<F, S, A>`][StateT] wraps a function `S -> F` where `F = G<(S, A)>` is a value of an
+//! underlying monad `G`, threading a state `S` through the computation.
+//!
+//! [StateT::map] and [StateT::flat_map] are [inherent methods](super#inherent-methods) requiring
+//! `'static` functions.
+//!
+//! Chains of [StateT::map] and [StateT::flat_map] are run in a loop, so long chains don't
+//! overflow the stack. Computations nested inside the functions given to `flat_map`, e.g.
+//! recursive ones, still run through the `flat_map` of `G`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! type Stack = Vec<i32>;
+//!
+//! fn push(x: i32) -> StateT<Option<(Stack, ())>, Stack, ()> {
+//!     StateT::modify(move |mut s: Stack| {
+//!         s.push(x);
+//!         s
+//!     })
+//! }
+//!
+//! fn pop() -> StateT<Option<(Stack, i32)>, Stack, i32> {
+//!     StateT::new(|mut s: Stack| s.pop().map(|x| (s, x)))
+//! }
+//!
+//! let program = push(1)
+//!     .flat_map(|_| push(2))
+//!     .flat_map(|_| pop())
+//!     .flat_map(|x| pop().map(move |y| x + y));
+//! assert_eq!(Some((vec![], 3)), program.run(vec![]));
+//!
+//! assert_eq!(None, pop().run(vec![]));
+//! ```
+
+use core::any::Any;
+use core::marker::PhantomData;
+use std::boxed::Box;
+use std::fmt::{Debug, Formatter};
+use std::vec::Vec;
+
+use crate::flatmap::FlatMap;
+use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::monad_trans::MonadTrans;
+use crate::pure::Pure;
+
+/// A function `S -> G<(S, A)>` threading a state `S` through an underlying monad `G`.
+/// See [the module level documentation](self) for more.
+pub struct StateT<F, S, A>(Repr<F, S>, PhantomData<A>);
+
+type Erased = Box<dyn Any>;
+
+/// A chain of `map` and `flat_map` is kept as a list of continuations run in a loop, rather than
+/// as nested closures, so that running a long chain doesn't overflow the stack. The values passed
+/// between the continuations are type-erased, and `unerase` restores the final one.
+enum Repr<F, S> {
+    Run(Box<dyn FnOnce(S) -> F>),
+    Chain {
+        first: Box<dyn FnOnce(S) -> Erased>,
+        conts: Vec<Box<dyn FnOnce(Erased) -> Erased>>,
+        unerase: fn(Erased) -> F,
+    },
+}
+
+#[inline]
+fn unerase<F: 'static>(x: Erased) -> F {
+    match x.downcast() {
+        Ok(x) => *x,
+        Err(_) => unreachable!("StateT continuations are chained with matching types"),
+    }
+}
+
+impl<F: Higher<Param = (S, A)>, S, A> StateT<F, S, A> {
+    /// Create a new StateT from the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::new(|s: i32| Some((s + 1, s * 2)));
+    /// assert_eq!(Some((2, 2)), st.run(1));
+    /// ```
+    #[inline]
+    pub fn new(f: impl FnOnce(S) -> F + 'static) -> Self {
+        StateT(Repr::Run(Box::new(f)), PhantomData)
+    }
+
+    /// Run the computation with the given initial state returning the final state and the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::new(|s: i32| vec![(s, 'a'), (s + 1, 'b')]);
+    /// assert_eq!(vec![(1, 'a'), (2, 'b')], st.run(1));
+    /// ```
+    #[inline]
+    pub fn run(self, initial: S) -> F {
+        match self.0 {
+            Repr::Run(f) => f(initial),
+            Repr::Chain {
+                first,
+                conts,
+                unerase,
+            } => unerase(conts.into_iter().fold(first(initial), |x, k| k(x))),
+        }
+    }
+
+    /// Run the computation with the given initial state returning only the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::new(|s: i32| Some((s + 1, s * 2)));
+    /// assert_eq!(Some(2), st.run_a(1));
+    /// ```
+    #[inline]
+    pub fn run_a(self, initial: S) -> F::Target<A>
+    where
+        F: Functor<A>,
+    {
+        self.run(initial).map(|(_, a)| a)
+    }
+
+    /// Run the computation with the given initial state returning only the final state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::new(|s: i32| Some((s + 1, s * 2)));
+    /// assert_eq!(Some(2), st.run_s(1));
+    /// ```
+    #[inline]
+    pub fn run_s(self, initial: S) -> F::Target<S>
+    where
+        F: Functor<S>,
+    {
+        self.run(initial).map(|(s, _)| s)
+    }
+
+    /// Create a StateT which computes a value from the current state without modifying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::<Option<_>, _, _>::inspect(|s: &String| s.len());
+    /// assert_eq!(Some(("foo".to_string(), 3)), st.run("foo".to_string()));
+    /// ```
+    #[inline]
+    pub fn inspect(f: impl FnOnce(&S) -> A + 'static) -> Self
+    where
+        F: Pure,
+    {
+        StateT::new(move |s| {
+            let a = f(&s);
+            F::pure((s, a))
+        })
+    }
+
+    /// Transform the value with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::new(|s: i32| Some((s, s))).map(|x| x.to_string());
+    /// assert_eq!(Some((1, "1".to_string())), st.run(1));
+    /// ```
+    #[inline]
+    pub fn map<B>(self, mut f: impl FnMut(A) -> B + 'static) -> StateT<F::Target<(S, B)>, S, B>
+    where
+        F: Functor<(S, B)> + 'static,
+        F::Target<(S, B)>: 'static,
+        S: 'static,
+        A: 'static,
+    {
+        self.and_then_erased(move |fa: F| fa.map(|(s, a)| (s, f(a))))
+    }
+
+    /// Sequence the computation with another one depending on the value of the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::new(|s: i32| Some((s + 1, s)))
+    ///     .flat_map(|x| StateT::new(move |s: i32| Some((s * 10, x + s))));
+    /// assert_eq!(Some((20, 3)), st.run(1));
+    /// ```
+    #[inline]
+    pub fn flat_map<B>(
+        self,
+        mut f: impl FnMut(A) -> StateT<F::Target<(S, B)>, S, B> + 'static,
+    ) -> StateT<F::Target<(S, B)>, S, B>
+    where
+        F: FlatMap<(S, B)> + 'static,
+        F::Target<(S, B)>: 'static,
+        S: 'static,
+        A: 'static,
+    {
+        self.and_then_erased(move |fa: F| fa.flat_map(|(s, a)| f(a).run(s)))
+    }
+
+    /// Append the continuation `k` to the chain of continuations run by this StateT.
+    fn and_then_erased<G: 'static, B>(self, k: impl FnOnce(F) -> G + 'static) -> StateT<G, S, B>
+    where
+        F: 'static,
+        S: 'static,
+    {
+        let (first, mut conts) = match self.0 {
+            Repr::Run(f) => {
+                let first: Box<dyn FnOnce(S) -> Erased> = Box::new(move |s| Box::new(f(s)));
+                (first, Vec::new())
+            }
+            Repr::Chain { first, conts, .. } => (first, conts),
+        };
+        conts.push(Box::new(move |x| Box::new(k(unerase::<F>(x)))));
+
+        StateT(
+            Repr::Chain {
+                first,
+                conts,
+                unerase: unerase::<G>,
+            },
+            PhantomData,
+        )
+    }
+}
+
+impl<F: Pure + Higher<Param = (S, S)>, S: Clone> StateT<F, S, S> {
+    /// Create a StateT returning the current state as the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::<Option<_>, _, _>::get();
+    /// assert_eq!(Some((1, 1)), st.run(1));
+    /// ```
+    #[inline]
+    pub fn get() -> Self {
+        StateT::new(|s: S| F::pure((s.clone(), s)))
+    }
+}
+
+impl<F: Pure + Higher<Param = (S, ())>, S: 'static> StateT<F, S, ()> {
+    /// Create a StateT replacing the current state with the given one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::<Option<_>, _, _>::set(2);
+    /// assert_eq!(Some((2, ())), st.run(1));
+    /// ```
+    #[inline]
+    pub fn set(state: S) -> Self {
+        StateT::new(move |_| F::pure((state, ())))
+    }
+
+    /// Create a StateT modifying the current state with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let st = StateT::<Option<_>, _, _>::modify(|s: i32| s + 1);
+    /// assert_eq!(Some((2, ())), st.run(1));
+    /// ```
+    #[inline]
+    pub fn modify(f: impl FnOnce(S) -> S + 'static) -> Self {
+        StateT::new(move |s| F::pure((f(s), ())))
+    }
+}
+
+impl<F, S, A> Debug for StateT<F, S, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StateT")
+    }
+}
+
+impl<F: Higher, S, A> Higher for StateT<F, S, A> {
+    type Param = A;
+    type Target<T> = StateT<F::Target<(S, T)>, S, T>;
+}
+
+impl<F, S, A> Pure for StateT<F, S, A>
+where
+    F: Pure + Higher<Param = (S, A)>,
+    A: 'static,
+{
+    #[inline]
+    fn pure(x: A) -> Self {
+        StateT::new(move |s| F::pure((s, x)))
+    }
+}
+
+impl<F, S, A> MonadTrans for StateT<F, S, A>
+where
+    F: Higher<Param = (S, A)>,
+    F::Target<A>: Functor<(S, A), Target<(S, A)> = F> + 'static,
+    S: Clone,
+{
+    type Inner = F::Target<A>;
+
+    #[inline]
+    fn lift(inner: F::Target<A>) -> Self {
+        StateT::new(move |s: S| inner.map(|a| (s.clone(), a)))
+    }
+}
@@ -0,0 +1,218 @@
+//! Asynchronous computations.
+//!
+//! [`Task<A>`][Task] wraps a boxed [Future] producing a value of type `A`, so that asynchronous
+//! computations can be composed in the same style as the other data types of this crate:
+//! [Task::map] transforms the result, [Task::flat_map] sequences a computation depending on the
+//! result, and [Task::product] and [Task::ap] run two computations concurrently by joining them.
+//! [traverse] and [sequence] join a whole collection of tasks.
+//!
+//! [Task::map] and [Task::flat_map] are [inherent methods](super#inherent-methods) requiring `Send`
+//! and `'static` functions.
+//!
+//! `Task` is itself a [Future], so it can be awaited or run by any executor.
+//!
+//! This module is only available with the `async` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use futures::executor::block_on;
+//! use rust2fun::prelude::*;
+//!
+//! let fetch = |id: u32| Task::new(async move { format!("user{id}") });
+//!
+//! let task = bind! {
+//!     for id in inherent(Task::pure(42));
+//!     for (name, friend) in inherent(fetch(id).product(fetch(id + 1)));
+//!     format!("{name} & {friend}")
+//! };
+//! assert_eq!("user42 & user43", block_on(task));
+//! ```
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::fmt::{Debug, Formatter};
+use std::vec::Vec;
+
+use futures::future::{self, BoxFuture, FutureExt};
+
+use crate::higher;
+use crate::pure::Pure;
+
+/// An asynchronous computation producing a value of type `A`.
+/// See [the module level documentation](self) for more.
+pub struct Task<A>(BoxFuture<'static, A>);
+
+impl<A: Send + 'static> Task<A> {
+    /// Create a new task from the given future.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(42, block_on(Task::new(async { 42 })));
+    /// ```
+    #[inline]
+    pub fn new(future: impl Future<Output = A> + Send + 'static) -> Self {
+        Task(future.boxed())
+    }
+
+    /// Transform the result of the computation with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!("42", block_on(Task::pure(42).map(|x| x.to_string())));
+    /// ```
+    #[inline]
+    pub fn map<B: Send + 'static>(self, f: impl FnOnce(A) -> B + Send + 'static) -> Task<B> {
+        Task(self.0.map(f).boxed())
+    }
+
+    /// Sequence the computation with another one depending on its result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let task = Task::new(async { 20 }).flat_map(|x| Task::new(async move { x + 22 }));
+    /// assert_eq!(42, block_on(task));
+    /// ```
+    #[inline]
+    pub fn flat_map<B: Send + 'static>(
+        self,
+        f: impl FnOnce(A) -> Task<B> + Send + 'static,
+    ) -> Task<B> {
+        Task(self.0.then(f).boxed())
+    }
+
+    /// Run both computations concurrently and combine their results into a tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let task = Task::pure(1).product(Task::new(async { "a" }));
+    /// assert_eq!((1, "a"), block_on(task));
+    /// ```
+    #[inline]
+    pub fn product<B: Send + 'static>(self, fb: Task<B>) -> Task<(A, B)> {
+        Task(future::join(self, fb).boxed())
+    }
+
+    /// Unwrap the underlying boxed future.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let future = Task::pure(42).into_inner();
+    /// assert_eq!(42, block_on(future));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> BoxFuture<'static, A> {
+        self.0
+    }
+}
+
+impl<F: Send + 'static> Task<F> {
+    /// Apply the function produced by `self` to the value produced by `fa`, running both
+    /// computations concurrently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use rust2fun::prelude::*;
+    ///
+    /// let task = Task::pure(|x: i32| x * 2).ap(Task::new(async { 21 }));
+    /// assert_eq!(42, block_on(task));
+    /// ```
+    #[inline]
+    pub fn ap<A, B>(self, fa: Task<A>) -> Task<B>
+    where
+        F: FnOnce(A) -> B,
+        A: Send + 'static,
+        B: Send + 'static,
+    {
+        self.product(fa).map(|(f, a)| f(a))
+    }
+}
+
+impl<A> Future for Task<A> {
+    type Output = A;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<A> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
+impl<A> Debug for Task<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Task")
+    }
+}
+
+higher!(Task);
+
+impl<A: Send + 'static> Pure for Task<A> {
+    #[inline]
+    fn pure(x: A) -> Self {
+        Task(future::ready(x).boxed())
+    }
+}
+
+/// Map each element of the iterator to a task and run them all concurrently, collecting the
+/// results in order.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use rust2fun::data::task;
+/// use rust2fun::prelude::*;
+///
+/// let task = task::traverse(1..=3, |x| Task::new(async move { x * 10 }));
+/// assert_eq!(vec![10, 20, 30], block_on(task));
+/// ```
+pub fn traverse<I, B, F>(iter: I, f: F) -> Task<Vec<B>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Task<B>,
+    B: Send + 'static,
+{
+    sequence(iter.into_iter().map(f))
+}
+
+/// Run all tasks of the iterator concurrently, collecting the results in order.
+///
+/// # Examples
+///
+/// ```
+/// use futures::executor::block_on;
+/// use rust2fun::data::task;
+/// use rust2fun::prelude::*;
+///
+/// let task = task::sequence(vec![Task::pure(1), Task::new(async { 2 })]);
+/// assert_eq!(vec![1, 2], block_on(task));
+/// ```
+pub fn sequence<A, I>(iter: I) -> Task<Vec<A>>
+where
+    I: IntoIterator<Item = Task<A>>,
+    A: Send + 'static,
+{
+    Task(future::join_all(iter).boxed())
+}
@@ -0,0 +1,61 @@
+//! Totally ordered floating point numbers.
+//!
+//! [`Total<T>`][Total] wraps a floating point number and gives it a lawful total order, as
+//! defined by `total_cmp`. In particular, `NaN` values are equal to themselves and ordered after
+//! (or before, for negative `NaN`s) all other values, and `-0.0` is less than `0.0`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let mut xs = vec![Total(2.0), Total(f64::NAN), Total(-1.0)];
+//! xs.sort();
+//! assert_eq!(Total(-1.0), xs[0]);
+//! assert_eq!(Total(2.0), xs[1]);
+//! assert!(xs[2].0.is_nan());
+//! ```
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// A floating point number ordered by `total_cmp`.
+/// See [the module level documentation](self) for more.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Total<T>(pub T);
+
+macro_rules! total_float {
+    ($($t:ty)*) => ($(
+        impl PartialEq for Total<$t> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.0.total_cmp(&other.0) == Ordering::Equal
+            }
+        }
+
+        impl Eq for Total<$t> {}
+
+        impl PartialOrd for Total<$t> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Total<$t> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for Total<$t> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+    )*)
+}
+
+total_float! { f32 f64 }
@@ -17,8 +17,33 @@ impl<T, E> From<Validated<T, E>> for Result<T, E> {
     }
 }
 
+#[cfg(feature = "either")]
+impl<T, E> From<either::Either<E, T>> for Validated<T, E> {
+    #[inline]
+    fn from(either: either::Either<E, T>) -> Self {
+        match either {
+            either::Either::Right(x) => Valid(x),
+            either::Either::Left(x) => Invalid(x),
+        }
+    }
+}
+
+#[cfg(feature = "either")]
+impl<T, E> From<Validated<T, E>> for either::Either<E, T> {
+    #[inline]
+    fn from(validated: Validated<T, E>) -> Self {
+        match validated {
+            Valid(x) => either::Either::Right(x),
+            Invalid(x) => either::Either::Left(x),
+        }
+    }
+}
+
 if_std! {
+    use std::vec::Vec;
+
     use crate::data::NEVec;
+    use crate::semigroup::Semigroup;
 
     impl<T, E> From<Validated<T, E>> for ValidatedNev<T, E> {
         #[inline]
@@ -36,4 +61,111 @@ if_std! {
             }
         }
     }
+
+    impl<T, E> ValidatedNev<T, E> {
+        /// Creates an [`Invalid`] value containing a single error.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust2fun::prelude::*;
+        ///
+        /// let x: ValidatedNev<i32, &str> = Validated::invalid_nev("error");
+        /// assert_eq!(x, Invalid(ne_vec!["error"]));
+        /// ```
+        #[inline]
+        pub fn invalid_nev(e: E) -> Self {
+            Invalid(NEVec::new(e))
+        }
+
+        /// Converts from [`Result<T, E>`] to `ValidatedNev<T, E>`, wrapping the error into a
+        /// [NEVec].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust2fun::prelude::*;
+        ///
+        /// let x = ValidatedNev::from_result_nev("42".parse::<i32>());
+        /// assert_eq!(x, Valid(42));
+        ///
+        /// let x = ValidatedNev::from_result_nev(Err::<i32, _>("error"));
+        /// assert_eq!(x, Invalid(ne_vec!["error"]));
+        /// ```
+        #[inline]
+        pub fn from_result_nev(result: Result<T, E>) -> Self {
+            result.into()
+        }
+    }
+
+    /// Extension trait for converting a [Result] into a [ValidatedNev].
+    pub trait ToValidatedNev<T, E> {
+        /// Converts the value into a [ValidatedNev], wrapping the error into a [NEVec].
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust2fun::prelude::*;
+        ///
+        /// fn positive(x: i32) -> Result<i32, String> {
+        ///     if x > 0 { Ok(x) } else { Err(format!("{} is not positive", x)) }
+        /// }
+        ///
+        /// let actual = positive(1).to_validated_nev().map2(positive(-2).to_validated_nev(), |a, b| a + b);
+        /// assert_eq!(actual, Invalid(ne_vec!["-2 is not positive".to_string()]));
+        /// ```
+        fn to_validated_nev(self) -> ValidatedNev<T, E>;
+    }
+
+    impl<T, E> ToValidatedNev<T, E> for Result<T, E> {
+        #[inline]
+        fn to_validated_nev(self) -> ValidatedNev<T, E> {
+            self.into()
+        }
+    }
+
+    /// Collects an iterator of [Validated] values into a [Validated] collection, accumulating
+    /// all the errors with their [Semigroup] instance instead of stopping at the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use rust2fun::prelude::*;
+    ///
+    /// fn positive(x: i32) -> ValidatedNev<i32, String> {
+    ///     if x > 0 { Valid(x) } else { Invalid(ne_vec![format!("{x} is not positive")]) }
+    /// }
+    ///
+    /// let actual: ValidatedNev<Vec<_>, _> = vec![1, 2, 3].into_iter().map(positive).collect();
+    /// assert_eq!(Valid(vec![1, 2, 3]), actual);
+    ///
+    /// let actual: ValidatedNev<NEVec<_>, _> = vec![1, 2, 2].into_iter().map(positive).collect();
+    /// assert_eq!(Valid(ne_vec![1, 2, 2]), actual);
+    ///
+    /// let actual: ValidatedNev<HashSet<_>, _> = vec![1, -2, -3].into_iter().map(positive).collect();
+    /// assert_eq!(
+    ///     Invalid(ne_vec!["-2 is not positive".to_string(), "-3 is not positive".to_string()]),
+    ///     actual
+    /// );
+    /// ```
+    impl<T, E: Semigroup, C: FromIterator<T>> FromIterator<Validated<T, E>> for Validated<C, E> {
+        fn from_iter<I: IntoIterator<Item = Validated<T, E>>>(iter: I) -> Self {
+            let mut valid = Vec::new();
+            let mut invalid: Option<E> = None;
+            for x in iter {
+                match (x, invalid.take()) {
+                    (Valid(x), None) => valid.push(x),
+                    (Valid(_), e @ Some(_)) => invalid = e,
+                    (Invalid(e), None) => invalid = Some(e),
+                    (Invalid(e), Some(acc)) => invalid = Some(acc.combine(e)),
+                }
+            }
+
+            match invalid {
+                None => Valid(valid.into_iter().collect()),
+                Some(e) => Invalid(e),
+            }
+        }
+    }
 }
@@ -75,11 +75,48 @@
 //!                CreditCard::new)
 //! }
 //! ```
+//!
+//! The same constructor can be derived with [`Validate`]:
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum Error {
+//!     InvalidNumber,
+//!     Expired,
+//! }
+//!
+//! fn validate_number(number: String) -> ValidatedNev<String, Error> {
+//!     if number.len() == 16 { Valid(number) } else { ValidatedNev::invalid_nev(Error::InvalidNumber) }
+//! }
+//!
+//! fn validate_expiration(year: u16) -> ValidatedNev<u16, Error> {
+//!     if year >= 2024 { Valid(year) } else { ValidatedNev::invalid_nev(Error::Expired) }
+//! }
+//!
+//! #[derive(Debug, PartialEq, Validate)]
+//! #[validate(error = Error)]
+//! struct CreditCard {
+//!     #[validate(with = validate_number)]
+//!     number: String,
+//!     #[validate(with = validate_expiration)]
+//!     expiration: u16,
+//!     cvv: u16,
+//! }
+//!
+//! let card = CreditCard::validate("1234567812345678".to_string(), 2030, 123);
+//! assert!(card.is_valid());
+//!
+//! let card = CreditCard::validate("1234".to_string(), 2000, 123);
+//! assert_eq!(Invalid(ne_vec![Error::InvalidNumber, Error::Expired]), card);
+//! ```
 pub use Validated::{Invalid, Valid};
 
 use crate::and_then::AndThen;
 use crate::apply::Apply;
 use crate::bifunctor::Bifunctor;
+use crate::data::ior::Ior;
 use crate::functor::Functor;
 use crate::higher::{Higher, Higher2};
 use crate::invariant_functor;
@@ -88,15 +125,32 @@ use crate::semigroup::Semigroup;
 use crate::semigroupal::Semigroupal;
 
 mod from;
+#[cfg(feature = "std")]
+mod partition;
+#[cfg(feature = "try_trait")]
+mod try_trait;
+
+#[cfg(feature = "std")]
+pub use from::ToValidatedNev;
+#[cfg(feature = "std")]
+pub use partition::{partition_map, partition_validated};
+#[cfg(feature = "std")]
+pub use rust2fun_macros::Validate;
 
 /// Type alias for a [`Validated`] value accumulating errors in a non-empty vector.
 #[cfg(feature = "std")]
 pub type ValidatedNev<T, E> = Validated<T, super::NEVec<E>>;
 
+/// Type alias for a [`Validated`] value accumulating errors in a non-empty chain, which combines
+/// errors in constant time.
+#[cfg(feature = "std")]
+pub type ValidatedNec<T, E> = Validated<T, super::NEChain<E>>;
+
 /// `Validated` is a type that represents either a [`Valid`] value or an error([`Invalid`]).
 ///
 /// See the [module-level documentation](self) for more details.
 #[derive(Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Validated<T, E> {
     /// Contains a valid value.
     Valid(T),
@@ -105,6 +159,54 @@ pub enum Validated<T, E> {
 }
 
 impl<T, E> Validated<T, E> {
+    /// Returns [`Valid`] with the `valid` value if `test` is `true`, otherwise returns
+    /// [`Invalid`] with the `invalid` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let age = 42;
+    /// assert_eq!(Validated::cond(age >= 18, age, "too young"), Valid(42));
+    ///
+    /// let age = 7;
+    /// assert_eq!(Validated::cond(age >= 18, age, "too young"), Invalid("too young"));
+    /// ```
+    #[inline]
+    pub fn cond(test: bool, valid: T, invalid: E) -> Self {
+        if test {
+            Valid(valid)
+        } else {
+            Invalid(invalid)
+        }
+    }
+
+    /// Returns [`Valid`] with the given value if it satisfies the predicate, otherwise returns
+    /// [`Invalid`] with the error computed from the value by `to_error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let positive = |x| Validated::from_predicate(x, |x| *x > 0, |x| format!("{} is not positive", x));
+    /// assert_eq!(positive(1), Valid(1));
+    /// assert_eq!(positive(-1), Invalid("-1 is not positive".to_string()));
+    /// ```
+    #[inline]
+    pub fn from_predicate(
+        value: T,
+        pred: impl FnOnce(&T) -> bool,
+        to_error: impl FnOnce(T) -> E,
+    ) -> Self {
+        if pred(&value) {
+            Valid(value)
+        } else {
+            Invalid(to_error(value))
+        }
+    }
+
     /// Returns `true` if the `Validated` is [`Valid`].
     ///
     /// # Examples
@@ -187,6 +289,27 @@ impl<T, E> Validated<T, E> {
         }
     }
 
+    /// Swaps the channels: a [`Valid`] value becomes [`Invalid`] and vice versa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, &str> = Valid(1);
+    /// assert_eq!(x.swap(), Invalid(1));
+    ///
+    /// let x: Validated<i32, &str> = Invalid("error");
+    /// assert_eq!(x.swap(), Valid("error"));
+    /// ```
+    #[inline]
+    pub fn swap(self) -> Validated<E, T> {
+        match self {
+            Valid(x) => Invalid(x),
+            Invalid(x) => Valid(x),
+        }
+    }
+
     /// Converts from `Validated<T, E>` to [`Result<T, E>`].
     ///
     /// # Examples
@@ -208,6 +331,69 @@ impl<T, E> Validated<T, E> {
         }
     }
 
+    /// Converts from `Validated<T, E>` to [`Ior<E, T>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, &str> = Valid(1);
+    /// assert_eq!(x.into_ior(), Ior::Right(1));
+    ///
+    /// let x: Validated<i32, &str> = Invalid("error");
+    /// assert_eq!(x.into_ior(), Ior::Left("error"));
+    /// ```
+    #[inline]
+    pub fn into_ior(self) -> Ior<E, T> {
+        self.into()
+    }
+
+    /// Creates a `Validated<T, E>` from an [`Ior<E, T>`], discarding the warnings of
+    /// [`Both`](Ior::Both).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, &str> = Validated::from_ior(Ior::Both("warning", 1));
+    /// assert_eq!(x, Valid(1));
+    ///
+    /// let x: Validated<i32, &str> = Validated::from_ior(Ior::Left("error"));
+    /// assert_eq!(x, Invalid("error"));
+    /// ```
+    #[inline]
+    pub fn from_ior(ior: Ior<E, T>) -> Self {
+        ior.into_validated()
+    }
+
+    /// Attaches a non-fatal warning to the `Validated`, producing an [`Ior<E, T>`].
+    /// A valid value is kept alongside the warning, while an invalid one accumulates the
+    /// warning with its errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, Vec<&str>> = Valid(1);
+    /// assert_eq!(x.with_warning(vec!["deprecated"]), Ior::Both(vec!["deprecated"], 1));
+    ///
+    /// let x: Validated<i32, Vec<&str>> = Invalid(vec!["error"]);
+    /// assert_eq!(x.with_warning(vec!["deprecated"]), Ior::Left(vec!["error", "deprecated"]));
+    /// ```
+    #[inline]
+    pub fn with_warning(self, warning: E) -> Ior<E, T>
+    where
+        E: Semigroup,
+    {
+        match self {
+            Valid(x) => Ior::Both(warning, x),
+            Invalid(e) => Ior::Left(e.combine(warning)),
+        }
+    }
+
     /// Converts from `&Validated<T, E>` to `Validated<&T, &E>`.
     ///
     /// Produces a new `Validated`, containing a reference
@@ -262,6 +448,64 @@ impl<T, E> Validated<T, E> {
         }
     }
 
+    /// Returns an iterator over the possibly contained [`Valid`] value.
+    ///
+    /// The iterator yields one value if the validated is [`Valid`], otherwise none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, &str> = Valid(1);
+    /// assert_eq!(x.iter().next(), Some(&1));
+    ///
+    /// let x: Validated<i32, &str> = Invalid("error");
+    /// assert_eq!(x.iter().next(), None);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> core::option::IntoIter<&T> {
+        self.as_ref().valid().into_iter()
+    }
+
+    /// Returns a mutable iterator over the possibly contained [`Valid`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut x: Validated<i32, &str> = Valid(1);
+    /// if let Some(v) = x.iter_mut().next() {
+    ///     *v = 2;
+    /// }
+    /// assert_eq!(x, Valid(2));
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::option::IntoIter<&mut T> {
+        self.as_mut().valid().into_iter()
+    }
+
+    /// Returns an iterator over the possibly contained [`Invalid`] error.
+    ///
+    /// The iterator yields one value if the validated is [`Invalid`], otherwise none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, &str> = Valid(1);
+    /// assert_eq!(x.iter_err().next(), None);
+    ///
+    /// let x: Validated<i32, &str> = Invalid("error");
+    /// assert_eq!(x.iter_err().next(), Some(&"error"));
+    /// ```
+    #[inline]
+    pub fn iter_err(&self) -> core::option::IntoIter<&E> {
+        self.as_ref().invalid().into_iter()
+    }
+
     /// Maps a `Validated<T, E>` to `Validated<U, E>` by applying a function to a
     /// contained [`Valid`] value, leaving an [`Invalid`] value untouched.
     ///
@@ -724,6 +968,36 @@ impl<T, E> Validated<T, E> {
         }
     }
 
+    /// Returns `other` if the result is [`Invalid`], otherwise returns the [`Valid`]
+    /// value of `self`. Unlike [`or`], when both values are [`Invalid`] their errors are
+    /// combined instead of discarding the errors of `self`.
+    ///
+    /// [`or`]: Validated::or
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, Vec<&str>> = Invalid(vec!["early error"]);
+    /// let y: Validated<i32, Vec<&str>> = Valid(2);
+    /// assert_eq!(x.or_else_accumulate(y), Valid(2));
+    ///
+    /// let x: Validated<i32, Vec<&str>> = Invalid(vec!["not a 2"]);
+    /// let y: Validated<i32, Vec<&str>> = Invalid(vec!["late error"]);
+    /// assert_eq!(x.or_else_accumulate(y), Invalid(vec!["not a 2", "late error"]));
+    /// ```
+    #[inline]
+    pub fn or_else_accumulate(self, other: Self) -> Self
+    where
+        E: Semigroup,
+    {
+        match (self, other) {
+            (Valid(x), _) | (Invalid(_), Valid(x)) => Valid(x),
+            (Invalid(e1), Invalid(e2)) => Invalid(e1.combine(e2)),
+        }
+    }
+
     /// Returns the contained [`Valid`] value or a provided default.
     ///
     /// Arguments passed to `unwrap_or` are eagerly evaluated; if you are passing the
@@ -800,6 +1074,39 @@ where
     }
 }
 
+impl<T, E> IntoIterator for Validated<T, E> {
+    type Item = T;
+    type IntoIter = core::option::IntoIter<T>;
+
+    /// Returns a consuming iterator over the possibly contained [`Valid`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Validated<i32, &str> = Valid(1);
+    /// assert_eq!(vec![1], x.into_iter().collect::<Vec<_>>());
+    ///
+    /// let x: Validated<i32, &str> = Invalid("error");
+    /// assert_eq!(x.into_iter().next(), None);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.valid().into_iter()
+    }
+}
+
+impl<'a, T, E> IntoIterator for &'a Validated<T, E> {
+    type Item = &'a T;
+    type IntoIter = core::option::IntoIter<&'a T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl<P, E> Higher for Validated<P, E> {
     type Param = P;
     type Target<T> = Validated<T, E>;
@@ -882,3 +1189,55 @@ impl<A, B, E> AndThen<B> for Validated<A, E> {
         self.and_then(f)
     }
 }
+
+/// Construct a struct from fields given as [Validated] values, accumulating the errors of all
+/// the invalid fields. There is no limit on the number of fields.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// let valid_age = |age: i32| u8::try_from(age).map_err(|_| format!("invalid age {age}"));
+///
+/// let actual: Validated<User, String> = validated! {
+///     User {
+///         name: Valid("Alice".to_string()),
+///         age: valid_age(42).into(),
+///     }
+/// };
+/// assert_eq!(Valid(User { name: "Alice".to_string(), age: 42 }), actual);
+///
+/// let actual: Validated<User, String> = validated! {
+///     User {
+///         name: Invalid("empty name; ".to_string()),
+///         age: valid_age(-1).into(),
+///     }
+/// };
+/// assert_eq!(Invalid("empty name; invalid age -1".to_string()), actual);
+/// ```
+#[macro_export]
+macro_rules! validated {
+    (@product ($($s:tt)*); $acc:expr; ($($pat:tt)*); ($($fields:ident)*);) => (
+        $crate::functor::Functor::map($acc, |$($pat)*| $($s)* { $($fields),* })
+    );
+    (@product ($($s:tt)*); $acc:expr; ($($pat:tt)*); ($($fields:ident)*);
+        $field:ident : $e:expr $(, $rest_field:ident : $rest:expr)*) => (
+        $crate::validated!(
+            @product ($($s)*);
+            $crate::semigroupal::Semigroupal::product($acc, $e);
+            (($($pat)*, $field));
+            ($($fields)* $field);
+            $($rest_field : $rest),*
+        )
+    );
+    ($($s:ident)::+ { $field:ident : $e:expr $(, $rest_field:ident : $rest:expr)* $(,)? }) => (
+        $crate::validated!(@product ($($s)::+); $e; ($field); ($field); $($rest_field : $rest),*)
+    );
+}
@@ -0,0 +1,53 @@
+use std::vec::Vec;
+
+use super::*;
+
+/// Splits an iterator of [Validated] values into the errors of the [`Invalid`] values and the
+/// values of the [`Valid`] ones, preserving their order.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let xs = vec![Valid(1), Invalid("error"), Valid(2)];
+/// assert_eq!((vec!["error"], vec![1, 2]), partition_validated(xs));
+/// ```
+pub fn partition_validated<T, E>(
+    iter: impl IntoIterator<Item = Validated<T, E>>,
+) -> (Vec<E>, Vec<T>) {
+    partition_map(iter, |x| x)
+}
+
+/// Validates each element of an iterator with `f` and splits the results into the errors of the
+/// [`Invalid`] values and the values of the [`Valid`] ones in a single pass, preserving their
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// fn parse(s: &str) -> Validated<i32, String> {
+///     s.parse().map_err(|_| s.to_string()).into()
+/// }
+///
+/// let (invalid, valid) = partition_map(vec!["1", "foo", "2", "bar"], parse);
+///
+/// assert_eq!(vec!["foo", "bar"], invalid);
+/// assert_eq!(vec![1, 2], valid);
+/// ```
+pub fn partition_map<A, T, E>(
+    iter: impl IntoIterator<Item = A>,
+    mut f: impl FnMut(A) -> Validated<T, E>,
+) -> (Vec<E>, Vec<T>) {
+    let mut invalid = Vec::new();
+    let mut valid = Vec::new();
+    for a in iter {
+        match f(a) {
+            Valid(x) => valid.push(x),
+            Invalid(e) => invalid.push(e),
+        }
+    }
+    (invalid, valid)
+}
@@ -0,0 +1,72 @@
+//! Support for the `?` operator, which short-circuits on the first [`Invalid`] value.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn parse(s: &str) -> Validated<i32, String> {
+//!     s.parse().map_err(|_| format!("{} is not a number", s)).into()
+//! }
+//!
+//! fn sum(x: &str, y: &str) -> Validated<i32, String> {
+//!     Valid(parse(x)? + parse(y)?)
+//! }
+//!
+//! assert_eq!(sum("1", "2"), Valid(3));
+//! assert_eq!(sum("1", "foo"), Invalid("foo is not a number".to_string()));
+//! ```
+
+use core::convert::Infallible;
+use core::ops::{ControlFlow, FromResidual, Residual, Try};
+
+use super::*;
+
+impl<T, E> Try for Validated<T, E> {
+    type Output = T;
+    type Residual = Validated<Infallible, E>;
+
+    #[inline]
+    fn from_output(output: T) -> Self {
+        Valid(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, T> {
+        match self {
+            Valid(x) => ControlFlow::Continue(x),
+            Invalid(e) => ControlFlow::Break(Invalid(e)),
+        }
+    }
+}
+
+impl<T, E> Residual<T> for Validated<Infallible, E> {
+    type TryType = Validated<T, E>;
+}
+
+impl<T, E, F: From<E>> FromResidual<Validated<Infallible, E>> for Validated<T, F> {
+    #[inline]
+    fn from_residual(residual: Validated<Infallible, E>) -> Self {
+        match residual {
+            Invalid(e) => Invalid(From::from(e)),
+        }
+    }
+}
+
+impl<T, E, F: From<E>> FromResidual<Result<Infallible, E>> for Validated<T, F> {
+    #[inline]
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Err(e) => Invalid(From::from(e)),
+        }
+    }
+}
+
+impl<T, E, F: From<E>> FromResidual<Validated<Infallible, E>> for Result<T, F> {
+    #[inline]
+    fn from_residual(residual: Validated<Infallible, E>) -> Self {
+        match residual {
+            Invalid(e) => Err(From::from(e)),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Writer monad transformer.
+//!
+//! [`WriterT<F, W, A>`][WriterT] wraps a value `F = G<(W, A)>` of an underlying monad `G`,
+//! pairing each value `A` with a log `W`. Sequencing computations with [FlatMap::flat_map]
+//! combines their logs with the [Semigroup] instance of `W`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn half(x: i32) -> WriterT<Option<(Vec<String>, i32)>, Vec<String>, i32> {
+//!     if x % 2 == 0 {
+//!         WriterT::new(Some((vec![format!("halved {}", x)], x / 2)))
+//!     } else {
+//!         WriterT::new(None)
+//!     }
+//! }
+//!
+//! let actual = bind! {
+//!     for x in half(8);
+//!     for y in half(x);
+//!     for z in lift::<WriterT<Option<_>, _, _>>(Some(y + 1));
+//!     for _ in WriterT::<Option<_>, _, _>::tell(vec!["done".to_string()]);
+//!     z
+//! };
+//! assert_eq!(
+//!     Some((vec!["halved 8".to_string(), "halved 4".to_string(), "done".to_string()], 3)),
+//!     actual.run()
+//! );
+//!
+//! assert_eq!(None, half(6).flat_map(half).run());
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::and_then::AndThen;
+use crate::flatmap::FlatMap;
+use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::invariant::Invariant;
+use crate::monad_trans::MonadTrans;
+use crate::monoid::Monoid;
+use crate::pure::Pure;
+use crate::semigroup::Semigroup;
+
+/// A value `F = G<(W, A)>` of an underlying monad `G` with a log `W` attached to each value `A`.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct WriterT<F, W, A> {
+    run: F,
+    _marker: PhantomData<(W, A)>,
+}
+
+impl<F: Higher<Param = (W, A)>, W, A> WriterT<F, W, A> {
+    /// Create a new WriterT from the given value of the underlying monad.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let w = WriterT::new(vec![("a", 1), ("b", 2)]);
+    /// assert_eq!(vec![("a", 1), ("b", 2)], w.run());
+    /// ```
+    #[inline]
+    pub fn new(run: F) -> Self {
+        WriterT {
+            run,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Unwrap the value of the underlying monad.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let w = WriterT::new(Some(("log", 1)));
+    /// assert_eq!(Some(("log", 1)), w.run());
+    /// ```
+    #[inline]
+    pub fn run(self) -> F {
+        self.run
+    }
+
+    /// Discard the log and return the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let w = WriterT::new(vec![("a", 1), ("b", 2)]);
+    /// assert_eq!(vec![1, 2], w.value());
+    /// ```
+    #[inline]
+    pub fn value(self) -> F::Target<A>
+    where
+        F: Functor<A>,
+    {
+        self.run.map(|(_, a)| a)
+    }
+
+    /// Discard the values and return the log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let w = WriterT::new(vec![("a", 1), ("b", 2)]);
+    /// assert_eq!(vec!["a", "b"], w.written());
+    /// ```
+    #[inline]
+    pub fn written(self) -> F::Target<W>
+    where
+        F: Functor<W>,
+    {
+        self.run.map(|(w, _)| w)
+    }
+}
+
+impl<F: Pure + Higher<Param = (W, ())>, W> WriterT<F, W, ()> {
+    /// Create a WriterT which only writes the given log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let w = WriterT::<Option<_>, _, _>::tell("log");
+    /// assert_eq!(Some(("log", ())), w.run());
+    /// ```
+    #[inline]
+    pub fn tell(w: W) -> Self {
+        WriterT::new(F::pure((w, ())))
+    }
+}
+
+impl<F: Higher, W, A> Higher for WriterT<F, W, A> {
+    type Param = A;
+    type Target<T> = WriterT<F::Target<(W, T)>, W, T>;
+}
+
+impl<F, W, A, B> Invariant<B> for WriterT<F, W, A>
+where
+    F: Functor<(W, B), Param = (W, A)>,
+{
+    #[inline]
+    fn imap<FF, G>(self, f: FF, _g: G) -> WriterT<F::Target<(W, B)>, W, B>
+    where
+        FF: FnMut(A) -> B,
+        G: FnMut(B) -> A,
+    {
+        self.map(f)
+    }
+}
+
+impl<F, W, A, B> Functor<B> for WriterT<F, W, A>
+where
+    F: Functor<(W, B), Param = (W, A)>,
+{
+    #[inline]
+    fn map(self, mut f: impl FnMut(A) -> B) -> WriterT<F::Target<(W, B)>, W, B> {
+        WriterT::new(self.run.map(|(w, a)| (w, f(a))))
+    }
+}
+
+impl<F, W: Monoid, A> Pure for WriterT<F, W, A>
+where
+    F: Pure + Higher<Param = (W, A)>,
+{
+    #[inline]
+    fn pure(x: A) -> Self {
+        WriterT::new(F::pure((W::empty(), x)))
+    }
+}
+
+impl<F, W, A, B> FlatMap<B> for WriterT<F, W, A>
+where
+    F: FlatMap<(W, B), Param = (W, A)>,
+    F::Target<(W, B)>: Functor<(W, B), Target<(W, B)> = F::Target<(W, B)>>,
+    W: Semigroup + Clone,
+{
+    #[inline]
+    fn flat_map<G>(self, mut f: G) -> WriterT<F::Target<(W, B)>, W, B>
+    where
+        G: FnMut(A) -> WriterT<F::Target<(W, B)>, W, B>,
+    {
+        WriterT::new(
+            self.run
+                .flat_map(|(w1, a)| f(a).run.map(|(w2, b)| (w1.clone().combine(w2), b))),
+        )
+    }
+}
+
+impl<F, W, A, B> AndThen<B> for WriterT<F, W, A>
+where
+    F: FlatMap<(W, B), Param = (W, A)>,
+    F::Target<(W, B)>: Functor<(W, B), Target<(W, B)> = F::Target<(W, B)>>,
+    W: Semigroup + Clone,
+{
+    #[inline]
+    fn and_then<G>(self, f: G) -> WriterT<F::Target<(W, B)>, W, B>
+    where
+        G: FnMut(A) -> WriterT<F::Target<(W, B)>, W, B>,
+    {
+        self.flat_map(f)
+    }
+}
+
+impl<F, W: Monoid, A> MonadTrans for WriterT<F, W, A>
+where
+    F: Higher<Param = (W, A)>,
+    F::Target<A>: Functor<(W, A), Target<(W, A)> = F>,
+{
+    type Inner = F::Target<A>;
+
+    #[inline]
+    fn lift(inner: F::Target<A>) -> Self {
+        WriterT::new(inner.map(|a| (W::empty(), a)))
+    }
+}
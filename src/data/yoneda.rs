@@ -0,0 +1,118 @@
+//! Yoneda.
+//!
+//! [`Yoneda<FA>`][Yoneda] views a functor value `F<A>` as the function `(A -> B) -> F<B>` which
+//! maps the value with the given function. By the Yoneda lemma both representations are
+//! isomorphic: [Yoneda::lift] and [Yoneda::lower] convert between them.
+//!
+//! Without rank-2 types Rust can't store the polymorphic function itself, so `Yoneda` keeps the
+//! underlying value and requires it to be a [Functor], unlike [Coyoneda] which works for any
+//! [Higher] type. Use [Coyoneda] to fuse a chain of maps into a single pass.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let y = Yoneda::lift(vec![1, 2, 3]);
+//! assert_eq!(vec!["1", "2", "3"], y.run(|x| x.to_string()));
+//! ```
+//!
+//! [Coyoneda]: crate::data::coyoneda::Coyoneda
+
+use crate::data::coyoneda::Coyoneda;
+use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::invariant::Invariant;
+
+/// A functor value `F<A>` viewed as the function `(A -> B) -> F<B>`.
+/// See [the module level documentation](self) for more.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Yoneda<FA>(FA);
+
+impl<FA: Higher> Yoneda<FA> {
+    /// Lift a functor value into Yoneda.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let y = Yoneda::lift(Some(1));
+    /// assert_eq!(Some(1), y.lower());
+    /// ```
+    #[inline]
+    pub fn lift(fa: FA) -> Self {
+        Yoneda(fa)
+    }
+
+    /// Lower Yoneda back to the underlying functor value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let y = Yoneda::lift(Some(1)).map(|x| x + 1);
+    /// assert_eq!(Some(2), y.lower());
+    /// ```
+    #[inline]
+    pub fn lower(self) -> FA {
+        self.0
+    }
+
+    /// Run the function `(A -> B) -> F<B>` represented by Yoneda.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let y = Yoneda::lift(Some(2));
+    /// assert_eq!(Some(4), y.run(|x| x * 2));
+    /// ```
+    #[inline]
+    pub fn run<B>(self, f: impl FnMut(FA::Param) -> B) -> FA::Target<B>
+    where
+        FA: Functor<B>,
+    {
+        self.0.map(f)
+    }
+
+    /// Convert Yoneda into [Coyoneda] with the identity function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let c = Yoneda::lift(vec![1, 2]).into_coyoneda().map(|x| x + 1);
+    /// assert_eq!(vec![2, 3], c.lower());
+    /// ```
+    #[inline]
+    pub fn into_coyoneda(self) -> Coyoneda<FA, FA::Param> {
+        Coyoneda::lift(self.0)
+    }
+}
+
+impl<FA: Higher> Higher for Yoneda<FA> {
+    type Param = FA::Param;
+    type Target<T> = Yoneda<FA::Target<T>>;
+}
+
+impl<FA: Functor<B>, B> Invariant<B> for Yoneda<FA> {
+    #[inline]
+    fn imap<F, G>(self, f: F, _g: G) -> Yoneda<FA::Target<B>>
+    where
+        F: FnMut(FA::Param) -> B,
+        G: FnMut(B) -> FA::Param,
+    {
+        self.map(f)
+    }
+}
+
+impl<FA: Functor<B>, B> Functor<B> for Yoneda<FA> {
+    #[inline]
+    fn map(self, f: impl FnMut(FA::Param) -> B) -> Yoneda<FA::Target<B>> {
+        Yoneda(self.0.map(f))
+    }
+}
@@ -0,0 +1,62 @@
+//! Decidable.
+//!
+//! A [Divisible] which can also consume a sum type by dispatching each alternative to a different
+//! consumer. It is the contravariant dual of choosing between alternatives.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let even = Predicate::new(|x: i32| x % 2 == 0);
+//! let non_empty = Predicate::new(|s: String| !s.is_empty());
+//! let mut p = even.choose(non_empty, |s: &str| s.parse::<i32>().map_err(|_| s.to_string()));
+//!
+//! assert!(p.test("42"));
+//! assert!(!p.test("1"));
+//! assert!(p.test("foo"));
+//! assert!(!p.test(""));
+//! ```
+//!
+//! [Divisible]: crate::divisible::Divisible
+
+use core::marker::PhantomData;
+
+use crate::divisible::Divisible;
+
+/// Contravariant functor able to decide between alternatives.
+/// See [the module level documentation](self) for more.
+pub trait Decidable<B, C>: Divisible<B, C> {
+    /// Combine two contravariant values into one by mapping the input into a [Result]. `Ok`
+    /// values are consumed by `self` and `Err` values are consumed by `fb`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let numbers = Comparator::natural();
+    /// let words = Comparator::natural();
+    /// let mut cmp = numbers.choose(words, |s: &str| s.parse::<i32>().map_err(|_| s));
+    ///
+    /// let mut xs = vec!["b", "10", "a", "9"];
+    /// xs.sort_by(|x, y| cmp.compare(*x, *y));
+    /// assert_eq!(vec!["9", "10", "a", "b"], xs);
+    /// ```
+    fn choose(
+        self,
+        fb: Self::Target<B>,
+        f: impl FnMut(C) -> Result<Self::Param, B> + 'static,
+    ) -> Self::Target<C>;
+}
+
+impl<A, B, C> Decidable<B, C> for PhantomData<A> {
+    #[inline]
+    fn choose(
+        self,
+        _fb: PhantomData<B>,
+        _f: impl FnMut(C) -> Result<A, B> + 'static,
+    ) -> PhantomData<C> {
+        PhantomData
+    }
+}
@@ -0,0 +1,64 @@
+//! Divide.
+//!
+//! The contravariant dual of [Semigroupal]: given a way to consume an `A` and a way to consume a
+//! `B`, a value of type `C` can be consumed by first splitting it into an `A` and a `B`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! struct User {
+//!     name: String,
+//!     age: u32,
+//! }
+//!
+//! let non_empty = Predicate::new(|s: String| !s.is_empty());
+//! let adult = Predicate::new(|x: u32| x >= 18);
+//! let mut valid_user = non_empty.divide(adult, |u: User| (u.name, u.age));
+//!
+//! assert!(valid_user.test(User { name: "Alice".to_string(), age: 42 }));
+//! assert!(!valid_user.test(User { name: "".to_string(), age: 42 }));
+//! assert!(!valid_user.test(User { name: "Bob".to_string(), age: 7 }));
+//! ```
+//!
+//! [Semigroupal]: crate::semigroupal::Semigroupal
+
+use core::marker::PhantomData;
+
+use crate::higher::Higher;
+
+/// Contravariant semigroupal functor. Unlike [Contravariant] it requires `'static` functions,
+/// so that it can be implemented by types wrapping boxed functions.
+/// See [the module level documentation](self) for more.
+///
+/// [Contravariant]: crate::contravariant::Contravariant
+pub trait Divide<B, C>: Higher {
+    /// Combine two contravariant values into one by splitting the input with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let by_len = Comparator::new(|x: usize, y: usize| x.cmp(&y));
+    /// let by_value = Comparator::natural();
+    /// let mut cmp = by_len.divide(by_value, |s: &str| (s.len(), s));
+    ///
+    /// let mut words = vec!["bb", "a", "ab", "c"];
+    /// words.sort_by(|x, y| cmp.compare(*x, *y));
+    /// assert_eq!(vec!["a", "c", "ab", "bb"], words);
+    /// ```
+    fn divide(
+        self,
+        fb: Self::Target<B>,
+        f: impl FnMut(C) -> (Self::Param, B) + 'static,
+    ) -> Self::Target<C>;
+}
+
+impl<A, B, C> Divide<B, C> for PhantomData<A> {
+    #[inline]
+    fn divide(self, _fb: PhantomData<B>, _f: impl FnMut(C) -> (A, B) + 'static) -> PhantomData<C> {
+        PhantomData
+    }
+}
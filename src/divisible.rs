@@ -0,0 +1,41 @@
+//! Divisible.
+//!
+//! The contravariant dual of [Applicative]: a [Divide] with an empty value [Conquer::conquer]
+//! which consumes any input without any effect, being the identity for [Divide::divide].
+//!
+//! [Applicative]: crate::applicative::Applicative
+
+use core::marker::PhantomData;
+
+use crate::divide::Divide;
+use crate::higher::Higher;
+
+/// Typeclass for creating a contravariant value that ignores its input.
+pub trait Conquer: Higher {
+    /// Create a value that consumes any input without any effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut always = Predicate::conquer();
+    /// assert!(always.test(42));
+    ///
+    /// let mut cmp = Comparator::conquer();
+    /// assert_eq!(std::cmp::Ordering::Equal, cmp.compare(1, 2));
+    /// ```
+    fn conquer() -> Self;
+}
+
+/// Contravariant monoidal functor. This is a [Divide] with [Conquer].
+pub trait Divisible<B, C>: Divide<B, C> + Conquer {}
+
+impl<T, B, C> Divisible<B, C> for T where T: Divide<B, C> + Conquer {}
+
+impl<A> Conquer for PhantomData<A> {
+    #[inline]
+    fn conquer() -> Self {
+        PhantomData
+    }
+}
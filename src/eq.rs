@@ -0,0 +1,55 @@
+//! Eq.
+//!
+//! A typeclass for equivalence, independent of [PartialEq] semantics. It is implemented for every
+//! type implementing [`core::cmp::Eq`].
+//!
+//! Note that the trait isn't exported by name from the prelude to avoid shadowing
+//! [`core::cmp::Eq`], only its methods are brought into scope. Use `rust2fun::eq::Eq` to refer
+//! to it.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! assert!(1.eqv(&1));
+//! assert!("a".neqv(&"b"));
+//! ```
+
+/// Typeclass for types with a lawful equivalence relation.
+/// See [the module level documentation](self) for more.
+pub trait Eq {
+    /// Returns `true` if `self` and `other` are equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(Some(1).eqv(&Some(1)));
+    /// assert!(!Some(1).eqv(&None));
+    /// ```
+    fn eqv(&self, other: &Self) -> bool;
+
+    /// Returns `true` if `self` and `other` are not equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(Some(1).neqv(&None));
+    /// assert!(!Some(1).neqv(&Some(1)));
+    /// ```
+    #[inline]
+    fn neqv(&self, other: &Self) -> bool {
+        !self.eqv(other)
+    }
+}
+
+impl<T: core::cmp::Eq + ?Sized> Eq for T {
+    #[inline]
+    fn eqv(&self, other: &Self) -> bool {
+        self == other
+    }
+}
@@ -1,11 +1,19 @@
 //! FlatMap.
 
 use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 use crate::combinator::id;
 use crate::constant1;
 use crate::functor::Functor;
 use crate::higher::Higher;
+use crate::semigroup::Semigroup;
 
 /// Gives access to the `flat_map` method. The motivation for separating this out of
 /// [Monad](super::monad::Monad) is that there are situations where `flat_map` can be implemented
@@ -165,6 +173,19 @@ impl<A, B> FlatMap<B> for Option<A> {
     }
 }
 
+impl<A, B> FlatMap<B> for Poll<A> {
+    #[inline]
+    fn flat_map<F>(self, mut f: F) -> Poll<B>
+    where
+        F: FnMut(A) -> Poll<B>,
+    {
+        match self {
+            Poll::Ready(a) => f(a),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
 impl<A, B, E> FlatMap<B> for Result<A, E> {
     #[inline]
     fn flat_map<F>(self, f: F) -> Result<B, E>
@@ -175,10 +196,78 @@ impl<A, B, E> FlatMap<B> for Result<A, E> {
     }
 }
 
+impl<X, A, B> FlatMap<B> for ControlFlow<X, A> {
+    #[inline]
+    fn flat_map<F>(self, mut f: F) -> ControlFlow<X, B>
+    where
+        F: FnMut(A) -> ControlFlow<X, B>,
+    {
+        match self {
+            ControlFlow::Continue(a) => f(a),
+            ControlFlow::Break(x) => ControlFlow::Break(x),
+        }
+    }
+}
+
+impl<X: Semigroup, A, B> FlatMap<B> for (X, A) {
+    #[inline]
+    fn flat_map<F>(self, mut f: F) -> (X, B)
+    where
+        F: FnMut(A) -> (X, B),
+    {
+        let (x, b) = f(self.1);
+        (self.0.combine(x), b)
+    }
+}
+
+/// Arrays flat map along the diagonal: the `i`-th element of the result is the `i`-th element of
+/// the array returned by `f` for the `i`-th element.
+impl<A, B, const N: usize> FlatMap<B> for [A; N] {
+    #[inline]
+    fn flat_map<F>(self, mut f: F) -> [B; N]
+    where
+        F: FnMut(A) -> [B; N],
+    {
+        let mut i = 0;
+        self.map(|a| {
+            let b = f(a)
+                .into_iter()
+                .nth(i)
+                .expect("arrays have the same length");
+            i += 1;
+            b
+        })
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A, B, const N: usize> FlatMap<B> for SmallVec<[A; N]> {
+    #[inline]
+    fn flat_map<F>(self, f: F) -> SmallVec<[B; N]>
+    where
+        F: FnMut(A) -> SmallVec<[B; N]>,
+    {
+        self.into_iter().flat_map(f).collect()
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, A, B> FlatMap<B> for Either<L, A> {
+    #[inline]
+    fn flat_map<F>(self, f: F) -> Either<L, B>
+    where
+        F: FnMut(A) -> Either<L, B>,
+    {
+        self.right_and_then(f)
+    }
+}
+
 if_std! {
     use std::boxed::Box;
     use std::collections::*;
     use std::hash::Hash;
+    use std::rc::Rc;
+    use std::sync::Arc;
     use std::vec::Vec;
 
     impl<A, B> FlatMap<B> for Box<A> {
@@ -191,6 +280,26 @@ if_std! {
         }
     }
 
+    impl<A: Clone, B> FlatMap<B> for Rc<A> {
+        #[inline]
+        fn flat_map<F>(self, mut f: F) -> Rc<B>
+        where
+            F: FnMut(A) -> Rc<B>,
+        {
+            f(Rc::unwrap_or_clone(self))
+        }
+    }
+
+    impl<A: Clone, B> FlatMap<B> for Arc<A> {
+        #[inline]
+        fn flat_map<F>(self, mut f: F) -> Arc<B>
+        where
+            F: FnMut(A) -> Arc<B>,
+        {
+            f(Arc::unwrap_or_clone(self))
+        }
+    }
+
     flatmap_iter!(Vec);
     flatmap_iter!(LinkedList);
     flatmap_iter!(VecDeque);
@@ -200,11 +309,21 @@ if_std! {
 
     impl<A, B, K: Eq + Hash> FlatMap<B> for HashMap<K, A> {
         #[inline]
-        fn flat_map<F>(self, mut f: F) -> HashMap<K, B>
+        fn flat_map<F>(self, f: F) -> HashMap<K, B>
         where
             F: FnMut(A) -> HashMap<K, B>,
         {
-            self.into_iter().flat_map(|(_, v)|  f(v)).collect()
+            self.into_values().flat_map(f).collect()
+        }
+    }
+
+    impl<A, B, K: Ord> FlatMap<B> for BTreeMap<K, A> {
+        #[inline]
+        fn flat_map<F>(self, f: F) -> BTreeMap<K, B>
+        where
+            F: FnMut(A) -> BTreeMap<K, B>,
+        {
+            self.into_values().flat_map(f).collect()
         }
     }
 }
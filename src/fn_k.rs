@@ -1,5 +1,7 @@
 //! Functor transformation.
 
+use crate::data::validated::Validated;
+use crate::functor::Functor;
 use crate::higher::Higher;
 use crate::monoid::Monoid;
 use crate::pure::Pure;
@@ -22,6 +24,9 @@ use core::marker::PhantomData;
 ///
 /// assert_eq!(vec![Some(1), None], foo(vec![Ok(1), Err(2)], Result::ok));
 /// ```
+///
+/// The function `foo` above is a special case of [map_k], which applies a transformation under
+/// any outer functor.
 pub trait FnK<A, B>
 where
     A: Higher,
@@ -76,6 +81,65 @@ where
     }
 }
 
+/// Apply a functor transformation under an outer [Functor], turning `F<G<A>>` into `F<H<A>>`.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let xs = vec![Ok(1), Err("err"), Ok(3)];
+/// assert_eq!(vec![Some(1), None, Some(3)], map_k(xs, OkToOption));
+///
+/// let xs = Some(vec![1, 2, 3]);
+/// assert_eq!(Some(Some(3)), map_k(xs, LastToOption));
+/// ```
+#[inline]
+pub fn map_k<FGA, GA, HA>(fga: FGA, f: impl FnK<GA, HA>) -> FGA::Target<HA>
+where
+    FGA: Functor<HA, Param = GA>,
+    GA: Higher,
+    HA: Higher<Param = GA::Param>,
+{
+    fga.map(|ga| f.apply(ga))
+}
+
+/// Create an anonymous functor transformation generic over the given type parameters, without
+/// declaring a named unit struct for it. Simple trait bounds are supported on the type
+/// parameters. Like any [FnK] implementor declared as an item, the transformation can't capture
+/// its environment; use a closure for that instead.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// fn heads<F: FnK<Vec<u8>, Option<u8>> + FnK<Vec<char>, Option<char>>>(f: F) -> (Option<u8>, Option<char>) {
+///     (f.apply(vec![1, 2]), f.apply(vec!['a']))
+/// }
+///
+/// let head = fn_k!(<T> |x: Vec<T>| -> Option<T> { x.into_iter().next() });
+/// assert_eq!((Some(1), Some('a')), heads(head));
+///
+/// let doubled = fn_k!(<T: Clone> |x: Option<T>| -> Vec<T> {
+///     x.into_iter().flat_map(|x| [x.clone(), x]).collect()
+/// });
+/// assert_eq!(vec![1, 1], doubled.apply(Some(1)));
+/// ```
+#[macro_export]
+macro_rules! fn_k {
+    ($(<$($g:ident $(: $b:ident $(+ $bs:ident)*)?),* $(,)?>)? |$x:ident : $a:ty| -> $r:ty $body:block) => {{
+        struct AnonymousFnK;
+
+        impl$(<$($g $(: $b $(+ $bs)*)?),*>)? $crate::fn_k::FnK<$a, $r> for AnonymousFnK {
+            #[inline]
+            fn apply(&self, $x: $a) -> $r $body
+        }
+
+        AnonymousFnK
+    }};
+}
+
 /// Functor transformation from `A` to `C` by composing two functor transformations.
 /// This transformation will apply the first transformation to the input value and then
 /// apply the second transformation to the result of the first transformation.
@@ -252,6 +316,84 @@ where
     }
 }
 
+/// Functor transformation from `Option` to `Result`.
+/// This transformation will return `Ok` with the value inside the `Option` if it is `Some`.
+/// If the `Option` is `None`, it will return `Err(E)`.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// assert_eq!(Ok(1), OptionToResult("err").apply(Some(1)));
+/// assert_eq!(Err("err"), OptionToResult("err").apply(None::<i32>));
+/// ```
+pub struct OptionToResult<E>(pub E);
+impl<T, E: Clone> FnK<Option<T>, Result<T, E>> for OptionToResult<E> {
+    #[inline]
+    fn apply(&self, a: Option<T>) -> Result<T, E> {
+        a.ok_or_else(|| self.0.clone())
+    }
+}
+
+/// Functor transformation from `Result` to `Option`.
+/// This transformation will return `Some` with the value inside the `Result` if it is `Ok`.
+/// If the `Result` is `Err`, the error is discarded and it will return `None`.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// assert_eq!(Some(1), OkToOption.apply(Ok::<_, &str>(1)));
+/// assert_eq!(None::<i32>, OkToOption.apply(Err("err")));
+/// ```
+pub struct OkToOption;
+impl<T, E> FnK<Result<T, E>, Option<T>> for OkToOption {
+    #[inline]
+    fn apply(&self, a: Result<T, E>) -> Option<T> {
+        a.ok()
+    }
+}
+
+/// Functor transformation from `Result` to [Validated].
+/// `Ok` values become `Valid` and `Err` values become `Invalid`.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// assert_eq!(Valid(1), ResultToValidated.apply(Ok::<_, &str>(1)));
+/// assert_eq!(Invalid::<i32, _>("err"), ResultToValidated.apply(Err("err")));
+/// ```
+pub struct ResultToValidated;
+impl<T, E> FnK<Result<T, E>, Validated<T, E>> for ResultToValidated {
+    #[inline]
+    fn apply(&self, a: Result<T, E>) -> Validated<T, E> {
+        a.into()
+    }
+}
+
+/// Functor transformation from [Validated] to `Result`.
+/// `Valid` values become `Ok` and `Invalid` values become `Err`.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// assert_eq!(Ok(1), ValidatedToResult.apply(Valid::<_, &str>(1)));
+/// assert_eq!(Err::<i32, _>("err"), ValidatedToResult.apply(Invalid("err")));
+/// ```
+pub struct ValidatedToResult;
+impl<T, E> FnK<Validated<T, E>, Result<T, E>> for ValidatedToResult {
+    #[inline]
+    fn apply(&self, a: Validated<T, E>) -> Result<T, E> {
+        a.into_result()
+    }
+}
+
 impl<A, B, F> FnK<A, B> for F
 where
     A: Higher,
@@ -265,6 +407,8 @@ where
 }
 
 if_std! {
+    use std::collections::HashSet;
+    use std::hash::Hash;
     use std::vec;
     use std::vec::Vec;
 
@@ -313,6 +457,48 @@ if_std! {
             }
         }
     }
+
+    /// Functor transformation from `IntoIterator` implementer to `Vec`.
+    /// This transformation will collect all elements of the iterator into a `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(vec![1, 2], IterToVec.apply(VecDeque::from([1, 2])));
+    /// assert_eq!(vec![1], IterToVec.apply(Some(1)));
+    /// ```
+    pub struct IterToVec;
+    impl<T, A> FnK<A, Vec<T>> for IterToVec
+    where
+        A: IntoIterator<Item = T> + Higher<Param = T>,
+    {
+        #[inline]
+        fn apply(&self, a: A) -> Vec<T> {
+            a.into_iter().collect()
+        }
+    }
+
+    /// Functor transformation from `Vec` to `HashSet`.
+    /// This transformation will remove duplicate elements and forget their order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(HashSet::from([1, 2]), VecToHashSet.apply(vec![1, 2, 1]));
+    /// ```
+    pub struct VecToHashSet;
+    impl<T: Eq + Hash> FnK<Vec<T>, HashSet<T>> for VecToHashSet {
+        #[inline]
+        fn apply(&self, a: Vec<T>) -> HashSet<T> {
+            a.into_iter().collect()
+        }
+    }
 }
 
 /// Functor transformation from `Option` to a type implementing [Pure] and [Monoid].
@@ -344,3 +530,29 @@ where
         }
     }
 }
+
+/// Functor transformation from `IntoIterator` implementer to a type implementing [Pure] and
+/// [Monoid]. This transformation will lift every element with [Pure::pure] and combine the
+/// results. It generalizes [OptionToF] to any iterable.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+/// use rust2fun::prelude::*;
+///
+/// let set: HashSet<_> = PureK.apply(vec![1, 2, 1]);
+/// assert_eq!(HashSet::from([1, 2]), set);
+/// assert_eq!(Some("ab".to_string()), PureK.apply(vec!["a".to_string(), "b".to_string()]));
+/// ```
+pub struct PureK;
+impl<T, A, F> FnK<A, F> for PureK
+where
+    A: IntoIterator<Item = T> + Higher<Param = T>,
+    F: Pure<Param = T> + Monoid,
+{
+    #[inline]
+    fn apply(&self, a: A) -> F {
+        F::combine_all(a.into_iter().map(F::pure))
+    }
+}
@@ -40,6 +40,13 @@
 //! ```
 
 use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 use crate::combinator::noop1;
 use crate::constant1;
@@ -205,6 +212,30 @@ pub trait Functor<B>: Invariant<B> {
         self.map(|a| (a, b))
     }
 
+    /// Run a side effect on each `A` value in `Self<A>`, preserving the structure and the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut seen = Vec::new();
+    /// let actual = vec![1, 2, 3].tap_each(|x: &i32| seen.push(*x)).map(|x| x * 2);
+    /// assert_eq!(vec![2, 4, 6], actual);
+    /// assert_eq!(vec![1, 2, 3], seen);
+    /// ```
+    #[inline]
+    fn tap_each<F>(self, mut f: F) -> Self::Target<B>
+    where
+        F: FnMut(&B),
+        Self: Higher<Param = B> + Sized,
+    {
+        self.map(|a| {
+            f(&a);
+            a
+        })
+    }
+
     /// Un-zips an `Self<(A, B)>` consisting of element pairs into two separate Self's tupled.
     ///
     /// # Examples
@@ -279,6 +310,27 @@ impl<A, B> Functor<B> for Option<A> {
     }
 }
 
+impl<X, A, B> Functor<B> for (X, A) {
+    #[inline]
+    fn map(self, mut f: impl FnMut(A) -> B) -> (X, B) {
+        (self.0, f(self.1))
+    }
+}
+
+impl<A, B, const N: usize> Functor<B> for [A; N] {
+    #[inline]
+    fn map(self, f: impl FnMut(A) -> B) -> [B; N] {
+        self.map(f)
+    }
+}
+
+impl<A, B> Functor<B> for Poll<A> {
+    #[inline]
+    fn map(self, f: impl FnMut(A) -> B) -> Poll<B> {
+        self.map(f)
+    }
+}
+
 impl<A, B, E> Functor<B> for Result<A, E> {
     #[inline]
     fn map(self, f: impl FnMut(A) -> B) -> Result<B, E> {
@@ -286,10 +338,39 @@ impl<A, B, E> Functor<B> for Result<A, E> {
     }
 }
 
+impl<X, A, B> Functor<B> for ControlFlow<X, A> {
+    #[inline]
+    fn map(self, mut f: impl FnMut(A) -> B) -> ControlFlow<X, B> {
+        match self {
+            ControlFlow::Continue(a) => ControlFlow::Continue(f(a)),
+            ControlFlow::Break(x) => ControlFlow::Break(x),
+        }
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A, B, const N: usize> Functor<B> for SmallVec<[A; N]> {
+    #[inline]
+    fn map(self, f: impl FnMut(A) -> B) -> SmallVec<[B; N]> {
+        self.into_iter().map(f).collect()
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, A, B> Functor<B> for Either<L, A> {
+    #[inline]
+    fn map(self, f: impl FnMut(A) -> B) -> Either<L, B> {
+        self.map_right(f)
+    }
+}
+
 if_std! {
     use std::boxed::Box;
+    use std::cell::{Cell, RefCell};
     use std::collections::*;
     use std::hash::Hash;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex, PoisonError, RwLock};
     use std::vec::Vec;
 
     impl<A, B> Functor<B> for Box<A> {
@@ -299,6 +380,52 @@ if_std! {
         }
     }
 
+    impl<A: Clone, B> Functor<B> for Rc<A> {
+        #[inline]
+        fn map(self, mut f: impl FnMut(A) -> B) -> Rc<B> {
+            Rc::new(f(Rc::unwrap_or_clone(self)))
+        }
+    }
+
+    impl<A: Clone, B> Functor<B> for Arc<A> {
+        #[inline]
+        fn map(self, mut f: impl FnMut(A) -> B) -> Arc<B> {
+            Arc::new(f(Arc::unwrap_or_clone(self)))
+        }
+    }
+
+    impl<A, B> Functor<B> for Cell<A> {
+        #[inline]
+        fn map(self, mut f: impl FnMut(A) -> B) -> Cell<B> {
+            Cell::new(f(self.into_inner()))
+        }
+    }
+
+    impl<A, B> Functor<B> for RefCell<A> {
+        #[inline]
+        fn map(self, mut f: impl FnMut(A) -> B) -> RefCell<B> {
+            RefCell::new(f(self.into_inner()))
+        }
+    }
+
+    /// Poisoning is deliberately ignored, as for the [Semigroup](crate::semigroup::Semigroup)
+    /// instance: the value is taken out of a poisoned lock and the new lock is not poisoned.
+    impl<A, B> Functor<B> for Mutex<A> {
+        #[inline]
+        fn map(self, mut f: impl FnMut(A) -> B) -> Mutex<B> {
+            Mutex::new(f(self.into_inner().unwrap_or_else(PoisonError::into_inner)))
+        }
+    }
+
+    /// Poisoning is deliberately ignored, as for the [Semigroup](crate::semigroup::Semigroup)
+    /// instance: the value is taken out of a poisoned lock and the new lock is not poisoned.
+    impl<A, B> Functor<B> for RwLock<A> {
+        #[inline]
+        fn map(self, mut f: impl FnMut(A) -> B) -> RwLock<B> {
+            RwLock::new(f(self.into_inner().unwrap_or_else(PoisonError::into_inner)))
+        }
+    }
+
     functor_iter!(Vec);
     functor_iter!(LinkedList);
     functor_iter!(VecDeque);
@@ -312,4 +439,11 @@ if_std! {
             self.into_iter().map(|(k, v)| (k, f(v))).collect()
         }
     }
+
+    impl<A, B, K: Ord> Functor<B> for BTreeMap<K, A> {
+        #[inline]
+        fn map(self, mut f: impl FnMut(A) -> B) -> BTreeMap<K, B> {
+            self.into_iter().map(|(k, v)| (k, f(v))).collect()
+        }
+    }
 }
@@ -0,0 +1,103 @@
+//! Group.
+
+use core::marker::PhantomData;
+
+use crate::monoid::{CommutativeMonoid, Monoid};
+
+/// A `Group` is a [Monoid] where each element has an inverse.
+pub trait Group: Monoid {
+    /// Returns the inverse of the value, so that `a.combine(a.inverse())` is the identity
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(-1, 1.inverse());
+    /// assert_eq!((-1, 2.5), (1, -2.5).inverse());
+    /// ```
+    fn inverse(self) -> Self;
+
+    /// Remove the other value from this one. This is the same as combining with the inverse of
+    /// the other value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(1, 3.remove(2));
+    ///
+    /// let window = [1, 2, 3, 4, 5];
+    /// let sum = window[..3].iter().copied().fold(i32::empty(), Semigroup::combine);
+    /// let next = sum.combine(window[3]).remove(window[0]);
+    /// assert_eq!(9, next);
+    /// ```
+    #[inline]
+    fn remove(self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        self.combine(other.inverse())
+    }
+}
+
+/// A commutative group is a [Group] whose operation is also commutative.
+/// It is implemented for every type that is both a [Group] and a [CommutativeMonoid].
+pub trait CommutativeGroup: Group + CommutativeMonoid {}
+
+impl<T: Group + CommutativeMonoid> CommutativeGroup for T {}
+
+macro_rules! group_numeric {
+    ($($t:ty)*) => ($(
+        impl Group for $t {
+            #[inline]
+            fn inverse(self) -> Self { -self }
+
+            #[inline]
+            fn remove(self, other: Self) -> Self { self - other }
+        }
+    )*)
+}
+
+group_numeric! { isize i8 i16 i32 i64 i128 f32 f64 }
+
+impl Group for () {
+    #[inline]
+    fn inverse(self) -> Self {}
+}
+
+macro_rules! group_tuple {
+    ($($idx:tt $t:tt),+) => {
+        impl<$($t: Group,)*> Group for ($($t,)+)
+        {
+            #[inline]
+            fn inverse(self) -> Self {
+                ($(
+                    $t :: inverse(self.$idx),
+                )+)
+            }
+        }
+    };
+}
+
+group_tuple!(0 A);
+group_tuple!(0 A, 1 B);
+group_tuple!(0 A, 1 B, 2 C);
+group_tuple!(0 A, 1 B, 2 C, 3 D);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+group_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
+impl<T> Group for PhantomData<T> {
+    #[inline]
+    fn inverse(self) -> Self {
+        PhantomData
+    }
+}
@@ -13,12 +13,37 @@
 //! must support the concept of higher kinded types. Although Rust lacks in a native support for HKT,
 //! we always have a walk around called Lightweight Higher Kinded Type.
 //!
+//! # Newtypes
+//!
+//! [HigherNewtype] derives [Higher] and the type classes of a single-field wrapper by delegating
+//! to the wrapped type, which makes branded types cheap to define:
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! #[derive(Debug, PartialEq, HigherNewtype)]
+//! struct Checked<T>(Vec<T>);
+//!
+//! let actual = Checked(vec![1, 2]).flat_map(|x| Checked(vec![x, x * 10]));
+//! assert_eq!(Checked(vec![1, 10, 2, 20]), actual);
+//! assert_eq!(Checked(vec![1, 2, 3]), Checked(vec![1]).combine(Checked(vec![2, 3])));
+//! ```
+//!
 //! # See also
 //!
 //! * [Lightweight Higher Kinded Type](https://www.cl.cam.ac.uk/~jdy22/papers/lightweight-higher-kinded-polymorphism.pdf)
 //! * [Rust/Haskell: Higher-Kinded Types (HKT)](https://gist.github.com/CMCDragonkai/a5638f50c87d49f815b8)
 
 use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
+pub use rust2fun_macros::HigherNewtype;
 
 /// Implementation of Lightweight Higher Kinded Type for a type of kind `* -> *`.
 pub trait Higher {
@@ -84,31 +109,83 @@ macro_rules! higher {
 
 higher!(Option);
 higher!(PhantomData);
+higher!(Poll);
 
 impl<P, E> Higher for Result<P, E> {
     type Param = P;
     type Target<T> = Result<T, E>;
 }
 
+impl<P, const N: usize> Higher for [P; N] {
+    type Param = P;
+    type Target<T> = [T; N];
+}
+
 impl<P, E> Higher2 for Result<P, E> {
     type Param1 = P;
     type Param2 = E;
     type Target<TP, TE> = Result<TP, TE>;
 }
 
+/// [ControlFlow] is [Higher] in its `Continue` value, `Break` short-circuits like `Err`.
+impl<B, C> Higher for ControlFlow<B, C> {
+    type Param = C;
+    type Target<T> = ControlFlow<B, T>;
+}
+
+impl<B, C> Higher2 for ControlFlow<B, C> {
+    type Param1 = B;
+    type Param2 = C;
+    type Target<TB, TC> = ControlFlow<TB, TC>;
+}
+
+/// Pairs are [Higher] in their second element, like a writer accumulating the first one.
+impl<A, B> Higher for (A, B) {
+    type Param = B;
+    type Target<T> = (A, T);
+}
+
 impl<A, B> Higher2 for (A, B) {
     type Param1 = A;
     type Param2 = B;
     type Target<TA, TB> = (TA, TB);
 }
 
+#[cfg(feature = "smallvec")]
+impl<P, const N: usize> Higher for SmallVec<[P; N]> {
+    type Param = P;
+    type Target<T> = SmallVec<[T; N]>;
+}
+
+#[cfg(feature = "either")]
+impl<L, R> Higher for Either<L, R> {
+    type Param = R;
+    type Target<T> = Either<L, T>;
+}
+
+#[cfg(feature = "either")]
+impl<L, R> Higher2 for Either<L, R> {
+    type Param1 = L;
+    type Param2 = R;
+    type Target<TL, TR> = Either<TL, TR>;
+}
+
 if_std! {
     use std::boxed::Box;
+    use std::cell::{Cell, RefCell};
     use std::collections::*;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex, RwLock};
     use std::vec::Vec;
 
     higher!(Vec);
     higher!(Box);
+    higher!(Rc);
+    higher!(Arc);
+    higher!(Cell);
+    higher!(RefCell);
+    higher!(Mutex);
+    higher!(RwLock);
     higher!(LinkedList);
     higher!(BinaryHeap);
     higher!(BTreeSet);
@@ -120,9 +197,26 @@ if_std! {
         type Target<T> = HashMap<K, T>;
     }
 
+    impl<K, V> Higher for BTreeMap<K, V> {
+        type Param = V;
+        type Target<T> = BTreeMap<K, T>;
+    }
+
+    impl<A, B> Higher2 for Box<dyn FnMut(A) -> B> {
+        type Param1 = A;
+        type Param2 = B;
+        type Target<T1, T2> = Box<dyn FnMut(T1) -> T2>;
+    }
+
     impl<K, V> Higher2 for HashMap<K, V>{
         type Param1 = K;
         type Param2 = V;
         type Target<TK, TV> = HashMap<TK, TV>;
     }
+
+    impl<K, V> Higher2 for BTreeMap<K, V> {
+        type Param1 = K;
+        type Param2 = V;
+        type Target<TK, TV> = BTreeMap<TK, TV>;
+    }
 }
@@ -1,6 +1,13 @@
 //! Invariant functors.
 
 use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 use crate::higher::Higher;
 
@@ -100,18 +107,131 @@ impl<A, B> Invariant<B> for PhantomData<A> {
 }
 
 invariant_functor!(Option<T>);
+invariant_functor!(Poll<T>);
 invariant_functor!(Result<T, E>);
+invariant_functor!(ControlFlow<X, T>);
+
+impl<X, A, B> Invariant<B> for (X, A) {
+    #[inline]
+    fn imap<F, G>(self, mut f: F, _g: G) -> (X, B)
+    where
+        F: FnMut(A) -> B,
+        G: FnMut(B) -> A,
+    {
+        (self.0, f(self.1))
+    }
+}
+
+impl<A, B, const N: usize> Invariant<B> for [A; N] {
+    #[inline]
+    fn imap<F, G>(self, f: F, _g: G) -> [B; N]
+    where
+        F: FnMut(A) -> B,
+        G: FnMut(B) -> A,
+    {
+        self.map(f)
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A, B, const N: usize> Invariant<B> for SmallVec<[A; N]> {
+    #[inline]
+    fn imap<F, G>(self, f: F, _g: G) -> SmallVec<[B; N]>
+    where
+        F: FnMut(A) -> B,
+        G: FnMut(B) -> A,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+#[cfg(feature = "either")]
+invariant_functor!(Either<L, T>);
 
 if_std! {
     use std::boxed::Box;
+    use std::cell::{Cell, RefCell};
     use std::collections::*;
     use std::hash::Hash;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex, PoisonError, RwLock};
     use std::vec::Vec;
 
     invariant_functor!(Vec<T>);
     invariant_functor!(LinkedList<T>);
     invariant_functor!(VecDeque<T>);
     invariant_functor!(Box<T>);
+
+    impl<A: Clone, B> Invariant<B> for Rc<A> {
+        #[inline]
+        fn imap<F, G>(self, mut f: F, _g: G) -> Rc<B>
+        where
+            F: FnMut(A) -> B,
+            G: FnMut(B) -> A,
+        {
+            Rc::new(f(Rc::unwrap_or_clone(self)))
+        }
+    }
+
+    impl<A: Clone, B> Invariant<B> for Arc<A> {
+        #[inline]
+        fn imap<F, G>(self, mut f: F, _g: G) -> Arc<B>
+        where
+            F: FnMut(A) -> B,
+            G: FnMut(B) -> A,
+        {
+            Arc::new(f(Arc::unwrap_or_clone(self)))
+        }
+    }
+
+    impl<A, B> Invariant<B> for Cell<A> {
+        #[inline]
+        fn imap<F, G>(self, mut f: F, _g: G) -> Cell<B>
+        where
+            F: FnMut(A) -> B,
+            G: FnMut(B) -> A,
+        {
+            Cell::new(f(self.into_inner()))
+        }
+    }
+
+    impl<A, B> Invariant<B> for RefCell<A> {
+        #[inline]
+        fn imap<F, G>(self, mut f: F, _g: G) -> RefCell<B>
+        where
+            F: FnMut(A) -> B,
+            G: FnMut(B) -> A,
+        {
+            RefCell::new(f(self.into_inner()))
+        }
+    }
+
+    /// Poisoning is deliberately ignored, as for the [Semigroup](crate::semigroup::Semigroup)
+    /// instance: the value is taken out of a poisoned lock and the new lock is not poisoned.
+    impl<A, B> Invariant<B> for Mutex<A> {
+        #[inline]
+        fn imap<F, G>(self, mut f: F, _g: G) -> Mutex<B>
+        where
+            F: FnMut(A) -> B,
+            G: FnMut(B) -> A,
+        {
+            Mutex::new(f(self.into_inner().unwrap_or_else(PoisonError::into_inner)))
+        }
+    }
+
+    /// Poisoning is deliberately ignored, as for the [Semigroup](crate::semigroup::Semigroup)
+    /// instance: the value is taken out of a poisoned lock and the new lock is not poisoned.
+    impl<A, B> Invariant<B> for RwLock<A> {
+        #[inline]
+        fn imap<F, G>(self, mut f: F, _g: G) -> RwLock<B>
+        where
+            F: FnMut(A) -> B,
+            G: FnMut(B) -> A,
+        {
+            RwLock::new(f(self.into_inner().unwrap_or_else(PoisonError::into_inner)))
+        }
+    }
+
     invariant_functor!(BinaryHeap<T>, Ord);
     invariant_functor!(BTreeSet<T>, Ord);
     invariant_functor!(HashSet<T>, Hash + Eq);
@@ -126,4 +246,15 @@ if_std! {
             self.into_iter().map(|(k, v)| (k, f(v))).collect()
         }
     }
+
+    impl<A, B, K: Ord> Invariant<B> for BTreeMap<K, A> {
+        #[inline]
+        fn imap<F, G>(self, mut f: F, _g: G) -> BTreeMap<K, B>
+        where
+            F: FnMut(A) -> B,
+            G: FnMut(B) -> A,
+        {
+            self.into_iter().map(|(k, v)| (k, f(v))).collect()
+        }
+    }
 }
@@ -0,0 +1,62 @@
+//! Invariant monoidal functors.
+//!
+//! [Monoid] is an invariant monoidal functor: the empty value of a monoid for `A` can be mapped
+//! to any type isomorphic to `A`. The [monoid_imap] macro derives both [Semigroup] and [Monoid]
+//! for newtypes this way.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Celsius(f64);
+//! monoid_imap!(Celsius, f64, Celsius, |c: Celsius| c.0);
+//!
+//! let total = Celsius::combine_all(vec![Celsius(1.5), Celsius(2.5)]);
+//! assert_eq!(Celsius(4.0), total);
+//! assert_eq!(Celsius(0.0), Celsius::empty());
+//! ```
+//!
+//! [Semigroup]: crate::semigroup::Semigroup
+
+use crate::invariant_semigroupal::InvariantSemigroupal;
+use crate::pure::Pure;
+
+/// Invariant semigroupal functor with a unit value. This is an [InvariantSemigroupal] with
+/// [Pure], which provides the unit value with [Pure::unit].
+pub trait InvariantMonoidal<B>: InvariantSemigroupal<B> + Pure {}
+
+impl<T, B> InvariantMonoidal<B> for T where T: InvariantSemigroupal<B> + Pure {}
+
+/// Macro to implement [Semigroup] and [Monoid] for a type isomorphic to a type implementing
+/// [Monoid] by transporting its instances through the functions `f: A -> B` and `g: B -> A`.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Name(String);
+/// monoid_imap!(Name, String, Name, |n: Name| n.0);
+///
+/// assert_eq!(Name("".to_string()), Name::empty());
+/// assert_eq!(Name("ab".to_string()), Name("a".to_string()).combine(Name("b".to_string())));
+/// ```
+///
+/// [Semigroup]: crate::semigroup::Semigroup
+/// [Monoid]: crate::monoid::Monoid
+#[macro_export]
+macro_rules! monoid_imap {
+    ($b:ty, $a:ty, $f:expr, $g:expr) => {
+        $crate::semigroup_imap!($b, $a, $f, $g);
+
+        impl $crate::monoid::Monoid for $b {
+            #[inline]
+            fn empty() -> Self {
+                ($f)(<$a as $crate::monoid::Monoid>::empty())
+            }
+        }
+    };
+}
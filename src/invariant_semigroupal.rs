@@ -0,0 +1,58 @@
+//! Invariant semigroupal functors.
+//!
+//! [Semigroup] itself is an invariant semigroupal functor: given a semigroup for `A` and an
+//! isomorphism between `A` and `B`, a semigroup for `B` can be derived by mapping the values
+//! to `A`, combining them and mapping the result back to `B`. The [semigroup_imap] macro derives
+//! [Semigroup] for newtypes this way.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Meters(u32);
+//! semigroup_imap!(Meters, u32, Meters, |m: Meters| m.0);
+//!
+//! assert_eq!(Meters(3), Meters(1).combine(Meters(2)));
+//! ```
+
+use crate::invariant::Invariant;
+use crate::semigroupal::Semigroupal;
+
+/// Invariant functor which is also [Semigroupal].
+pub trait InvariantSemigroupal<B>: Invariant<B> + Semigroupal<B> {}
+
+impl<T, B> InvariantSemigroupal<B> for T where T: Invariant<B> + Semigroupal<B> {}
+
+/// Macro to implement [Semigroup] for a type isomorphic to a type implementing [Semigroup] by
+/// transporting its instance through the functions `f: A -> B` and `g: B -> A`.
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Tags(Vec<&'static str>);
+/// semigroup_imap!(Tags, Vec<&'static str>, Tags, |t: Tags| t.0);
+///
+/// assert_eq!(Tags(vec!["a", "b"]), Tags(vec!["a"]).combine(Tags(vec!["b"])));
+/// ```
+///
+/// [Semigroup]: crate::semigroup::Semigroup
+#[macro_export]
+macro_rules! semigroup_imap {
+    ($b:ty, $a:ty, $f:expr, $g:expr) => {
+        impl $crate::semigroup::Semigroup for $b {
+            #[inline]
+            fn combine(self, other: Self) -> Self {
+                let g = $g;
+                ($f)(<$a as $crate::semigroup::Semigroup>::combine(
+                    g(self),
+                    g(other),
+                ))
+            }
+        }
+    };
+}
@@ -1,6 +1,7 @@
 //! A library for functional programming in Rust
 
 #![no_std]
+#![cfg_attr(feature = "try_trait", feature(try_trait_v2, try_trait_v2_residual))]
 #![deny(missing_docs)]
 #![allow(clippy::too_many_arguments)]
 
@@ -20,46 +21,90 @@ macro_rules! if_std {
     ( $( $code:tt )* ) => {};
 }
 
+pub mod align;
 pub mod and_then;
 pub mod ap_n;
 pub mod applicative;
+pub mod applicative_error;
 pub mod apply;
+#[cfg(feature = "proptest-support")]
+pub mod arbitrary;
+pub mod arrow;
 pub mod bifunctor;
+pub mod coflatmap;
 pub mod combinator;
+pub mod comonad;
 pub mod contravariant;
 pub mod data;
+pub mod decidable;
+pub mod divide;
+pub mod divisible;
+pub mod eq;
 pub mod flatmap;
 pub mod fn_k;
 pub mod functor;
+pub mod group;
 pub mod higher;
 pub mod invariant;
+pub mod invariant_monoidal;
+pub mod invariant_semigroupal;
 pub mod map_n;
 pub mod monad;
+pub mod monad_error;
+pub mod monad_trans;
 pub mod monoid;
+pub mod non_empty_traverse;
+pub mod order;
+pub mod partial_order;
+pub mod profunctor;
 pub mod pure;
+pub mod reducible;
 pub mod semigroup;
 pub mod semigroupal;
+pub mod show;
+pub mod traverse;
 
 /// Convenience re-export of common members of the library.
 pub mod prelude {
+    pub use crate::align::*;
     pub use crate::and_then::*;
     pub use crate::ap_n::*;
     pub use crate::applicative::*;
+    pub use crate::applicative_error::*;
     pub use crate::apply::*;
+    pub use crate::arrow::*;
     pub use crate::bifunctor::*;
+    pub use crate::coflatmap::*;
     pub use crate::combinator::*;
+    pub use crate::comonad::*;
     pub use crate::contravariant::*;
     pub use crate::data::*;
+    pub use crate::decidable::*;
+    pub use crate::divide::*;
+    pub use crate::divisible::*;
+    pub use crate::eq::Eq as _;
     pub use crate::flatmap::*;
     pub use crate::fn_k::*;
     pub use crate::functor::*;
+    pub use crate::group::*;
     pub use crate::higher::*;
     pub use crate::invariant::*;
+    pub use crate::invariant_monoidal::*;
+    pub use crate::invariant_semigroupal::*;
     pub use crate::map_n::*;
     pub use crate::monad::*;
+    pub use crate::monad_error::*;
+    pub use crate::monad_trans::*;
     pub use crate::monoid::*;
+    pub use crate::non_empty_traverse::*;
+    pub use crate::order::{compare_by, compare_reversed, compare_when_equal};
+    pub use crate::partial_order::*;
+    pub use crate::profunctor::*;
     pub use crate::pure::*;
+    pub use crate::reducible::*;
     pub use crate::semigroup::*;
     pub use crate::semigroupal::*;
+    pub use crate::show::*;
+    pub use crate::traverse::*;
     pub use crate::*;
 }
@@ -55,15 +55,11 @@ pub trait MapN<B>: Semigroupal<B> {
         self.product(fb).product(fc).map(|((a, b), c)| f(a, b, c))
     }
 
-    map_n!(4);
-    map_n!(5);
-    map_n!(6);
-    map_n!(7);
-    map_n!(8);
-    map_n!(9);
-    map_n!(10);
-    map_n!(11);
-    map_n!(12);
+    map_n!(4..=6);
+    #[cfg(feature = "arity-12")]
+    map_n!(7..=12);
+    #[cfg(feature = "arity-16")]
+    map_n!(13..=16);
 
     /// Compose two effectful values discarding the result of the first.
     ///
@@ -109,3 +105,47 @@ pub trait MapN<B>: Semigroupal<B> {
 }
 
 impl<T: Semigroupal<B>, B> MapN<B> for T {}
+
+/// Apply a function of any arity to effectful arguments, combining their effects. This is the
+/// same as calling the `mapN` method of the right arity, and the arity is inferred from the
+/// number of arguments.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let add3 = |x: i32, y: i32, z: i32| x + y + z;
+/// assert_eq!(Some(6), apply!(add3, Some(1), Some(2), Some(3)));
+/// assert_eq!(None, apply!(add3, Some(1), None, Some(3)));
+///
+/// let actual = apply!(|x: i32, s: &str| format!("{s}{x}"), vec![1, 2], vec!["a", "b"]);
+/// assert_eq!(vec!["a1", "b1", "a2", "b2"], actual);
+///
+/// // Arities above 12 are supported too.
+/// let actual: Validated<usize, String> = apply!(
+///     |a, b, c, d, e, f, g, h, i, j, k, l, m| [a, b, c, d, e, f, g, h, i, j, k, l, m].len(),
+///     Valid(1), Valid(2), Valid(3), Valid(4), Valid(5), Valid(6), Valid(7),
+///     Valid(8), Valid(9), Invalid("a".to_string()), Valid(11), Valid(12), Invalid("b".to_string())
+/// );
+/// assert_eq!(Invalid("ab".to_string()), actual);
+/// ```
+#[macro_export]
+macro_rules! apply {
+    (@product $f:ident; $acc:expr; ($($pat:tt)*); ($($args:ident)*);) => (
+        $crate::functor::Functor::map($acc, |$($pat)*| $f($($args),*))
+    );
+    (@product $f:ident; $acc:expr; ($($pat:tt)*); ($($args:ident)*); $fa:expr $(, $rest:expr)*) => (
+        $crate::apply!(
+            @product $f;
+            $crate::semigroupal::Semigroupal::product($acc, $fa);
+            (($($pat)*, x));
+            ($($args)* x);
+            $($rest),*
+        )
+    );
+    ($f:expr, $fa:expr $(, $rest:expr)* $(,)?) => ({
+        let mut f = $f;
+        $crate::apply!(@product f; $fa; (x); (x); $($rest),*)
+    });
+}
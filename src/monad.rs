@@ -69,6 +69,8 @@
 //! }
 //! ```
 
+use crate::functor::Functor;
+use crate::higher::Higher;
 use crate::prelude::FlatMap;
 use crate::pure::Pure;
 
@@ -78,6 +80,168 @@ pub trait Monad<B>: FlatMap<B> + Pure {}
 
 impl<T, B> Monad<B> for T where T: FlatMap<B> + Pure {}
 
+/// Run the effectful `body` for each element of an iterator, sequencing the effects from left to
+/// right and discarding the results.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let check = |x: i32| if x > 0 { Some(()) } else { None };
+/// assert_eq!(Some(()), for_each_m(vec![1, 2, 3], check));
+/// assert_eq!(None, for_each_m(vec![1, -2, 3], check));
+///
+/// let actual = for_each_m([1, 2], |_| vec![(), ()]);
+/// assert_eq!(vec![(); 4], actual);
+/// ```
+pub fn for_each_m<I, MU, F>(iter: I, mut body: F) -> MU
+where
+    I: IntoIterator,
+    I::Item: Clone,
+    F: FnMut(I::Item) -> MU,
+    MU: FlatMap<(), Param = (), Target<()> = MU> + Pure,
+{
+    iter.into_iter()
+        .fold(MU::pure(()), |acc, a| acc.flat_map(|_| body(a.clone())))
+}
+
+/// Run the effectful `body` as long as the effectful condition `cond` holds.
+///
+/// The iterations are driven by a loop rather than by recursion, so that long loops don't
+/// overflow the stack. If the monad has several results, like [Vec], the loop continues as long
+/// as the condition holds for one of them.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::Cell;
+/// use rust2fun::prelude::*;
+///
+/// let counter = Cell::new(0);
+/// let actual = while_m(
+///     || Some(counter.get() < 100_000),
+///     || Some(counter.set(counter.get() + 1)),
+/// );
+/// assert_eq!(Some(()), actual);
+/// assert_eq!(100_000, counter.get());
+///
+/// let actual = while_m(|| Some(true), || None::<()>);
+/// assert_eq!(None, actual);
+/// ```
+pub fn while_m<MB, MU, C, F>(mut cond: C, mut body: F) -> MU
+where
+    C: FnMut() -> MB,
+    F: FnMut() -> MU,
+    MB: FlatMap<bool, Param = bool, Target<bool> = MB> + Functor<(), Target<()> = MU> + Pure,
+    MU: FlatMap<bool, Param = (), Target<bool> = MB> + Higher,
+{
+    let mut state = cond();
+    loop {
+        let mut again = false;
+        state = state.flat_map(|c| {
+            if c {
+                again = true;
+                body().flat_map(|_| cond())
+            } else {
+                MB::pure(false)
+            }
+        });
+        if !again {
+            return state.void();
+        }
+    }
+}
+
+/// Fold an iterator with an effectful function, sequencing the effects from left to right.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let safe_div = |acc: i32, x: i32| if x != 0 { Some(acc / x) } else { None };
+/// assert_eq!(Some(5), fold_m(vec![2, 3], 30, safe_div));
+/// assert_eq!(None, fold_m(vec![2, 0, 3], 30, safe_div));
+///
+/// let actual = fold_m([1, 2], 0, |acc, x| vec![acc + x, acc * x]);
+/// assert_eq!(vec![3, 2, 2, 0], actual);
+/// ```
+pub fn fold_m<I, B, MB, F>(iter: I, init: B, mut f: F) -> MB
+where
+    I: IntoIterator,
+    I::Item: Clone,
+    F: FnMut(B, I::Item) -> MB,
+    MB: FlatMap<B, Param = B, Target<B> = MB> + Pure,
+{
+    iter.into_iter()
+        .fold(MB::pure(init), |acc, a| acc.flat_map(|b| f(b, a.clone())))
+}
+
+/// Apply the effectful function `f` repeatedly, starting from `init`, as long as the predicate
+/// `p` holds for the current value. The predicate is checked before the first application.
+///
+/// Like [while_m], the iterations are driven by a loop rather than by recursion. The predicate
+/// may be called more than once on the same value, so it should be free of side effects.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let actual = iterate_while_m(1, |x: u32| x.checked_mul(2), |x| *x < 1000);
+/// assert_eq!(Some(1024), actual);
+///
+/// let actual = iterate_while_m(1, |x: u8| x.checked_mul(2), |x| *x < 200);
+/// assert_eq!(None, actual);
+///
+/// let actual = iterate_while_m(0, |x| Ok::<_, ()>(x + 1), |x| *x < 100_000);
+/// assert_eq!(Ok(100_000), actual);
+/// ```
+pub fn iterate_while_m<A, MA, F, P>(init: A, mut f: F, mut p: P) -> MA
+where
+    F: FnMut(A) -> MA,
+    P: FnMut(&A) -> bool,
+    MA: FlatMap<A, Param = A, Target<A> = MA> + Pure,
+{
+    let mut state = MA::pure(init);
+    loop {
+        let mut again = false;
+        state = state.flat_map(|a| {
+            if p(&a) {
+                again = true;
+                f(a)
+            } else {
+                MA::pure(a)
+            }
+        });
+        if !again {
+            return state;
+        }
+    }
+}
+
+/// Apply the effectful function `f` repeatedly, starting from `init`, until the predicate `p`
+/// holds for the current value. The predicate is checked before the first application.
+/// See [iterate_while_m] for more.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let collatz = |x: u64| if x % 2 == 0 { Some(x / 2) } else { x.checked_mul(3).map(|x| x + 1) };
+/// assert_eq!(Some(1), iterate_until_m(27, collatz, |x| *x == 1));
+/// ```
+pub fn iterate_until_m<A, MA, F, P>(init: A, f: F, mut p: P) -> MA
+where
+    F: FnMut(A) -> MA,
+    P: FnMut(&A) -> bool,
+    MA: FlatMap<A, Param = A, Target<A> = MA> + Pure,
+{
+    iterate_while_m(init, f, |a| !p(a))
+}
+
 /// Bind macro. Allows for a more natural syntax for monadic composition.
 /// It is similar to the `do` notation in Haskell or the `for` notation in Scala.
 ///
@@ -171,6 +335,146 @@ impl<T, B> Monad<B> for T where T: FlatMap<B> + Pure {}
 /// assert_eq!(Some(3), actual);
 /// ```
 ///
+/// A final `if`/`else` or `match` block can branch into different bind sequences. Each branch,
+/// and each match arm in braces, is a bind sequence on its own. Match arms without braces are
+/// plain values, lifted with [Pure::pure].
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let lookup = |x: i32| bind! {
+///     for y in Some(x);
+///     if y > 10 {
+///         for z in y.checked_mul(2);
+///         z
+///     } else if y > 0 {
+///         y
+///     } else {
+///         for _ in None::<()>;
+///         0
+///     }
+/// };
+///
+/// assert_eq!(Some(22), lookup(11));
+/// assert_eq!(Some(5), lookup(5));
+/// assert_eq!(None, lookup(-1));
+///
+/// let actual = bind! {
+///     for x in vec![1, 2, 3];
+///     match x % 2 {
+///         0 => {
+///             for y in vec![x, x * 10];
+///             y
+///         }
+///         _ => x,
+///     }
+/// };
+///
+/// assert_eq!(vec![1, 2, 20, 3], actual);
+/// ```
+///
+/// Effectful loops are supported with `while_m cond => body;`, running `body` while `cond`
+/// holds (see [while_m]), and `for_each_m x in iter => body;`, running `body` for each element
+/// of an ordinary iterator (see [for_each_m]).
+///
+/// ```
+/// use std::cell::Cell;
+/// use rust2fun::prelude::*;
+///
+/// let total = &Cell::new(0);
+/// let actual = bind! {
+///     for limit in Some(10);
+///     for_each_m x in 1..=3 => Some(total.set(total.get() + x));
+///     while_m Some(total.get() < limit) => Some(total.set(total.get() * 2));
+///     total.get()
+/// };
+///
+/// assert_eq!(Some(12), actual);
+/// ```
+///
+/// Types which can't implement [FlatMap] because they require `'static` functions, like
+/// [IO](crate::data::io::IO), can be used too as long as they provide an inherent `flat_map`
+/// method. Such values are bound with the `inherent` syntax, which calls `flat_map` with method
+/// syntax instead of [FlatMap::flat_map].
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let program = bind! {
+///     for x in inherent(IO::delay(|| 1));
+///     for y in inherent(IO::delay(move || x + 1));
+///     x + y
+/// };
+///
+/// assert_eq!(3, program.run());
+/// ```
+///
+/// Values of an underlying monad can be lifted into a monad transformer (see [MonadTrans]) with
+/// the `lift` syntax, giving the type of the transformer explicitly.
+///
+/// ```
+/// use rust2fun::prelude::*;
+/// #
+/// # #[derive(Debug, PartialEq)]
+/// # struct IdT<GA>(GA);
+/// #
+/// # impl<GA: Higher> Higher for IdT<GA> {
+/// #     type Param = GA::Param;
+/// #     type Target<T> = IdT<GA::Target<T>>;
+/// # }
+/// #
+/// # impl<GA: Higher> MonadTrans for IdT<GA> {
+/// #     type Inner = GA;
+/// #     fn lift(inner: GA) -> Self {
+/// #         IdT(inner)
+/// #     }
+/// # }
+/// #
+/// # impl<GA: FlatMap<B>, B> FlatMap<B> for IdT<GA> {
+/// #     fn flat_map<F>(self, mut f: F) -> IdT<GA::Target<B>>
+/// #     where
+/// #         F: FnMut(GA::Param) -> IdT<GA::Target<B>>,
+/// #     {
+/// #         IdT(self.0.flat_map(|a| f(a).0))
+/// #     }
+/// # }
+/// #
+/// # impl<GA: Pure> Pure for IdT<GA> {
+/// #     fn pure(x: GA::Param) -> Self {
+/// #         IdT(GA::pure(x))
+/// #     }
+/// # }
+///
+/// let actual = bind! {
+///     for x in IdT(Some(1));
+///     for y in lift::<IdT<_>>(Some(2));
+///     x + y
+/// };
+///
+/// assert_eq!(IdT(Some(3)), actual);
+/// ```
+///
+/// Similarly, values of other types can be converted with [From] using the `from` syntax.
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let actual = bind! {
+///     for x in ne_vec![1, 2];
+///     for y in from::<NEVec<_>>([x, x * 10]);
+///     y
+/// };
+///
+/// assert_eq!(ne_vec![1, 10, 2, 20], actual);
+/// ```
+///
+/// The type of the target monad has to be given explicitly in both cases, as it can't be
+/// inferred from the surrounding binds.
+///
+/// The `inherent`, `lift` and `from` forms are recognized by name, so they take over functions of
+/// the same names. To bind the result of such a function, call it through its path, e.g.
+/// `for x in self::from::<T>(y);`.
+///
 /// # Examples
 ///
 /// ```
@@ -207,8 +511,53 @@ impl<T, B> Monad<B> for T where T: FlatMap<B> + Pure {}
 ///
 /// assert_eq!(vec![("GOOG".to_string(), 2), ("AMZN".to_string(), 1)], profits);
 /// ```
+///
+/// [MonadTrans]: crate::monad_trans::MonadTrans
 #[macro_export]
 macro_rules! bind {
+    (@collect [$($acc:tt)*] ; $($rest:tt)+) => ({
+        $($acc)*;
+        bind!($($rest)+)
+    });
+    (@collect [$($acc:tt)*] $t:tt $($rest:tt)*) => (
+        bind!(@collect [$($acc)* $t] $($rest)*)
+    );
+    (@collect [if $($acc:tt)*]) => (
+        bind!(@if [] $($acc)*)
+    );
+    (@collect [match $($acc:tt)*]) => (
+        bind!(@match [] $($acc)*)
+    );
+    (@if [$($cond:tt)+] { $($then:tt)* } else { $($else:tt)* }) => (
+        if $($cond)+ { bind!($($then)*) } else { bind!($($else)*) }
+    );
+    (@if [$($cond:tt)+] { $($then:tt)* } else if $($rest:tt)+) => (
+        if $($cond)+ { bind!($($then)*) } else { bind!(@if [] $($rest)+) }
+    );
+    (@if [$($cond:tt)*] $t:tt $($rest:tt)*) => (
+        bind!(@if [$($cond)* $t] $($rest)*)
+    );
+    (@if [$($e:tt)*]) => (
+        $crate::pure::Pure::pure(if $($e)*)
+    );
+    (@match [$($e:tt)+] { $($arms:tt)* }) => (
+        bind!(@arms [$($e)+] [] $($arms)*)
+    );
+    (@match [$($e:tt)*] $t:tt $($rest:tt)*) => (
+        bind!(@match [$($e)* $t] $($rest)*)
+    );
+    (@arms [$($e:tt)+] [$($acc:tt)*]) => (
+        match $($e)+ { $($acc)* }
+    );
+    (@arms [$($e:tt)+] [$($acc:tt)*] $p:pat $(if $guard:expr)? => { $($arm:tt)* } , $($rest:tt)*) => (
+        bind!(@arms [$($e)+] [$($acc)* $p $(if $guard)? => bind!($($arm)*),] $($rest)*)
+    );
+    (@arms [$($e:tt)+] [$($acc:tt)*] $p:pat $(if $guard:expr)? => { $($arm:tt)* } $($rest:tt)*) => (
+        bind!(@arms [$($e)+] [$($acc)* $p $(if $guard)? => bind!($($arm)*),] $($rest)*)
+    );
+    (@arms [$($e:tt)+] [$($acc:tt)*] $p:pat $(if $guard:expr)? => $v:expr $(, $($rest:tt)*)?) => (
+        bind!(@arms [$($e)+] [$($acc)* $p $(if $guard)? => $crate::pure::Pure::pure($v),] $($($rest)*)?)
+    );
     (return $e:expr, if $cond:expr;) => (
         if $cond { $e } else { $crate::monoid::Monoid::empty() }
     );
@@ -223,6 +572,37 @@ macro_rules! bind {
         let $p = $e;
         bind!($($rest)+)
     });
+    (for $p:pat in lift::<$t:ty>($e:expr) , if $cond:expr ; $($rest:tt)+) => (
+        bind!(for $p in <$t as $crate::monad_trans::MonadTrans>::lift($e), if $cond; $($rest)+)
+    );
+    (for $p:pat in lift::<$t:ty>($e:expr); $($rest:tt)+) => (
+        bind!(for $p in <$t as $crate::monad_trans::MonadTrans>::lift($e); $($rest)+)
+    );
+    (while_m $cond:expr => $body:expr; $($rest:tt)+) => (
+        bind!(for _ in $crate::monad::while_m(|| $cond, || $body); $($rest)+)
+    );
+    (while_m $cond:expr => $body:expr $(;)?) => (
+        $crate::monad::while_m(|| $cond, || $body)
+    );
+    (for_each_m $p:pat in $iter:expr => $body:expr; $($rest:tt)+) => (
+        bind!(for _ in $crate::monad::for_each_m($iter, |$p| $body); $($rest)+)
+    );
+    (for_each_m $p:pat in $iter:expr => $body:expr $(;)?) => (
+        $crate::monad::for_each_m($iter, |$p| $body)
+    );
+    (for $p:pat in from::<$t:ty>($e:expr) , if $cond:expr ; $($rest:tt)+) => (
+        bind!(for $p in <$t as ::core::convert::From<_>>::from($e), if $cond; $($rest)+)
+    );
+    (for $p:pat in from::<$t:ty>($e:expr); $($rest:tt)+) => (
+        bind!(for $p in <$t as ::core::convert::From<_>>::from($e); $($rest)+)
+    );
+    (for $p:pat in inherent($e:expr) , if $cond:expr ; $($rest:tt)+) => (
+        (if $cond { $e } else { $crate::monoid::Monoid::empty() })
+            .flat_map(move |$p| bind!($($rest)+))
+    );
+    (for $p:pat in inherent($e:expr); $($rest:tt)+) => (
+        ($e).flat_map(move |$p| bind!($($rest)+))
+    );
     (for $p:pat in $e:expr , if $cond:expr ; $($rest:tt)+) => (
         $crate::flatmap::FlatMap::flat_map(
             if $cond { $e } else { $crate::monoid::Monoid::empty() },
@@ -232,6 +612,12 @@ macro_rules! bind {
     (for $p:pat in $e:expr; $($rest:tt)+) => (
         $crate::flatmap::FlatMap::flat_map($e, move |$p| bind!($($rest)+))
     );
+    (if $($rest:tt)+) => (
+        bind!(@collect [if] $($rest)+)
+    );
+    (match $($rest:tt)+) => (
+        bind!(@collect [match] $($rest)+)
+    );
     ($s:stmt;  $($rest:tt)+) => ({
         $s
         bind!($($rest)+)
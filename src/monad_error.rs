@@ -0,0 +1,116 @@
+//! MonadError.
+//!
+//! A monad that also allows you to raise and handle errors of type `E`. Compared to
+//! [ApplicativeError] it allows inspecting successful values to decide whether to fail, and
+//! lifting errors nested in the value into the context.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn positive<F>(fa: F) -> F
+//! where
+//!     F: MonadError<&'static str, Param = i32> + FlatMap<i32, Target<i32> = F>,
+//! {
+//!     fa.ensure(|| "not positive", |x| *x > 0)
+//! }
+//!
+//! assert_eq!(Ok(1), positive(Ok(1)));
+//! assert_eq!(Err("not positive"), positive(Ok(-1)));
+//! assert_eq!(Err("error"), positive(Err("error")));
+//! ```
+
+use crate::applicative_error::ApplicativeError;
+use crate::flatmap::FlatMap;
+use crate::higher::Higher;
+use crate::pure::Pure;
+
+/// A monad with an error channel of type `E`.
+/// See [the module level documentation](self) for more.
+pub trait MonadError<E>: ApplicativeError<E> + FlatMap<<Self as Higher>::Param> {
+    /// Turn a successful value into an error if it does not satisfy the given predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Result<i32, &str> = Ok(1);
+    /// assert_eq!(Ok(1), x.ensure(|| "not odd", |x| x % 2 == 1));
+    /// assert_eq!(Err("not even"), x.ensure(|| "not even", |x| x % 2 == 0));
+    /// assert_eq!(None, Some(1).ensure(|| (), |x| x % 2 == 0));
+    /// ```
+    #[inline]
+    fn ensure<F, P>(self, mut error: F, predicate: P) -> Self
+    where
+        F: FnMut() -> E,
+        P: FnMut(&Self::Param) -> bool,
+        Self: FlatMap<<Self as Higher>::Param, Target<<Self as Higher>::Param> = Self> + Sized,
+    {
+        self.ensure_or(|_| error(), predicate)
+    }
+
+    /// Turn a successful value into an error computed from the value if it does not satisfy the
+    /// given predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Result<i32, String> = Ok(1);
+    /// assert_eq!(Ok(1), x.clone().ensure_or(|x| format!("{x} is even"), |x| x % 2 == 1));
+    /// assert_eq!(Err("1 is odd".to_string()), x.ensure_or(|x| format!("{x} is odd"), |x| x % 2 == 0));
+    /// ```
+    #[inline]
+    fn ensure_or<F, P>(self, mut error: F, mut predicate: P) -> Self
+    where
+        F: FnMut(Self::Param) -> E,
+        P: FnMut(&Self::Param) -> bool,
+        Self: FlatMap<<Self as Higher>::Param, Target<<Self as Higher>::Param> = Self> + Sized,
+    {
+        #[inline]
+        fn internal<FA: FlatMap<<FA as Higher>::Param, Target<<FA as Higher>::Param> = FA>>(
+            fa: FA,
+            g: impl FnMut(FA::Param) -> FA,
+        ) -> FA {
+            fa.flat_map(g)
+        }
+
+        internal(self, |a| {
+            if predicate(&a) {
+                Self::pure(a)
+            } else {
+                Self::raise_error(error(a))
+            }
+        })
+    }
+
+    /// Lift an error nested in the successful value into the context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let x: Result<Result<i32, &str>, &str> = Ok(Err("inner"));
+    /// assert_eq!(Err("inner"), x.rethrow());
+    ///
+    /// let x: Result<Result<i32, &str>, &str> = Ok(Ok(1));
+    /// assert_eq!(Ok(1), x.rethrow());
+    /// ```
+    #[inline]
+    fn rethrow<A>(self) -> Self::Target<A>
+    where
+        Self: Higher<Param = Result<A, E>> + FlatMap<A> + Sized,
+        Self::Target<A>: ApplicativeError<E>,
+    {
+        self.flat_map(|x| match x {
+            Ok(a) => Pure::pure(a),
+            Err(e) => ApplicativeError::raise_error(e),
+        })
+    }
+}
+
+impl<E, T> MonadError<E> for T where T: ApplicativeError<E> + FlatMap<<T as Higher>::Param> {}
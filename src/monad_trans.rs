@@ -0,0 +1,51 @@
+//! MonadTrans.
+//!
+//! A monad transformer `T<G, A>` adds an effect on top of an underlying monad `G<A>`.
+//! [MonadTrans::lift] injects a value of the underlying monad into the transformer, so that it
+//! can be sequenced with other transformer values without bespoke lifting methods.
+//!
+//! Instances must satisfy the following laws:
+//!
+//! 1. Identity: `T::lift(G::pure(a)) == T::pure(a)`
+//! 2. Composition: `T::lift(ga.flat_map(f)) == T::lift(ga).flat_map(|a| T::lift(f(a)))`
+//!
+//! The [bind] macro supports lifting with the `for x in lift::<T>(ga);` syntax, and converting
+//! with [From] using the `for x in from::<T>(ga);` syntax.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct IdT<GA>(GA);
+//!
+//! impl<GA: Higher> Higher for IdT<GA> {
+//!     type Param = GA::Param;
+//!     type Target<T> = IdT<GA::Target<T>>;
+//! }
+//!
+//! impl<GA: Higher> MonadTrans for IdT<GA> {
+//!     type Inner = GA;
+//!
+//!     fn lift(inner: GA) -> Self {
+//!         IdT(inner)
+//!     }
+//! }
+//!
+//! assert_eq!(IdT(Some(1)), IdT::lift(Some(1)));
+//! ```
+//!
+//! [bind]: crate::bind
+
+use crate::higher::Higher;
+
+/// Typeclass for monad transformers able to lift values of the underlying monad.
+/// See [the module level documentation](self) for more.
+pub trait MonadTrans: Higher {
+    /// The underlying monadic value `G<A>` with the same parameter as the transformer.
+    type Inner: Higher<Param = Self::Param>;
+
+    /// Lift a value of the underlying monad into the transformer.
+    fn lift(inner: Self::Inner) -> Self;
+}
@@ -1,8 +1,16 @@
 //! Monoid.
 
+use core::cmp::Ordering;
 use core::marker::PhantomData;
+use core::num::{
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+use core::time::Duration;
 
-use crate::semigroup::Semigroup;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
+use crate::semigroup::{CommutativeSemigroup, Semigroup};
 
 /// A `Monoid` is a `Semigroup` with an identity element.
 pub trait Monoid: Semigroup {
@@ -57,6 +65,12 @@ pub trait Monoid: Semigroup {
     }
 }
 
+/// A commutative monoid is a [Monoid] whose operation is also commutative.
+/// It is implemented for every type that is both a [Monoid] and a [CommutativeSemigroup].
+pub trait CommutativeMonoid: Monoid + CommutativeSemigroup {}
+
+impl<T: Monoid + CommutativeSemigroup> CommutativeMonoid for T {}
+
 macro_rules! semigroup_numeric {
     ($($t:ty)*) => ($(
         impl Monoid for $t {
@@ -68,6 +82,33 @@ macro_rules! semigroup_numeric {
 
 semigroup_numeric! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
 
+macro_rules! monoid_overflow {
+    (wrapping $($t:ty)*) => ($(
+        impl Monoid for Wrapping<$t> {
+            #[inline]
+            fn empty() -> Self { Wrapping(0) }
+        }
+    )*);
+    (saturating $($t:ty)*) => ($(
+        impl Monoid for Saturating<$t> {
+            #[inline]
+            fn empty() -> Self { Saturating(0) }
+        }
+    )*);
+    (non_zero $($t:ty)*) => ($(
+        impl Monoid for $t {
+            #[inline]
+            fn empty() -> Self { <$t>::MIN }
+        }
+    )*);
+}
+
+monoid_overflow! { wrapping usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+monoid_overflow! { saturating usize u8 u16 u32 u64 u128 }
+monoid_overflow! {
+    non_zero NonZeroUsize NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128
+}
+
 impl Monoid for () {
     #[inline]
     fn empty() -> Self {}
@@ -78,6 +119,20 @@ impl Monoid for () {
     }
 }
 
+impl Monoid for Ordering {
+    #[inline]
+    fn empty() -> Self {
+        Ordering::Equal
+    }
+}
+
+impl Monoid for Duration {
+    #[inline]
+    fn empty() -> Self {
+        Duration::ZERO
+    }
+}
+
 macro_rules! monoid_tuple {
     ($($idx:tt $t:tt),+) => {
         impl<$($t: Monoid,)*> Monoid for ($($t,)+)
@@ -124,6 +179,19 @@ impl<T: Semigroup> Monoid for Option<T> {
     }
 }
 
+#[cfg(feature = "smallvec")]
+impl<T, const N: usize> Monoid for SmallVec<[T; N]> {
+    #[inline]
+    fn empty() -> Self {
+        SmallVec::new()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        SmallVec::is_empty(self)
+    }
+}
+
 if_std! {
     use std::collections::*;
     use std::hash::Hash;
@@ -140,6 +208,34 @@ if_std! {
         fn is_empty(&self) -> bool {
             String::is_empty(self)
         }
+
+        #[inline]
+        fn combine_all<I>(iter: I) -> Self
+        where
+            I: IntoIterator<Item = Self>,
+        {
+            Self::combine_all_option(iter).unwrap_or_default()
+        }
+    }
+
+    impl<T> Monoid for Vec<T> {
+        #[inline]
+        fn empty() -> Self {
+            Vec::new()
+        }
+
+        #[inline]
+        fn is_empty(&self) -> bool {
+            Vec::is_empty(self)
+        }
+
+        #[inline]
+        fn combine_all<I>(iter: I) -> Self
+        where
+            I: IntoIterator<Item = Self>,
+        {
+            Self::combine_all_option(iter).unwrap_or_default()
+        }
     }
 
     macro_rules! monoid_new {
@@ -171,7 +267,6 @@ if_std! {
         };
     }
 
-    monoid_new!(Vec);
     monoid_new!(LinkedList);
     monoid_new!(VecDeque);
     monoid_new!(BinaryHeap, Ord);
@@ -189,4 +284,16 @@ if_std! {
             HashMap::is_empty(self)
         }
     }
+
+    impl<K: Ord, V: Semigroup> Monoid for BTreeMap<K, V> {
+        #[inline]
+        fn empty() -> Self {
+            BTreeMap::new()
+        }
+
+        #[inline]
+        fn is_empty(&self) -> bool {
+            BTreeMap::is_empty(self)
+        }
+    }
 }
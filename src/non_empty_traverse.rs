@@ -0,0 +1,93 @@
+//! NonEmptyTraverse.
+//!
+//! Traversal over non-empty structures. Since there is always at least one element to start
+//! from, the effect only needs to be [Semigroupal] and a [Functor] (i.e. [Apply]-like), without
+//! the ability to lift pure values. This allows traversing with effects like [Validated] whose
+//! error type is only a [Semigroup].
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn parse(s: &str) -> Validated<i32, NEVec<String>> {
+//!     s.parse().map_err(|_| ne_vec![format!("{s} is not a number")]).into()
+//! }
+//!
+//! assert_eq!(Valid(ne_vec![1, 2]), ne_vec!["1", "2"].non_empty_traverse(parse));
+//! assert_eq!(
+//!     Invalid(ne_vec!["a is not a number".to_string(), "b is not a number".to_string()]),
+//!     ne_vec!["a", "2", "b"].non_empty_traverse(parse)
+//! );
+//! ```
+//!
+//! [Apply]: crate::apply::Apply
+//! [Validated]: crate::data::validated::Validated
+//! [Semigroup]: crate::semigroup::Semigroup
+//! [Functor]: crate::functor::Functor
+//! [Semigroupal]: crate::semigroupal::Semigroupal
+
+use crate::higher::Higher;
+
+/// Traverse a non-empty structure with an effect `GB` that doesn't need to be [Pure].
+/// See [the module level documentation](self) for more.
+///
+/// [Pure]: crate::pure::Pure
+pub trait NonEmptyTraverse<GB: Higher>: Higher {
+    /// Apply an effectful function to each element and collect the results in the effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let actual = ne_vec![1, 2, 3].non_empty_traverse(|x| if x > 0 { Some(x) } else { None });
+    /// assert_eq!(Some(ne_vec![1, 2, 3]), actual);
+    ///
+    /// let actual = ne_vec![1, -2, 3].non_empty_traverse(|x| if x > 0 { Some(x) } else { None });
+    /// assert_eq!(None, actual);
+    /// ```
+    fn non_empty_traverse<F>(self, f: F) -> GB::Target<Self::Target<GB::Param>>
+    where
+        F: FnMut(Self::Param) -> GB;
+
+    /// Turn a structure of effects into an effect of the structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let actual = ne_vec![Ok(1), Ok(2)].non_empty_sequence();
+    /// assert_eq!(Ok::<_, &str>(ne_vec![1, 2]), actual);
+    ///
+    /// let actual = ne_vec![Ok(1), Err("error")].non_empty_sequence();
+    /// assert_eq!(Err("error"), actual);
+    /// ```
+    #[inline]
+    fn non_empty_sequence(self) -> GB::Target<Self::Target<GB::Param>>
+    where
+        Self: Higher<Param = GB> + Sized,
+    {
+        self.non_empty_traverse(|x| x)
+    }
+}
+
+if_std! {
+    use std::boxed::Box;
+
+    use crate::functor::Functor;
+
+    impl<A, GB> NonEmptyTraverse<GB> for Box<A>
+    where
+        GB: Functor<Box<<GB as Higher>::Param>>,
+    {
+        #[inline]
+        fn non_empty_traverse<F>(self, mut f: F) -> GB::Target<Box<GB::Param>>
+        where
+            F: FnMut(A) -> GB,
+        {
+            f(*self).map(Box::new)
+        }
+    }
+}
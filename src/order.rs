@@ -0,0 +1,154 @@
+//! Order.
+//!
+//! A typeclass for total orders. It is implemented for every type implementing [Ord]. Floating
+//! point numbers can be given a lawful total order with the [Total] wrapper.
+//!
+//! [Order] is not exported by the prelude: its associated `min` and `max` functions would make
+//! `Ord::min` and `Ord::max` ambiguous for every [Ord] type, so it has to be imported explicitly.
+//!
+//! This module also provides combinators building comparison functions, which can be passed to
+//! methods like [`slice::sort_by`].
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let mut xs = vec![("b", 2.0), ("a", f64::NAN), ("c", 1.0)];
+//! xs.sort_by(compare_by(|x: &(&str, f64)| Total(x.1)));
+//! assert_eq!(vec!["c", "b", "a"], xs.iter().map(|x| x.0).collect::<Vec<_>>());
+//! ```
+//!
+//! [Total]: crate::data::total::Total
+
+use core::cmp::Ordering;
+
+use crate::partial_order::PartialOrder;
+
+/// Typeclass for types with a lawful total order.
+/// See [the module level documentation](self) for more.
+pub trait Order: PartialOrder {
+    /// Returns an ordering between `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use rust2fun::order::Order;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Ordering::Less, 1.compare(&2));
+    /// assert_eq!(Ordering::Greater, Total(f64::NAN).compare(&Total(1.0)));
+    /// ```
+    fn compare(&self, other: &Self) -> Ordering;
+
+    /// Returns the lesser of two values. Returns `x` if they are equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::order::Order;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(1, Order::min(1, 2));
+    /// assert_eq!(Total(-0.0), Order::min(Total(0.0), Total(-0.0)));
+    /// ```
+    #[inline]
+    fn min(x: Self, y: Self) -> Self
+    where
+        Self: Sized,
+    {
+        match x.compare(&y) {
+            Ordering::Greater => y,
+            _ => x,
+        }
+    }
+
+    /// Returns the greater of two values. Returns `y` if they are equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::order::Order;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(2, Order::max(1, 2));
+    /// assert_eq!(Total(0.0), Order::max(Total(0.0), Total(-0.0)));
+    /// ```
+    #[inline]
+    fn max(x: Self, y: Self) -> Self
+    where
+        Self: Sized,
+    {
+        match x.compare(&y) {
+            Ordering::Greater => x,
+            _ => y,
+        }
+    }
+}
+
+impl<T: Ord + ?Sized> Order for T {
+    #[inline]
+    fn compare(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+/// Create a comparison function comparing values by the result of the given function.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let mut xs = vec!["bb", "a", "ccc"];
+/// xs.sort_by(compare_by(|s: &&str| s.len()));
+/// assert_eq!(vec!["a", "bb", "ccc"], xs);
+/// ```
+#[inline]
+pub fn compare_by<A, B: Order>(mut f: impl FnMut(&A) -> B) -> impl FnMut(&A, &A) -> Ordering {
+    move |x, y| f(x).compare(&f(y))
+}
+
+/// Reverse the given comparison function.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::order::Order;
+/// use rust2fun::prelude::*;
+///
+/// let mut xs = vec![2, 3, 1];
+/// xs.sort_by(compare_reversed(Order::compare));
+/// assert_eq!(vec![3, 2, 1], xs);
+/// ```
+#[inline]
+pub fn compare_reversed<A>(
+    mut cmp: impl FnMut(&A, &A) -> Ordering,
+) -> impl FnMut(&A, &A) -> Ordering {
+    move |x, y| cmp(x, y).reverse()
+}
+
+/// Combine two comparison functions lexicographically: values are compared with `second` only
+/// if `first` considers them equal.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::order::Order;
+/// use rust2fun::prelude::*;
+///
+/// let mut xs = vec!["bb", "b", "ab", "a"];
+/// xs.sort_by(compare_when_equal(
+///     compare_by(|s: &&str| s.len()),
+///     Order::compare,
+/// ));
+/// assert_eq!(vec!["a", "b", "ab", "bb"], xs);
+/// ```
+#[inline]
+pub fn compare_when_equal<A>(
+    mut first: impl FnMut(&A, &A) -> Ordering,
+    mut second: impl FnMut(&A, &A) -> Ordering,
+) -> impl FnMut(&A, &A) -> Ordering {
+    move |x, y| first(x, y).then_with(|| second(x, y))
+}
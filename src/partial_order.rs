@@ -0,0 +1,115 @@
+//! PartialOrder.
+//!
+//! A typeclass for partial orders. It is implemented for every type implementing both
+//! [PartialOrd] and [`core::cmp::Eq`].
+
+use core::cmp::Ordering;
+
+use crate::eq::Eq;
+
+/// Typeclass for types with a lawful partial order.
+pub trait PartialOrder: Eq {
+    /// Returns an ordering between `self` and `other` if one exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use std::collections::BTreeSet;
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(Ordering::Less), 1.partial_compare(&2));
+    ///
+    /// let x = BTreeSet::from([1]);
+    /// let y = BTreeSet::from([2]);
+    /// assert_eq!(Some(Ordering::Less), x.partial_compare(&y));
+    /// ```
+    fn partial_compare(&self, other: &Self) -> Option<Ordering>;
+
+    /// Returns `true` if `self` is less than or equivalent to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(1.lteqv(&1));
+    /// assert!(1.lteqv(&2));
+    /// assert!(!2.lteqv(&1));
+    /// ```
+    #[inline]
+    fn lteqv(&self, other: &Self) -> bool {
+        matches!(
+            self.partial_compare(other),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// Returns `true` if `self` is greater than or equivalent to `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert!(1.gteqv(&1));
+    /// assert!(2.gteqv(&1));
+    /// assert!(!1.gteqv(&2));
+    /// ```
+    #[inline]
+    fn gteqv(&self, other: &Self) -> bool {
+        matches!(
+            self.partial_compare(other),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
+
+    /// Returns the lesser of two values if they are comparable. Returns `x` if they are
+    /// equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(1), PartialOrder::pmin(1, 2));
+    /// ```
+    #[inline]
+    fn pmin(x: Self, y: Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match x.partial_compare(&y)? {
+            Ordering::Greater => Some(y),
+            _ => Some(x),
+        }
+    }
+
+    /// Returns the greater of two values if they are comparable. Returns `y` if they are
+    /// equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(2), PartialOrder::pmax(1, 2));
+    /// ```
+    #[inline]
+    fn pmax(x: Self, y: Self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match x.partial_compare(&y)? {
+            Ordering::Greater => Some(x),
+            _ => Some(y),
+        }
+    }
+}
+
+impl<T: PartialOrd + core::cmp::Eq + ?Sized> PartialOrder for T {
+    #[inline]
+    fn partial_compare(&self, other: &Self) -> Option<Ordering> {
+        self.partial_cmp(other)
+    }
+}
@@ -0,0 +1,185 @@
+//! Profunctors.
+//!
+//! A profunctor is a type constructor of two type arguments which is contravariant in its first
+//! argument and covariant in its second argument. The canonical example is a function `A -> B`:
+//! it can be pre-composed with a function `C -> A` and post-composed with a function `B -> D`.
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! let len: Box<dyn FnMut(String) -> usize> = Box::new(|s| s.len());
+//! let mut f = len.dimap(|x: i32| x.to_string(), |n| n * 2);
+//! assert_eq!(6, f(100));
+//! ```
+
+use crate::higher::Higher2;
+
+/// Profunctor is contravariant in its first type parameter and covariant in its second one.
+/// See [the module level documentation](self) for more.
+pub trait Profunctor<C, D>: Higher2 {
+    /// Transform a `Self<A, B>` into a `Self<C, D>` by providing a transformation from `C` to `A`
+    /// and from `B` to `D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let mut g = f.dimap(|s: &str| s.parse().unwrap(), |x| x.to_string());
+    /// assert_eq!("2", g("1"));
+    /// ```
+    fn dimap(
+        self,
+        f: impl FnMut(C) -> Self::Param1 + 'static,
+        g: impl FnMut(Self::Param2) -> D + 'static,
+    ) -> Self::Target<C, D>;
+
+    /// Contramap on the first type parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let mut g = f.lmap(|s: &str| s.parse().unwrap());
+    /// assert_eq!(2, g("1"));
+    /// ```
+    #[inline]
+    fn lmap(self, f: impl FnMut(C) -> Self::Param1 + 'static) -> Self::Target<C, D>
+    where
+        Self: Higher2<Param2 = D> + Sized,
+    {
+        self.dimap(f, |x| x)
+    }
+
+    /// Map on the second type parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let mut g = f.rmap(|x| x.to_string());
+    /// assert_eq!("2", g(1));
+    /// ```
+    #[inline]
+    fn rmap(self, g: impl FnMut(Self::Param2) -> D + 'static) -> Self::Target<C, D>
+    where
+        Self: Higher2<Param1 = C> + Sized,
+    {
+        self.dimap(|x| x, g)
+    }
+}
+
+/// Strong profunctor is a [Profunctor] that can be lifted to work on pairs, passing the other
+/// component `X` of the pair through untouched.
+pub trait Strong<X>: Profunctor<<Self as Higher2>::Param1, <Self as Higher2>::Param2> {
+    /// Lift `Self<A, B>` to work on the first component of a pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let mut g = f.first();
+    /// assert_eq!((2, "foo"), g((1, "foo")));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn first(self) -> Self::Target<(Self::Param1, X), (Self::Param2, X)>;
+
+    /// Lift `Self<A, B>` to work on the second component of a pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let mut g = f.second();
+    /// assert_eq!(("foo", 2), g(("foo", 1)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn second(self) -> Self::Target<(X, Self::Param1), (X, Self::Param2)>;
+}
+
+/// Choice profunctor is a [Profunctor] that can be lifted to work on one branch of a sum type,
+/// passing the other branch `X` through untouched. [Result] is used as the sum type, with `Err`
+/// being the left branch and `Ok` being the right one.
+pub trait Choice<X>: Profunctor<<Self as Higher2>::Param1, <Self as Higher2>::Param2> {
+    /// Lift `Self<A, B>` to work on the left (`Err`) branch of a [Result].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let mut g = f.left();
+    /// assert_eq!(Err(2), g(Err(1)));
+    /// assert_eq!(Ok("foo"), g(Ok("foo")));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn left(self) -> Self::Target<Result<X, Self::Param1>, Result<X, Self::Param2>>;
+
+    /// Lift `Self<A, B>` to work on the right (`Ok`) branch of a [Result].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let f: Box<dyn FnMut(i32) -> i32> = Box::new(|x| x + 1);
+    /// let mut g = f.right();
+    /// assert_eq!(Ok(2), g(Ok(1)));
+    /// assert_eq!(Err("foo"), g(Err("foo")));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn right(self) -> Self::Target<Result<Self::Param1, X>, Result<Self::Param2, X>>;
+}
+
+if_std! {
+    use std::boxed::Box;
+
+    impl<A: 'static, B: 'static, C: 'static, D: 'static> Profunctor<C, D>
+        for Box<dyn FnMut(A) -> B>
+    {
+        #[inline]
+        fn dimap(
+            mut self,
+            mut f: impl FnMut(C) -> A + 'static,
+            mut g: impl FnMut(B) -> D + 'static,
+        ) -> Box<dyn FnMut(C) -> D> {
+            Box::new(move |c| g(self(f(c))))
+        }
+    }
+
+    impl<A: 'static, B: 'static, X: 'static> Strong<X> for Box<dyn FnMut(A) -> B> {
+        #[inline]
+        fn first(mut self) -> Box<dyn FnMut((A, X)) -> (B, X)> {
+            Box::new(move |(a, x)| (self(a), x))
+        }
+
+        #[inline]
+        fn second(mut self) -> Box<dyn FnMut((X, A)) -> (X, B)> {
+            Box::new(move |(x, a)| (x, self(a)))
+        }
+    }
+
+    impl<A: 'static, B: 'static, X: 'static> Choice<X> for Box<dyn FnMut(A) -> B> {
+        #[inline]
+        fn left(mut self) -> Box<dyn FnMut(Result<X, A>) -> Result<X, B>> {
+            Box::new(move |r| r.map_err(&mut self))
+        }
+
+        #[inline]
+        fn right(mut self) -> Box<dyn FnMut(Result<A, X>) -> Result<B, X>> {
+            Box::new(move |r| r.map(&mut self))
+        }
+    }
+}
@@ -1,6 +1,15 @@
 //! Pure.
 
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
 use crate::higher::Higher;
+use crate::monoid::Monoid;
 
 /// Typeclass for lifting values into a context.
 pub trait Pure: Higher {
@@ -43,6 +52,20 @@ impl<A> Pure for Option<A> {
     }
 }
 
+impl<X, A> Pure for ControlFlow<X, A> {
+    #[inline]
+    fn pure(x: A) -> ControlFlow<X, A> {
+        ControlFlow::Continue(x)
+    }
+}
+
+impl<A> Pure for Poll<A> {
+    #[inline]
+    fn pure(x: A) -> Poll<A> {
+        Poll::Ready(x)
+    }
+}
+
 impl<A, E> Pure for Result<A, E> {
     #[inline]
     fn pure(x: A) -> Self {
@@ -50,12 +73,45 @@ impl<A, E> Pure for Result<A, E> {
     }
 }
 
+impl<X: Monoid, A> Pure for (X, A) {
+    #[inline]
+    fn pure(x: A) -> Self {
+        (X::empty(), x)
+    }
+}
+
+impl<A: Clone, const N: usize> Pure for [A; N] {
+    #[inline]
+    fn pure(x: A) -> Self {
+        core::array::from_fn(|_| x.clone())
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A, const N: usize> Pure for SmallVec<[A; N]> {
+    #[inline]
+    fn pure(x: A) -> Self {
+        smallvec::smallvec![x]
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, A> Pure for Either<L, A> {
+    #[inline]
+    fn pure(x: A) -> Either<L, A> {
+        Either::Right(x)
+    }
+}
+
 if_std! {
     use std::boxed::Box;
+    use std::cell::{Cell, RefCell};
     use std::collections::*;
     use std::hash::Hash;
-    use std::vec;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex, RwLock};
     use std::vec::Vec;
+    use std::vec;
 
     impl<A> Pure for Box<A> {
         #[inline]
@@ -64,6 +120,48 @@ if_std! {
         }
     }
 
+    impl<A> Pure for Rc<A> {
+        #[inline]
+        fn pure(x: A) -> Self {
+            Rc::new(x)
+        }
+    }
+
+    impl<A> Pure for Arc<A> {
+        #[inline]
+        fn pure(x: A) -> Self {
+            Arc::new(x)
+        }
+    }
+
+    impl<A> Pure for Cell<A> {
+        #[inline]
+        fn pure(x: A) -> Self {
+            Cell::new(x)
+        }
+    }
+
+    impl<A> Pure for RefCell<A> {
+        #[inline]
+        fn pure(x: A) -> Self {
+            RefCell::new(x)
+        }
+    }
+
+    impl<A> Pure for Mutex<A> {
+        #[inline]
+        fn pure(x: A) -> Self {
+            Mutex::new(x)
+        }
+    }
+
+    impl<A> Pure for RwLock<A> {
+        #[inline]
+        fn pure(x: A) -> Self {
+            RwLock::new(x)
+        }
+    }
+
     impl<A> Pure for Vec<A> {
         #[inline]
         fn pure(x: A) -> Self {
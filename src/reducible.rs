@@ -0,0 +1,202 @@
+//! Reducible.
+//!
+//! Folding over non-empty structures. Since there is always at least one element, a reduction
+//! doesn't need an initial value: the first element is used instead. This allows combining the
+//! elements with a [Semigroup] without requiring a [Monoid].
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! assert_eq!(6, ne_vec![1, 2, 3].reduce());
+//! assert_eq!(3, ne_vec![1, 3, 2].reduce_left(Ord::max));
+//! assert_eq!("1, 2, 3", ne_vec![1, 2, 3].reduce_left_to(|x| x.to_string(), |acc, x| format!("{acc}, {x}")));
+//! ```
+//!
+//! [Monoid]: crate::monoid::Monoid
+
+use crate::higher::Higher;
+use crate::semigroup::Semigroup;
+
+/// Fold a non-empty structure without an initial value.
+/// See [the module level documentation](self) for more.
+pub trait Reducible: Higher {
+    /// Left-associative fold, turning the first element into the initial value with `f` and then
+    /// folding the remaining elements with `g`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let actual = ne_vec!["a", "bb", "ccc"].reduce_left_to(|s| s.len(), |acc, s| acc + s.len());
+    /// assert_eq!(6, actual);
+    /// ```
+    fn reduce_left_to<B>(
+        self,
+        f: impl FnOnce(Self::Param) -> B,
+        g: impl FnMut(B, Self::Param) -> B,
+    ) -> B;
+
+    /// Left-associative fold using the first element as the initial value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(-4, ne_vec![1, 2, 3].reduce_left(|x, y| x - y));
+    /// ```
+    #[inline]
+    fn reduce_left(self, f: impl FnMut(Self::Param, Self::Param) -> Self::Param) -> Self::Param
+    where
+        Self: Sized,
+    {
+        self.reduce_left_to(|a| a, f)
+    }
+
+    /// Combine all the elements using their [Semigroup] instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!("abc", ne_vec!["a".to_string(), "b".to_string(), "c".to_string()].reduce());
+    /// ```
+    #[inline]
+    fn reduce(self) -> Self::Param
+    where
+        Self: Sized,
+        Self::Param: Semigroup,
+    {
+        self.reduce_left(Semigroup::combine)
+    }
+
+    /// Map each element to a [Semigroup] and combine the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(vec![1, 1, 2, 2], ne_vec![1, 2].reduce_map(|x| vec![x, x]));
+    /// ```
+    #[inline]
+    fn reduce_map<B: Semigroup>(self, f: impl FnMut(Self::Param) -> B) -> B
+    where
+        Self: Sized,
+    {
+        let (b, _) = self.reduce_left_to(
+            |a| {
+                let mut f = f;
+                (f(a), f)
+            },
+            |(b, mut f), a| (b.combine(f(a)), f),
+        );
+        b
+    }
+
+    /// Returns the maximum element. If several elements are equally maximum, the last one is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(3, ne_vec![1, 3, 2].maximum());
+    /// ```
+    #[inline]
+    fn maximum(self) -> Self::Param
+    where
+        Self: Sized,
+        Self::Param: Ord,
+    {
+        self.reduce_left(Ord::max)
+    }
+
+    /// Returns the minimum element. If several elements are equally minimum, the first one is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(1, ne_vec![2, 1, 3].minimum());
+    /// ```
+    #[inline]
+    fn minimum(self) -> Self::Param
+    where
+        Self: Sized,
+        Self::Param: Ord,
+    {
+        self.reduce_left(Ord::min)
+    }
+
+    /// Returns the element that gives the maximum value from the specified function. If several
+    /// elements are equally maximum, the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!("ccc", ne_vec!["a", "ccc", "bb"].maximum_by_key(|s| s.len()));
+    /// ```
+    #[inline]
+    fn maximum_by_key<K: Ord>(self, f: impl FnMut(&Self::Param) -> K) -> Self::Param
+    where
+        Self: Sized,
+    {
+        let (_, a, _) = self.reduce_left_to(
+            |a| {
+                let mut f = f;
+                (f(&a), a, f)
+            },
+            |(k, a, mut f), b| {
+                let kb = f(&b);
+                if kb >= k {
+                    (kb, b, f)
+                } else {
+                    (k, a, f)
+                }
+            },
+        );
+        a
+    }
+
+    /// Returns the element that gives the minimum value from the specified function. If several
+    /// elements are equally minimum, the first one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!("a", ne_vec!["ccc", "a", "bb"].minimum_by_key(|s| s.len()));
+    /// ```
+    #[inline]
+    fn minimum_by_key<K: Ord>(self, f: impl FnMut(&Self::Param) -> K) -> Self::Param
+    where
+        Self: Sized,
+    {
+        let (_, a, _) = self.reduce_left_to(
+            |a| {
+                let mut f = f;
+                (f(&a), a, f)
+            },
+            |(k, a, mut f), b| {
+                let kb = f(&b);
+                if kb < k {
+                    (kb, b, f)
+                } else {
+                    (k, a, f)
+                }
+            },
+        );
+        a
+    }
+}
@@ -1,6 +1,14 @@
 //! Semigroup.
 
+use core::cmp::Ordering;
 use core::marker::PhantomData;
+use core::num::{
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+use core::time::Duration;
+
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 /// A Semigroup is an algebraic structure consisting of a set together with an associative binary
 /// operation. A Semigroup is a Monoid without an identity element.
@@ -19,6 +27,9 @@ pub trait Semigroup {
 
     /// Combine with itself `n` times.
     ///
+    /// For `n > 2` the copies are combined by repeated squaring, which takes `O(log n)` calls to
+    /// [combine](Semigroup::combine) instead of `n`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -28,6 +39,8 @@ pub trait Semigroup {
     /// assert_eq!(2, 1.combine_n(1));
     /// assert_eq!(3, 1.combine_n(2));
     /// assert_eq!(4, Semigroup::combine_n(1, 3));
+    /// # #[cfg(feature = "std")]
+    /// assert_eq!(Some("ab".repeat(8)), Some("ab".to_string()).combine_n(7));
     /// ```
     #[inline]
     fn combine_n(self, n: u32) -> Self
@@ -38,12 +51,34 @@ pub trait Semigroup {
             return self;
         }
 
-        let mut result = self.clone();
-        for _ in 1..n {
-            result = result.combine(self.clone());
+        if n <= 2 {
+            let mut result = self.clone();
+            for _ in 1..n {
+                result = result.combine(self.clone());
+            }
+
+            return result.combine(self);
         }
 
-        result.combine(self)
+        // Combine the powers of two of `self` selected by the bits of the number of copies.
+        let mut copies = u64::from(n) + 1;
+        let mut power = self;
+        let mut result: Option<Self> = None;
+        loop {
+            if copies & 1 == 1 {
+                result = Some(match result {
+                    Some(result) => result.combine(power.clone()),
+                    None => power.clone(),
+                });
+            }
+            copies >>= 1;
+            if copies == 0 {
+                break;
+            }
+            power = power.clone().combine(power);
+        }
+
+        result.unwrap_or(power)
     }
 
     /// Combine all values in the iterator and return the total.
@@ -75,6 +110,22 @@ pub trait Semigroup {
     }
 }
 
+/// A commutative semigroup is a [Semigroup] whose operation is also commutative, i.e.
+/// `a.combine(b) == b.combine(a)`. This is a marker trait that doesn't add any methods.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// fn combine_any_order<T: CommutativeSemigroup + Clone>(a: T, b: T) -> T {
+///     b.combine(a)
+/// }
+///
+/// assert_eq!(3, combine_any_order(1, 2));
+/// ```
+pub trait CommutativeSemigroup: Semigroup {}
+
 /// Macro to implement [Semigroup] for numeric types.
 macro_rules! semigroup_numeric {
     ($($t:ty)*) => ($(
@@ -82,16 +133,77 @@ macro_rules! semigroup_numeric {
             #[inline]
             fn combine(self, other: Self) -> Self { self + other }
         }
+
+        impl CommutativeSemigroup for $t {}
     )*)
 }
 
 semigroup_numeric! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
 
+/// Macro to implement [Semigroup] for integer types with an explicit overflow behavior.
+macro_rules! semigroup_overflow {
+    (wrapping $($t:ty)*) => ($(
+        /// Wrapping addition, which never panics on overflow.
+        impl Semigroup for Wrapping<$t> {
+            #[inline]
+            fn combine(self, other: Self) -> Self { self + other }
+        }
+
+        impl CommutativeSemigroup for Wrapping<$t> {}
+    )*);
+    (saturating $($t:ty)*) => ($(
+        /// Saturating addition, which never panics on overflow.
+        impl Semigroup for Saturating<$t> {
+            #[inline]
+            fn combine(self, other: Self) -> Self { self + other }
+        }
+
+        impl CommutativeSemigroup for Saturating<$t> {}
+    )*);
+    (non_zero $($t:ty)*) => ($(
+        /// Saturating multiplication, which never panics on overflow.
+        impl Semigroup for $t {
+            #[inline]
+            fn combine(self, other: Self) -> Self { self.saturating_mul(other) }
+        }
+
+        impl CommutativeSemigroup for $t {}
+    )*);
+}
+
+semigroup_overflow! { wrapping usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+// Saturating addition of signed integers is not associative, e.g. `(100 + 100) - 100 != 100`
+// for `i8`, so only unsigned integers are lawful semigroups.
+semigroup_overflow! { saturating usize u8 u16 u32 u64 u128 }
+semigroup_overflow! {
+    non_zero NonZeroUsize NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128
+}
+
 impl Semigroup for () {
     #[inline]
     fn combine(self, _other: Self) -> Self {}
 }
 
+impl CommutativeSemigroup for () {}
+
+/// Lexicographic combination: the first non-[Equal](Ordering::Equal) ordering wins.
+impl Semigroup for Ordering {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+/// Saturating addition, which never panics on overflow.
+impl Semigroup for Duration {
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        self.saturating_add(other)
+    }
+}
+
+impl CommutativeSemigroup for Duration {}
+
 macro_rules! semigroup_tuple {
     ($($idx:tt $t:tt),+) => {
         impl<$($t: Semigroup,)*> Semigroup for ($($t,)+)
@@ -103,6 +215,8 @@ macro_rules! semigroup_tuple {
                 )+)
             }
         }
+
+        impl<$($t: CommutativeSemigroup,)*> CommutativeSemigroup for ($($t,)+) {}
     };
 }
 
@@ -172,6 +286,8 @@ impl<T> Semigroup for PhantomData<T> {
     }
 }
 
+impl<T> CommutativeSemigroup for PhantomData<T> {}
+
 impl<T: Semigroup> Semigroup for Option<T> {
     #[inline]
     fn combine(self, other: Self) -> Self {
@@ -182,11 +298,25 @@ impl<T: Semigroup> Semigroup for Option<T> {
     }
 }
 
+impl<T: CommutativeSemigroup> CommutativeSemigroup for Option<T> {}
+
+#[cfg(feature = "smallvec")]
+impl<T, const N: usize> Semigroup for SmallVec<[T; N]> {
+    #[inline]
+    fn combine(mut self, other: Self) -> Self {
+        self.extend(other);
+        self
+    }
+}
+
 if_std! {
     use std::boxed::Box;
+    use std::cell::{Cell, RefCell};
     use std::collections::*;
     use std::hash::Hash;
+    use std::rc::Rc;
     use std::string::String;
+    use std::sync::{Arc, Mutex, PoisonError, RwLock};
     use std::vec::Vec;
 
     impl Semigroup for String {
@@ -194,6 +324,44 @@ if_std! {
         fn combine(self, other: Self) -> Self {
             self + &other
         }
+
+        /// Concatenates all strings, allocating the total length once.
+        #[inline]
+        fn combine_all_option<I>(iter: I) -> Option<Self>
+        where
+            I: IntoIterator<Item = Self>,
+        {
+            let strings: Vec<String> = iter.into_iter().collect();
+            let additional = strings.iter().skip(1).map(String::len).sum();
+            let mut strings = strings.into_iter();
+            let mut result = strings.next()?;
+            result.reserve(additional);
+            strings.for_each(|s| result.push_str(&s));
+            Some(result)
+        }
+    }
+
+    impl<T> Semigroup for Vec<T> {
+        #[inline]
+        fn combine(mut self, other: Self) -> Self {
+            self.extend(other);
+            self
+        }
+
+        /// Concatenates all vectors, allocating the total length once.
+        #[inline]
+        fn combine_all_option<I>(iter: I) -> Option<Self>
+        where
+            I: IntoIterator<Item = Self>,
+        {
+            let vecs: Vec<Vec<T>> = iter.into_iter().collect();
+            let additional = vecs.iter().skip(1).map(Vec::len).sum();
+            let mut vecs = vecs.into_iter();
+            let mut result = vecs.next()?;
+            result.reserve(additional);
+            vecs.for_each(|mut v| result.append(&mut v));
+            Some(result)
+        }
     }
 
     impl<T: Semigroup> Semigroup for Box<T> {
@@ -203,31 +371,109 @@ if_std! {
         }
     }
 
-    semigroup_extend!(Vec);
+    impl<T: Semigroup + Clone> Semigroup for Rc<T> {
+        #[inline]
+        fn combine(self, other: Self) -> Self {
+            Rc::new(Rc::unwrap_or_clone(self).combine(Rc::unwrap_or_clone(other)))
+        }
+    }
+
+    impl<T: Semigroup + Clone> Semigroup for Arc<T> {
+        #[inline]
+        fn combine(self, other: Self) -> Self {
+            Arc::new(Arc::unwrap_or_clone(self).combine(Arc::unwrap_or_clone(other)))
+        }
+    }
+
+    impl<T: Semigroup> Semigroup for Cell<T> {
+        #[inline]
+        fn combine(self, other: Self) -> Self {
+            Cell::new(self.into_inner().combine(other.into_inner()))
+        }
+    }
+
+    impl<T: Semigroup> Semigroup for RefCell<T> {
+        #[inline]
+        fn combine(self, other: Self) -> Self {
+            RefCell::new(self.into_inner().combine(other.into_inner()))
+        }
+    }
+
+    /// Poisoning is deliberately ignored: the values are taken out of the locks even if they are
+    /// poisoned, and the combined lock is not poisoned. Check [Mutex::is_poisoned] beforehand if
+    /// a panic may have left a value inconsistent.
+    impl<T: Semigroup> Semigroup for Mutex<T> {
+        #[inline]
+        fn combine(self, other: Self) -> Self {
+            let x = self.into_inner().unwrap_or_else(PoisonError::into_inner);
+            let y = other.into_inner().unwrap_or_else(PoisonError::into_inner);
+            Mutex::new(x.combine(y))
+        }
+    }
+
+    /// Poisoning is deliberately ignored: the values are taken out of the locks even if they are
+    /// poisoned, and the combined lock is not poisoned. Check [RwLock::is_poisoned] beforehand if
+    /// a panic may have left a value inconsistent.
+    impl<T: Semigroup> Semigroup for RwLock<T> {
+        #[inline]
+        fn combine(self, other: Self) -> Self {
+            let x = self.into_inner().unwrap_or_else(PoisonError::into_inner);
+            let y = other.into_inner().unwrap_or_else(PoisonError::into_inner);
+            RwLock::new(x.combine(y))
+        }
+    }
+
     semigroup_append!(LinkedList);
     semigroup_append!(VecDeque);
     semigroup_append!(BinaryHeap, Ord);
     semigroup_append!(BTreeSet, Ord);
     semigroup_extend!(HashSet, Eq + Hash);
 
+    impl<T: CommutativeSemigroup> CommutativeSemigroup for Box<T> {}
+    impl<T: CommutativeSemigroup + Clone> CommutativeSemigroup for Rc<T> {}
+    impl<T: CommutativeSemigroup + Clone> CommutativeSemigroup for Arc<T> {}
+    impl<T: Ord> CommutativeSemigroup for BinaryHeap<T> {}
+    impl<T: Ord> CommutativeSemigroup for BTreeSet<T> {}
+    impl<T: Eq + Hash> CommutativeSemigroup for HashSet<T> {}
+
     impl<K: Eq + Hash, V: Semigroup> Semigroup for HashMap<K, V> {
         #[inline]
         fn combine(self, other: Self) -> Self {
-            let (mut acc, other) = if self.len() > other.len() {
-                (self, other)
+            let (mut acc, other, acc_is_left) = if self.len() > other.len() {
+                (self, other, true)
             } else {
-                (other, self)
+                (other, self, false)
             };
 
             for (k, v) in other {
-                if let Some(v_acc ) = acc.remove(&k){
-                    acc.insert(k, v.combine(v_acc));
-                } else {
-                    acc.insert(k, v);
-                }
+                let v = match acc.remove(&k) {
+                    Some(v_acc) if acc_is_left => v_acc.combine(v),
+                    Some(v_acc) => v.combine(v_acc),
+                    None => v,
+                };
+                acc.insert(k, v);
             }
 
             acc
         }
     }
+
+    impl<K: Eq + Hash, V: CommutativeSemigroup> CommutativeSemigroup for HashMap<K, V> {}
+
+    impl<K: Ord, V: Semigroup> Semigroup for BTreeMap<K, V> {
+        #[inline]
+        fn combine(mut self, other: Self) -> Self {
+            for (k, v) in other {
+                let v = match self.remove(&k) {
+                    Some(v_acc) => v_acc.combine(v),
+                    None => v,
+                };
+                self.insert(k, v);
+            }
+
+            self
+        }
+    }
+
+    impl<K: Ord, V: CommutativeSemigroup> CommutativeSemigroup for BTreeMap<K, V> {}
 }
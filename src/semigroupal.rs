@@ -1,8 +1,16 @@
 //! Semigroupal.
 
 use core::marker::PhantomData;
+use core::ops::ControlFlow;
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
 
 use crate::higher::Higher;
+use crate::semigroup::Semigroup;
 
 /// Semigroupal captures the idea of composing independent effectful values.
 ///
@@ -29,7 +37,33 @@ pub trait Semigroupal<B>: Higher {
     fn product(self, fb: Self::Target<B>) -> Self::Target<(Self::Param, B)>;
 }
 
-/// Macro to implement [Semigroupal] for types with [Iterator] support.
+/// Borrowing cartesian product for collections.
+///
+/// [Semigroupal::product] takes both collections by value, so it has to clone every element of
+/// `self` and `fb` for each pair it builds. [ProductRef::product_ref] pairs references to the
+/// elements instead, without cloning anything. For [Copy] elements, mapping the result with
+/// `|(a, b)| (*a, *b)` gives the same result as [Semigroupal::product].
+pub trait ProductRef<B>: Higher {
+    /// Combine two collections into the collection of all pairs of references to their elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let fa = vec![1, 2];
+    /// let fb = vec!['a', 'b'];
+    /// let actual = fa.product_ref(&fb);
+    /// assert_eq!(vec![(&1, &'a'), (&1, &'b'), (&2, &'a'), (&2, &'b')], actual);
+    ///
+    /// let actual = fa.product_ref(&fb).map(|(a, b)| (*a, *b));
+    /// assert_eq!(fa.product(fb), actual);
+    /// ```
+    fn product_ref<'a>(&'a self, fb: &'a Self::Target<B>)
+        -> Self::Target<(&'a Self::Param, &'a B)>;
+}
+
+/// Macro to implement [Semigroupal] and [ProductRef] for types with [Iterator] support.
 #[macro_export]
 macro_rules! semigroupal_iter {
     ($name:ident) => {
@@ -37,20 +71,34 @@ macro_rules! semigroupal_iter {
             #[inline]
             fn product(self, fb: Self::Target<B>) -> Self::Target<(A, B)> {
                 self.into_iter()
-                    .flat_map(|a| fb.clone().into_iter().map(move |b| (a. clone(), b)))
+                    .flat_map(|a| fb.iter().map(move |b| (a.clone(), b.clone())))
                     .collect()
             }
         }
+
+        impl<A, B> $crate::semigroupal::ProductRef<B> for $name<A> {
+            #[inline]
+            fn product_ref<'a>(&'a self, fb: &'a Self::Target<B>) -> Self::Target<(&'a A, &'a B)> {
+                self.iter().flat_map(|a| fb.iter().map(move |b| (a, b))).collect()
+            }
+        }
     };
     ($name:ident, $ct:tt $(+ $dt:tt )*) => {
         impl<A: Clone + $ct $(+ $dt )*, B: Clone + $ct $(+ $dt )*> $crate::semigroupal::Semigroupal<B> for $name<A> {
             #[inline]
             fn product(self, fb: Self::Target<B>) -> Self::Target<(A, B)> {
                 self.into_iter()
-                    .flat_map(|a| fb.clone().into_iter().map(move |b| (a.clone(), b)))
+                    .flat_map(|a| fb.iter().map(move |b| (a.clone(), b.clone())))
                     .collect()
             }
         }
+
+        impl<A: $ct $(+ $dt )*, B: $ct $(+ $dt )*> $crate::semigroupal::ProductRef<B> for $name<A> {
+            #[inline]
+            fn product_ref<'a>(&'a self, fb: &'a Self::Target<B>) -> Self::Target<(&'a A, &'a B)> {
+                self.iter().flat_map(|a| fb.iter().map(move |b| (a, b))).collect()
+            }
+        }
     };
 }
 
@@ -71,6 +119,27 @@ impl<A, B> Semigroupal<B> for Option<A> {
     }
 }
 
+impl<A, B> Semigroupal<B> for Poll<A> {
+    #[inline]
+    fn product(self, fb: Poll<B>) -> Poll<(A, B)> {
+        match (self, fb) {
+            (Poll::Ready(a), Poll::Ready(b)) => Poll::Ready((a, b)),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl<X, A, B> Semigroupal<B> for ControlFlow<X, A> {
+    #[inline]
+    fn product(self, fb: ControlFlow<X, B>) -> ControlFlow<X, (A, B)> {
+        match (self, fb) {
+            (ControlFlow::Continue(a), ControlFlow::Continue(b)) => ControlFlow::Continue((a, b)),
+            (ControlFlow::Break(x), _) => ControlFlow::Break(x),
+            (_, ControlFlow::Break(x)) => ControlFlow::Break(x),
+        }
+    }
+}
+
 impl<A, B, E> Semigroupal<B> for Result<A, E> {
     #[inline]
     fn product(self, fb: Result<B, E>) -> Result<(A, B), E> {
@@ -82,10 +151,49 @@ impl<A, B, E> Semigroupal<B> for Result<A, E> {
     }
 }
 
+impl<X: Semigroup, A, B> Semigroupal<B> for (X, A) {
+    #[inline]
+    fn product(self, fb: (X, B)) -> (X, (A, B)) {
+        (self.0.combine(fb.0), (self.1, fb.1))
+    }
+}
+
+impl<A, B, const N: usize> Semigroupal<B> for [A; N] {
+    #[inline]
+    fn product(self, fb: [B; N]) -> [(A, B); N] {
+        let mut fb = fb.into_iter();
+        self.map(|a| (a, fb.next().expect("arrays have the same length")))
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: Clone, B: Clone, const N: usize> Semigroupal<B> for SmallVec<[A; N]> {
+    #[inline]
+    fn product(self, fb: SmallVec<[B; N]>) -> SmallVec<[(A, B); N]> {
+        self.into_iter()
+            .flat_map(|a| fb.iter().map(move |b| (a.clone(), b.clone())))
+            .collect()
+    }
+}
+
+#[cfg(feature = "either")]
+impl<L, A, B> Semigroupal<B> for Either<L, A> {
+    #[inline]
+    fn product(self, fb: Either<L, B>) -> Either<L, (A, B)> {
+        match (self, fb) {
+            (Either::Right(a), Either::Right(b)) => Either::Right((a, b)),
+            (Either::Left(l), _) => Either::Left(l),
+            (_, Either::Left(l)) => Either::Left(l),
+        }
+    }
+}
+
 if_std! {
     use std::boxed::Box;
     use std::collections::*;
     use std::hash::Hash;
+    use std::rc::Rc;
+    use std::sync::Arc;
     use std::vec::Vec;
 
     impl<A, B> Semigroupal<B> for Box<A> {
@@ -95,6 +203,20 @@ if_std! {
         }
     }
 
+    impl<A: Clone, B: Clone> Semigroupal<B> for Rc<A> {
+        #[inline]
+        fn product(self, fb: Rc<B>) -> Rc<(A, B)> {
+            Rc::new((Rc::unwrap_or_clone(self), Rc::unwrap_or_clone(fb)))
+        }
+    }
+
+    impl<A: Clone, B: Clone> Semigroupal<B> for Arc<A> {
+        #[inline]
+        fn product(self, fb: Arc<B>) -> Arc<(A, B)> {
+            Arc::new((Arc::unwrap_or_clone(self), Arc::unwrap_or_clone(fb)))
+        }
+    }
+
     semigroupal_iter!(Vec);
     semigroupal_iter!(LinkedList);
     semigroupal_iter!(VecDeque);
@@ -110,4 +232,13 @@ if_std! {
                 .collect()
         }
     }
+
+    impl<A, B, K: Ord> Semigroupal<B> for BTreeMap<K, A> {
+        #[inline]
+        fn product(self, mut fb: BTreeMap<K, B>) -> BTreeMap<K, (A, B)> {
+            self.into_iter()
+                .filter_map(|(k, a)| fb.remove(&k).map(|b| (k, (a, b))))
+                .collect()
+        }
+    }
 }
@@ -0,0 +1,315 @@
+//! Show.
+//!
+//! A typeclass for rendering values as text without relying on [Debug] formatting. Instances are
+//! provided for the primitive types, strings, tuples and standard containers.
+//!
+//! Show instances for custom types can be built contravariantly from an existing instance with
+//! [show_contramap] or from a [Display](core::fmt::Display) implementation with [show_display].
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! struct Celsius(f64);
+//! show_contramap!(Celsius, |c: &Celsius| c.0);
+//!
+//! struct UserId(u32);
+//! impl std::fmt::Display for UserId {
+//!     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+//!         write!(f, "user#{}", self.0)
+//!     }
+//! }
+//! show_display!(UserId);
+//!
+//! assert_eq!("36.6", Celsius(36.6).show());
+//! assert_eq!("[Some(user#1), None]", vec![Some(UserId(1)), None].show());
+//! ```
+//!
+//! [Debug]: core::fmt::Debug
+
+use core::fmt::{Result, Write};
+use core::marker::PhantomData;
+
+/// Typeclass for types which can be rendered as text.
+/// See [the module level documentation](self) for more.
+pub trait Show {
+    /// Write the textual representation of the value to the given writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let mut s = String::new();
+    /// (1, Some('a')).show_to(&mut s).unwrap();
+    /// assert_eq!("(1, Some(a))", s);
+    /// ```
+    fn show_to<W: Write>(&self, w: &mut W) -> Result;
+
+    if_std! {
+        /// Render the value as a [String](std::string::String).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use rust2fun::prelude::*;
+        ///
+        /// assert_eq!("Ok([1, 2])", Ok::<_, ()>(vec![1, 2]).show());
+        /// ```
+        #[inline]
+        fn show(&self) -> std::string::String {
+            let mut s = std::string::String::new();
+            self.show_to(&mut s).expect("a Show implementation returned an error unexpectedly");
+            s
+        }
+    }
+}
+
+/// Macro to implement [Show] for types implementing [Display](core::fmt::Display).
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// struct Name(&'static str);
+///
+/// impl std::fmt::Display for Name {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str(self.0)
+///     }
+/// }
+///
+/// show_display!(Name);
+/// assert_eq!("Alice", Name("Alice").show());
+/// ```
+#[macro_export]
+macro_rules! show_display {
+    ($($t:ty)*) => ($(
+        impl $crate::show::Show for $t {
+            #[inline]
+            fn show_to<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+                write!(w, "{}", self)
+            }
+        }
+    )*)
+}
+
+/// Macro to implement [Show] for a type by transforming its values into a type that already
+/// implements [Show].
+///
+/// # Example
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// show_contramap!(Point, |p: &Point| (p.x, p.y));
+/// assert_eq!("(1, 2)", Point { x: 1, y: 2 }.show());
+/// ```
+#[macro_export]
+macro_rules! show_contramap {
+    ($t:ty, $f:expr) => {
+        impl $crate::show::Show for $t {
+            #[inline]
+            fn show_to<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+                $crate::show::Show::show_to(&($f)(self), w)
+            }
+        }
+    };
+}
+
+show_display! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 bool char str }
+
+/// Write the items separated by commas.
+fn show_items<'a, T: Show + 'a, W: Write>(
+    items: impl IntoIterator<Item = &'a T>,
+    w: &mut W,
+) -> Result {
+    for (i, x) in items.into_iter().enumerate() {
+        if i > 0 {
+            w.write_str(", ")?;
+        }
+        x.show_to(w)?;
+    }
+    Ok(())
+}
+
+impl<T: Show + ?Sized> Show for &T {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        (**self).show_to(w)
+    }
+}
+
+impl<T: Show + ?Sized> Show for &mut T {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        (**self).show_to(w)
+    }
+}
+
+impl<T: Show> Show for [T] {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        w.write_char('[')?;
+        show_items(self, w)?;
+        w.write_char(']')
+    }
+}
+
+impl<T: Show, const N: usize> Show for [T; N] {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        self.as_slice().show_to(w)
+    }
+}
+
+impl Show for () {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        w.write_str("()")
+    }
+}
+
+macro_rules! show_tuple {
+    ($($idx:tt $t:tt),+) => {
+        impl<$($t: Show,)*> Show for ($($t,)+) {
+            #[inline]
+            fn show_to<W: Write>(&self, w: &mut W) -> Result {
+                w.write_char('(')?;
+                let mut _first = true;
+                $(
+                    if !_first {
+                        w.write_str(", ")?;
+                    }
+                    _first = false;
+                    self.$idx.show_to(w)?;
+                )+
+                w.write_char(')')
+            }
+        }
+    };
+}
+
+show_tuple!(0 A);
+show_tuple!(0 A, 1 B);
+show_tuple!(0 A, 1 B, 2 C);
+show_tuple!(0 A, 1 B, 2 C, 3 D);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+show_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
+impl<T> Show for PhantomData<T> {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        w.write_str("PhantomData")
+    }
+}
+
+impl<T: Show> Show for Option<T> {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        match self {
+            Some(x) => {
+                w.write_str("Some(")?;
+                x.show_to(w)?;
+                w.write_char(')')
+            }
+            None => w.write_str("None"),
+        }
+    }
+}
+
+impl<T: Show, E: Show> Show for core::result::Result<T, E> {
+    #[inline]
+    fn show_to<W: Write>(&self, w: &mut W) -> Result {
+        match self {
+            Ok(x) => {
+                w.write_str("Ok(")?;
+                x.show_to(w)?;
+            }
+            Err(e) => {
+                w.write_str("Err(")?;
+                e.show_to(w)?;
+            }
+        }
+        w.write_char(')')
+    }
+}
+
+if_std! {
+    use std::boxed::Box;
+    use std::collections::*;
+    use std::string::String;
+    use std::vec::Vec;
+
+    show_display! { String }
+
+    impl<T: Show + ?Sized> Show for Box<T> {
+        #[inline]
+        fn show_to<W: Write>(&self, w: &mut W) -> Result {
+            (**self).show_to(w)
+        }
+    }
+
+    macro_rules! show_seq {
+        ($name:ident, $open:expr, $close:expr) => {
+            impl<T: Show> Show for $name<T> {
+                #[inline]
+                fn show_to<W: Write>(&self, w: &mut W) -> Result {
+                    w.write_str($open)?;
+                    show_items(self, w)?;
+                    w.write_str($close)
+                }
+            }
+        };
+    }
+
+    show_seq!(Vec, "[", "]");
+    show_seq!(VecDeque, "[", "]");
+    show_seq!(LinkedList, "[", "]");
+    show_seq!(BTreeSet, "{", "}");
+    show_seq!(HashSet, "{", "}");
+
+    impl<K: Show, V: Show> Show for BTreeMap<K, V> {
+        #[inline]
+        fn show_to<W: Write>(&self, w: &mut W) -> Result {
+            show_map(self, w)
+        }
+    }
+
+    impl<K: Show, V: Show, S> Show for HashMap<K, V, S> {
+        #[inline]
+        fn show_to<W: Write>(&self, w: &mut W) -> Result {
+            show_map(self, w)
+        }
+    }
+
+    /// Write the entries of a map as `{k: v, ...}`.
+    fn show_map<'a, K: Show + 'a, V: Show + 'a, W: Write>(
+        entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+        w: &mut W,
+    ) -> Result {
+        w.write_char('{')?;
+        for (i, (k, v)) in entries.into_iter().enumerate() {
+            if i > 0 {
+                w.write_str(", ")?;
+            }
+            k.show_to(w)?;
+            w.write_str(": ")?;
+            v.show_to(w)?;
+        }
+        w.write_char('}')
+    }
+}
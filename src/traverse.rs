@@ -0,0 +1,372 @@
+//! Traverse.
+//!
+//! Traversal over structures with an effect. Each element is mapped to an effectful value and
+//! the effects are combined into a single effect of the whole structure. Since the structure may
+//! be empty, the effect must be able to lift pure values, unlike [NonEmptyTraverse].
+//!
+//! # Examples
+//!
+//! ```
+//! use rust2fun::prelude::*;
+//!
+//! fn parse(s: &str) -> Option<i32> {
+//!     s.parse().ok()
+//! }
+//!
+//! assert_eq!(Some(vec![1, 2, 3]), vec!["1", "2", "3"].traverse(parse));
+//! assert_eq!(None, vec!["1", "foo", "3"].traverse(parse));
+//! assert_eq!(Some(vec![]), Vec::<&str>::new().traverse(parse));
+//! ```
+//!
+//! [NonEmptyTraverse]: crate::non_empty_traverse::NonEmptyTraverse
+
+use core::task::Poll;
+
+#[cfg(feature = "either")]
+use either::Either;
+
+use crate::functor::Functor;
+use crate::higher::Higher;
+use crate::pure::Pure;
+use crate::semigroupal::Semigroupal;
+
+/// Traverse a structure with an effect `GB`.
+/// See [the module level documentation](self) for more.
+pub trait Traverse<GB: Higher>: Higher {
+    /// Apply an effectful function to each element and collect the results in the effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// let actual = vec![1, 2, 3].traverse(|x| if x > 0 { Ok(x) } else { Err(x) });
+    /// assert_eq!(Ok(vec![1, 2, 3]), actual);
+    ///
+    /// let actual = vec![1, -2, -3].traverse(|x| if x > 0 { Ok(x) } else { Err(x) });
+    /// assert_eq!(Err(-2), actual);
+    /// ```
+    fn traverse<F>(self, f: F) -> GB::Target<Self::Target<GB::Param>>
+    where
+        F: FnMut(Self::Param) -> GB;
+
+    /// Turn a structure of effects into an effect of the structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust2fun::prelude::*;
+    ///
+    /// assert_eq!(Some(vec![1, 2]), vec![Some(1), Some(2)].sequence());
+    /// assert_eq!(None, vec![Some(1), None].sequence());
+    /// assert_eq!(vec![Some(1), Some(2)], Some(vec![1, 2]).sequence());
+    /// ```
+    #[inline]
+    fn sequence(self) -> GB::Target<Self::Target<GB::Param>>
+    where
+        Self: Higher<Param = GB> + Sized,
+    {
+        self.traverse(|x| x)
+    }
+}
+
+impl<A, GB> Traverse<GB> for Option<A>
+where
+    GB: Functor<Option<<GB as Higher>::Param>>,
+    GB::Target<Option<<GB as Higher>::Param>>: Pure,
+{
+    #[inline]
+    fn traverse<F>(self, mut f: F) -> GB::Target<Option<GB::Param>>
+    where
+        F: FnMut(A) -> GB,
+    {
+        match self {
+            Some(a) => f(a).map(Some),
+            None => Pure::pure(None),
+        }
+    }
+}
+
+impl<A, GB> Traverse<GB> for Poll<A>
+where
+    GB: Functor<Poll<<GB as Higher>::Param>>,
+    GB::Target<Poll<<GB as Higher>::Param>>: Pure,
+{
+    #[inline]
+    fn traverse<F>(self, mut f: F) -> GB::Target<Poll<GB::Param>>
+    where
+        F: FnMut(A) -> GB,
+    {
+        match self {
+            Poll::Ready(a) => f(a).map(Poll::Ready),
+            Poll::Pending => Pure::pure(Poll::Pending),
+        }
+    }
+}
+
+impl<A, E, GB> Traverse<GB> for Result<A, E>
+where
+    GB: Functor<Result<<GB as Higher>::Param, E>>,
+    GB::Target<Result<<GB as Higher>::Param, E>>: Pure,
+{
+    #[inline]
+    fn traverse<F>(self, mut f: F) -> GB::Target<Result<GB::Param, E>>
+    where
+        F: FnMut(A) -> GB,
+    {
+        match self {
+            Ok(a) => f(a).map(Ok),
+            Err(e) => Pure::pure(Err(e)),
+        }
+    }
+}
+
+/// Apply an effectful function to each element of an iterator, combining the effects and
+/// discarding the results. Unlike [Traverse::traverse] no intermediate collection is built, which
+/// makes it a good fit for running effects only for their errors.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// let check = |x: i32| if x > 0 { Valid(x) } else { Invalid(vec![x]) };
+/// assert_eq!(Valid(()), traverse_(vec![1, 2, 3], check));
+/// assert_eq!(Invalid(vec![-2, -3]), traverse_(vec![1, -2, -3], check));
+/// ```
+#[inline]
+pub fn traverse_<I, GB, F>(iter: I, mut f: F) -> GB::Target<()>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> GB,
+    GB: Higher,
+    GB::Target<()>:
+        Pure + Semigroupal<<GB as Higher>::Param, Param = (), Target<<GB as Higher>::Param> = GB>,
+    <GB::Target<()> as Higher>::Target<((), <GB as Higher>::Param)>:
+        Functor<(), Target<()> = GB::Target<()>>,
+{
+    iter.into_iter().fold(Pure::pure(()), |acc, a| {
+        Functor::map(Semigroupal::product(acc, f(a)), |_| ())
+    })
+}
+
+/// Combine the effects of an iterator of effectful values, discarding the results.
+/// See [traverse_] for more.
+///
+/// # Examples
+///
+/// ```
+/// use rust2fun::prelude::*;
+///
+/// assert_eq!(Some(()), sequence_(vec![Some(1), Some(2)]));
+/// assert_eq!(None, sequence_(vec![Some(1), None]));
+/// ```
+#[inline]
+pub fn sequence_<I, GB>(iter: I) -> GB::Target<()>
+where
+    I: IntoIterator<Item = GB>,
+    GB: Higher,
+    GB::Target<()>:
+        Pure + Semigroupal<<GB as Higher>::Param, Param = (), Target<<GB as Higher>::Param> = GB>,
+    <GB::Target<()> as Higher>::Target<((), <GB as Higher>::Param)>:
+        Functor<(), Target<()> = GB::Target<()>>,
+{
+    traverse_(iter, |x| x)
+}
+
+impl<X: Clone, A, GB> Traverse<GB> for (X, A)
+where
+    GB: Functor<(X, <GB as Higher>::Param)>,
+{
+    #[inline]
+    fn traverse<F>(self, mut f: F) -> GB::Target<(X, GB::Param)>
+    where
+        F: FnMut(A) -> GB,
+    {
+        let (x, a) = self;
+        f(a).map(|b| (x.clone(), b))
+    }
+}
+
+impl<A, GB, const N: usize> Traverse<GB> for [A; N]
+where
+    GB: Higher,
+    GB::Target<[Option<<GB as Higher>::Param>; N]>: Pure<Param = [Option<<GB as Higher>::Param>; N]>
+        + Semigroupal<<GB as Higher>::Param, Target<<GB as Higher>::Param> = GB>
+        + Functor<
+            [<GB as Higher>::Param; N],
+            Target<[<GB as Higher>::Param; N]> = GB::Target<[<GB as Higher>::Param; N]>,
+        >,
+    <GB::Target<[Option<<GB as Higher>::Param>; N]> as Higher>::Target<(
+        [Option<<GB as Higher>::Param>; N],
+        <GB as Higher>::Param,
+    )>: Functor<
+        [Option<<GB as Higher>::Param>; N],
+        Target<[Option<<GB as Higher>::Param>; N]> = GB::Target<[Option<<GB as Higher>::Param>; N]>,
+    >,
+{
+    #[inline]
+    fn traverse<F>(self, mut f: F) -> GB::Target<[GB::Param; N]>
+    where
+        F: FnMut(A) -> GB,
+    {
+        self.into_iter()
+            .enumerate()
+            .fold(
+                Pure::pure(core::array::from_fn(|_| None)),
+                |acc: GB::Target<[Option<GB::Param>; N]>, (i, a)| {
+                    acc.product(f(a)).map(|(mut bs, b)| {
+                        bs[i] = Some(b);
+                        bs
+                    })
+                },
+            )
+            .map(|bs| bs.map(|b| b.expect("all the elements are traversed")))
+    }
+}
+
+// TODO. Refactor this when specialization is stable.
+/// Macro to implement [Traverse] for iterable collections implementing [Default] and [Extend].
+#[macro_export]
+macro_rules! traverse_iter {
+    ($name:ident) => {
+        impl<A, GB> $crate::traverse::Traverse<GB> for $name<A>
+        where
+            GB: $crate::higher::Higher,
+            GB::Target<$name<<GB as $crate::higher::Higher>::Param>>: $crate::pure::Pure
+                + $crate::semigroupal::Semigroupal<
+                    <GB as $crate::higher::Higher>::Param,
+                    Target<<GB as $crate::higher::Higher>::Param> = GB,
+                >,
+            <GB::Target<$name<<GB as $crate::higher::Higher>::Param>> as $crate::higher::Higher>::Target<(
+                $name<<GB as $crate::higher::Higher>::Param>,
+                <GB as $crate::higher::Higher>::Param,
+            )>: $crate::functor::Functor<
+                $name<<GB as $crate::higher::Higher>::Param>,
+                Target<$name<<GB as $crate::higher::Higher>::Param>> = GB::Target<
+                    $name<<GB as $crate::higher::Higher>::Param>,
+                >,
+            >,
+        {
+            #[inline]
+            fn traverse<F>(self, mut f: F) -> GB::Target<$name<GB::Param>>
+            where
+                F: FnMut(A) -> GB,
+            {
+                self.into_iter().fold(
+                    $crate::pure::Pure::pure($name::default()),
+                    |acc, a| {
+                        let acc = $crate::semigroupal::Semigroupal::product(acc, f(a));
+                        $crate::functor::Functor::map(acc, |(mut bs, b): ($name<_>, _)| {
+                            bs.extend(core::iter::once(b));
+                            bs
+                        })
+                    },
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "either")]
+impl<L, A, GB> Traverse<GB> for Either<L, A>
+where
+    GB: Functor<Either<L, <GB as Higher>::Param>>,
+    GB::Target<Either<L, <GB as Higher>::Param>>: Pure,
+{
+    #[inline]
+    fn traverse<F>(self, mut f: F) -> GB::Target<Either<L, GB::Param>>
+    where
+        F: FnMut(A) -> GB,
+    {
+        match self {
+            Either::Right(a) => f(a).map(Either::Right),
+            Either::Left(l) => Pure::pure(Either::Left(l)),
+        }
+    }
+}
+
+if_std! {
+    use std::boxed::Box;
+    use std::collections::*;
+    use std::vec::Vec;
+
+    traverse_iter!(Vec);
+    traverse_iter!(VecDeque);
+    traverse_iter!(LinkedList);
+
+    impl<K, A, GB> Traverse<GB> for HashMap<K, A>
+    where
+        K: Eq + core::hash::Hash + Clone,
+        GB: Higher,
+        GB::Target<HashMap<K, <GB as Higher>::Param>>:
+            Pure + Semigroupal<<GB as Higher>::Param, Target<<GB as Higher>::Param> = GB>,
+        <GB::Target<HashMap<K, <GB as Higher>::Param>> as Higher>::Target<(
+            HashMap<K, <GB as Higher>::Param>,
+            <GB as Higher>::Param,
+        )>: Functor<
+            HashMap<K, <GB as Higher>::Param>,
+            Target<HashMap<K, <GB as Higher>::Param>> = GB::Target<
+                HashMap<K, <GB as Higher>::Param>,
+            >,
+        >,
+    {
+        #[inline]
+        fn traverse<F>(self, mut f: F) -> GB::Target<HashMap<K, GB::Param>>
+        where
+            F: FnMut(A) -> GB,
+        {
+            self.into_iter()
+                .fold(Pure::pure(HashMap::new()), |acc, (k, a)| {
+                    acc.product(f(a)).map(|(mut bs, b)| {
+                        bs.insert(k.clone(), b);
+                        bs
+                    })
+                })
+        }
+    }
+
+    impl<K, A, GB> Traverse<GB> for BTreeMap<K, A>
+    where
+        K: Ord + Clone,
+        GB: Higher,
+        GB::Target<BTreeMap<K, <GB as Higher>::Param>>:
+            Pure + Semigroupal<<GB as Higher>::Param, Target<<GB as Higher>::Param> = GB>,
+        <GB::Target<BTreeMap<K, <GB as Higher>::Param>> as Higher>::Target<(
+            BTreeMap<K, <GB as Higher>::Param>,
+            <GB as Higher>::Param,
+        )>: Functor<
+            BTreeMap<K, <GB as Higher>::Param>,
+            Target<BTreeMap<K, <GB as Higher>::Param>> = GB::Target<
+                BTreeMap<K, <GB as Higher>::Param>,
+            >,
+        >,
+    {
+        #[inline]
+        fn traverse<F>(self, mut f: F) -> GB::Target<BTreeMap<K, GB::Param>>
+        where
+            F: FnMut(A) -> GB,
+        {
+            self.into_iter()
+                .fold(Pure::pure(BTreeMap::new()), |acc, (k, a)| {
+                    acc.product(f(a)).map(|(mut bs, b)| {
+                        bs.insert(k.clone(), b);
+                        bs
+                    })
+                })
+        }
+    }
+
+    impl<A, GB> Traverse<GB> for Box<A>
+    where
+        GB: Functor<Box<<GB as Higher>::Param>>,
+    {
+        #[inline]
+        fn traverse<F>(self, mut f: F) -> GB::Target<Box<GB::Param>>
+        where
+            F: FnMut(A) -> GB,
+        {
+            f(*self).map(Box::new)
+        }
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "proptest-support")]
+
+use proptest::prelude::*;
+
+use rust2fun::arbitrary::*;
+use rust2fun::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_ne_vec(xs in ne_vec(any::<u8>(), 0..4)) {
+        prop_assert!((1..4).contains(&xs.len()));
+    }
+
+    #[test]
+    fn test_ne_chain(xs in ne_chain(any::<u8>(), 2..=3)) {
+        prop_assert!((2..=3).contains(&xs.len()));
+    }
+
+    #[test]
+    fn test_validated(x in validated(Just(1), Just("error"))) {
+        prop_assert!(x == Valid(1) || x == Invalid("error"));
+    }
+
+    #[test]
+    fn test_ior(x in ior(Just(1), Just("right"))) {
+        prop_assert!(x.left().into_iter().all(|a| a == 1));
+        prop_assert!(x.right().into_iter().all(|b| b == "right"));
+    }
+
+    #[test]
+    fn test_arbitrary(
+        xs: NEVec<u8>,
+        ys: NEChain<u8>,
+        fa: Validated<u8, String>,
+        fb: Ior<u8, String>,
+    ) {
+        prop_assert!(!xs.is_empty());
+        prop_assert!(ys.len() >= 1);
+        prop_assert_eq!(fa.is_valid(), fa.valid().is_some());
+        prop_assert!(fb.clone().left().is_some() || fb.right().is_some());
+    }
+}
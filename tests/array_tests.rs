@@ -0,0 +1,92 @@
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+
+use rust2fun_laws::applicative_laws::*;
+use rust2fun_laws::apply_laws::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::traverse_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_invariant(fa: [bool; 3]) {
+        prop_assert!(invariant_identity(fa).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa: [bool; 3]) {
+        prop_assert!(covariant_identity(fa).holds());
+        prop_assert!(covariant_composition(fa, print, parse::<bool>).holds());
+        prop_assert!(lift_identity(fa).holds());
+        prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+    }
+
+    #[test]
+    fn test_semigroupal(fa: [bool; 3], fb: [i32; 3], fc: [Result<String, u8>; 3]) {
+        prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+    }
+
+    #[test]
+    fn test_apply(fa: [String; 3], fb: [usize; 3]) {
+        prop_assert!(map2_product_consistency(fa.clone(), fb, |a, b| a.len() == b).holds());
+        prop_assert!(product_r_consistency(fa.clone(), fb).holds());
+        prop_assert!(product_l_consistency(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_applicative(fa: [bool; 3], a: bool) {
+        prop_assert!(applicative_identity(fa).holds());
+        prop_assert!(applicative_homomorphism::<[_; 3], _, _>(a, print).holds());
+        prop_assert!(applicative_map(fa, print).holds());
+        prop_assert!(ap_product_consistent(fa, [print; 3]).holds());
+        prop_assert!(applicative_unit::<[_; 3]>(a).holds());
+    }
+
+    #[test]
+    fn test_flatmap(fa: [bool; 3]) {
+        prop_assert!(flat_map_associativity(fa, |x| [print(x), print(!x), print(x)], |s| [parse::<bool>(s.clone()); 3]).holds());
+        prop_assert!(flat_map_consistent_apply(fa, [print; 3]).holds());
+        prop_assert!(m_product_consistency(fa, |x| [print(x), print(!x), print(x)]).holds());
+    }
+
+    #[test]
+    fn test_monad(a: bool, fa: [bool; 3]) {
+        prop_assert!(monad_left_identity::<[_; 3], _, _>(a, |x| [print(x), print(!x), print(x)]).holds());
+        prop_assert!(monad_right_identity(fa).holds());
+        prop_assert!(map_flat_map_coherence(fa, print).holds());
+    }
+
+    #[test]
+    fn test_traverse(fa: [bool; 3]) {
+        prop_assert!(traverse_identity(fa, print).holds());
+    }
+}
+
+#[test]
+fn test_zip() {
+    assert_eq!([(1, 'a'), (2, 'b')], [1, 2].product(['a', 'b']));
+    assert_eq!([2, 20], [|x| x + 1, |x| x * 10].ap([1, 2]));
+    assert_eq!([1, 4], [1, 2].flat_map(|x| [x, x * x]));
+}
+
+#[test]
+fn test_traverse_effects() {
+    let f = |x: i32| if x > 0 { Some(x) } else { None };
+    assert_eq!(Some([1, 2, 3]), [1, 2, 3].traverse(f));
+    assert_eq!(None, [1, -2, 3].traverse(f));
+    assert_eq!(
+        vec![[1, 2], [1, -2], [-1, 2], [-1, -2]],
+        [1, 2].traverse(|x| vec![x, -x])
+    );
+}
@@ -0,0 +1,132 @@
+mod common;
+
+if_std! {
+    use rust2fun::prelude::*;
+
+    fn half(x: i32) -> Option<i32> {
+        if x % 2 == 0 {
+            Some(x / 2)
+        } else {
+            None
+        }
+    }
+
+    #[test]
+    fn test_match_braced_arms() {
+        let actual = |x| bind! {
+            for y in half(x);
+            match y {
+                0 => {
+                    for z in None::<i32>;
+                    z
+                }
+                n if n < 10 => {
+                    for z in half(n);
+                    z + 100
+                }
+                _ => {
+                    y * 2
+                }
+            }
+        };
+        assert_eq!(None, actual(0));
+        assert_eq!(None, actual(1));
+        assert_eq!(Some(102), actual(8));
+        assert_eq!(None, actual(14));
+        assert_eq!(Some(40), actual(40));
+    }
+
+    #[test]
+    fn test_match_plain_arms() {
+        let actual = |x| bind! {
+            for y in half(x);
+            match y {
+                0 => -1,
+                1 | 2 => y * 10,
+                _ => y
+            }
+        };
+        assert_eq!(Some(-1), actual(0));
+        assert_eq!(Some(10), actual(2));
+        assert_eq!(Some(20), actual(4));
+        assert_eq!(Some(5), actual(10));
+        assert_eq!(None, actual(3));
+    }
+
+    #[test]
+    fn test_match_mixed_arms() {
+        let actual = |x| bind! {
+            for y in half(x);
+            match y {
+                0 => -1,
+                n if n % 2 == 0 => {
+                    for z in half(n);
+                    z
+                }
+                1 | 3 => y * 10,
+                _ => {
+                    y + 100
+                },
+            }
+        };
+        assert_eq!(Some(-1), actual(0));
+        assert_eq!(Some(10), actual(2));
+        assert_eq!(Some(2), actual(8));
+        assert_eq!(Some(30), actual(6));
+        assert_eq!(Some(105), actual(10));
+        assert_eq!(None, actual(7));
+    }
+
+    #[test]
+    fn test_lift() {
+        type Writer<A> = WriterT<Option<(String, A)>, String, A>;
+
+        let actual = bind! {
+            for x in Writer::new(Some(("a".to_string(), 1)));
+            for y in lift::<Writer<_>>(Some(x + 1));
+            for _ in Writer::<()>::tell("b".to_string());
+            x + y
+        };
+        assert_eq!(Some(("ab".to_string(), 3)), actual.run());
+
+        let actual = bind! {
+            for x in lift::<Writer<i32>>(None);
+            x
+        };
+        assert_eq!(None, actual.run());
+    }
+
+    #[test]
+    fn test_from() {
+        let actual = bind! {
+            for x in from::<NEVec<_>>([1, 2]);
+            for y in from::<NEVec<_>>([x, x * 10]);
+            y
+        };
+        assert_eq!(ne_vec![1, 10, 2, 20], actual);
+    }
+
+    #[test]
+    fn test_inherent() {
+        let actual = bind! {
+            for x in inherent(IO::delay(|| 1));
+            for y in inherent(IO::pure(x + 1));
+            x + y
+        };
+        assert_eq!(3, actual.run());
+    }
+
+    fn from<T>(x: T) -> Option<T> {
+        Some(x)
+    }
+
+    #[test]
+    fn test_function_named_from() {
+        let actual = bind! {
+            for x in self::from::<i32>(1);
+            for y in self::from(x + 1);
+            x + y
+        };
+        assert_eq!(Some(3), actual);
+    }
+}
@@ -5,6 +5,7 @@ if_std! {
 
     use rust2fun_laws::applicative_laws::*;
     use rust2fun_laws::apply_laws::*;
+    use rust2fun_laws::comonad_laws::*;
     use rust2fun_laws::flatmap_laws::*;
     use rust2fun_laws::functor_laws::*;
     use rust2fun_laws::invariant_laws::*;
@@ -76,4 +77,26 @@ if_std! {
         assert!(monad_right_identity(Box::new(1)).holds());
         assert!(map_flat_map_coherence(Box::new(1), |x| x + 1).holds());
     }
+
+    #[test]
+    fn test_comonad() {
+        assert!(comonad_left_identity(Box::new(1)).holds());
+        assert!(comonad_right_identity(Box::new(1), |x| *x + 1).holds());
+        assert!(coflat_map_associativity(Box::new(1), |x| *x + 1, |x| print(*x)).holds());
+    }
+
+    #[test]
+    fn test_boxed_iterator() {
+        // The prelude must not make the Iterator methods of a boxed iterator ambiguous.
+        #[allow(unused_imports)]
+        use rust2fun::prelude::*;
+
+        let it: Box<std::vec::IntoIter<i32>> = Box::new(vec![1, 2, 3].into_iter());
+        assert_eq!(Some(6), it.reduce(|a, b| a + b));
+
+        let mut seen = Vec::new();
+        let it: Box<std::vec::IntoIter<i32>> = Box::new(vec![1, 2].into_iter());
+        assert_eq!(3, it.inspect(|x| seen.push(*x)).sum::<i32>());
+        assert_eq!(vec![1, 2], seen);
+    }
 }
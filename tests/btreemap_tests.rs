@@ -0,0 +1,139 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use std::collections::BTreeMap;
+
+    use proptest::prelude::*;
+
+    use rust2fun::data::{Invalid, Ior, Valid};
+    use rust2fun::semigroup::Semigroup;
+    use rust2fun::traverse::Traverse;
+
+    use rust2fun_laws::align_laws::*;
+    use rust2fun_laws::apply_laws::*;
+    use rust2fun_laws::bifunctor_laws::*;
+    use rust2fun_laws::flatmap_laws::*;
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::invariant_laws::*;
+    use rust2fun_laws::monoid_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+    use rust2fun_laws::semigroupal_laws::*;
+    use rust2fun_laws::strategies::small_btree_map;
+    use rust2fun_laws::traverse_laws::*;
+
+    use crate::common::{parse, print};
+
+    proptest! {
+        #[test]
+        fn test_invariant(fa: BTreeMap::<i32, bool>) {
+            prop_assert!(invariant_identity(fa.clone()).holds());
+            prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+        }
+
+        #[test]
+        fn test_functor(fa: BTreeMap::<i32, bool>) {
+            prop_assert!(covariant_identity(fa.clone()).holds());
+            prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+            prop_assert!(lift_identity(fa.clone()).holds());
+            prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+        }
+
+        #[test]
+        fn test_align(fa: BTreeMap::<i32, bool>, fb: BTreeMap::<i32, String>) {
+            prop_assert!(align_idempotency(fa.clone()).holds());
+            prop_assert!(align_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert!(align_with_consistency(fa, fb, Ior::pad).holds());
+        }
+
+        #[test]
+        fn test_bifunctor(fa: BTreeMap::<i32, bool>) {
+            prop_assert!(bifunctor_identity(fa.clone()).holds());
+            prop_assert!(bifunctor_composition(fa, print, parse::<i32>, print, parse::<bool>).holds());
+        }
+
+        #[test]
+        fn test_semigroupal(fa: BTreeMap::<i32, bool>, fb:BTreeMap::<i32, usize>, fc: BTreeMap<i32, Result<String, u8>>) {
+            prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+        }
+
+        #[test]
+        fn test_apply(fa: BTreeMap<i32, String>, fb: BTreeMap<i32, usize>) {
+            prop_assert!(map2_product_consistency(fa.clone(), fb.clone(), |a, b| a.len() == b).holds());
+            prop_assert!(product_r_consistency(fa.clone(), fb.clone()).holds());
+            prop_assert!(product_l_consistency(fa, fb).holds());
+        }
+
+        #[test]
+        fn test_monoid(fa: BTreeMap<i32, String>) {
+            prop_assert!(monoid_left_identity(fa.clone()).holds());
+            prop_assert!(monoid_right_identity(fa.clone()).holds());
+            prop_assert!(is_id(fa).holds());
+        }
+
+        #[test]
+        fn test_overlapping_keys(fa in small_btree_map(any::<bool>()), fb in small_btree_map(any::<String>()), fc in small_btree_map(any::<String>())) {
+            prop_assert!(align_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert!(semigroupal_associativity(fa.clone(), fb.clone(), fc.clone()).holds());
+            prop_assert!(map2_product_consistency(fb.clone(), fa, |s, b| s.len() > 3 && b).holds());
+            prop_assert!(semigroup_associativity(fb.clone(), fc.clone(), fb.clone()).holds());
+            prop_assert!(semigroup_commutativity(fb.len(), fc.len()).holds());
+        }
+
+        #[test]
+        fn test_traverse(fa: BTreeMap<i8, i8>) {
+            prop_assert!(traverse_identity(fa.clone(), print).holds());
+
+            let errors = fa.values().filter(|x| **x < 0).count();
+            let actual = fa.clone().traverse(|x| {
+                if x < 0 { Invalid(vec![x]) } else { Valid(x) }
+            });
+            if errors == 0 {
+                prop_assert_eq!(Valid(fa), actual);
+            } else {
+                prop_assert_eq!(errors, actual.unwrap_err().len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_semigroup() {
+        let mut fa = BTreeMap::new();
+        fa.insert(0, "a".to_owned());
+        fa.insert(2, "a".to_owned());
+        let mut fb = BTreeMap::new();
+        fb.insert(0, "b".to_owned());
+        fb.insert(1, "a".to_owned());
+        let fc = BTreeMap::new();
+
+        assert!(repeat_0(fa.clone()).holds());
+        assert!(repeat_1(fb.clone()).holds());
+        assert!(semigroup_associativity(fa.clone(), fb.clone(), fc).holds());
+
+        let expected = BTreeMap::from([
+            (0, "ab".to_owned()),
+            (1, "a".to_owned()),
+            (2, "a".to_owned()),
+        ]);
+        assert_eq!(expected, fa.combine(fb));
+    }
+
+    #[test]
+    fn test_flatmap() {
+        assert!(flat_map_associativity(
+            BTreeMap::from([(1, 1)]),
+            |x| BTreeMap::from([(1, print(x))]),
+            |x| BTreeMap::from([(1, parse::<i32>(x))])
+        )
+        .holds());
+
+        assert!(
+            flat_map_consistent_apply(BTreeMap::from([(1, 1)]), BTreeMap::from([(1, print)])).holds()
+        );
+
+        assert!(
+            m_product_consistency(BTreeMap::from([(1, 1)]), |x| BTreeMap::from([(1, print(x))])).holds()
+        );
+    }
+}
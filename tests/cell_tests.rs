@@ -0,0 +1,73 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use std::cell::{Cell, RefCell};
+    use std::sync::{Mutex, RwLock};
+
+    use rust2fun::prelude::*;
+
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::invariant_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+
+    use crate::common::{parse, print};
+
+    #[test]
+    fn test_invariant() {
+        assert!(invariant_identity(Cell::new(1)).holds());
+        assert!(invariant_identity(RefCell::new("id")).holds());
+        assert!(invariant_composition(RefCell::new(1), print, parse, parse::<i32>, print).holds());
+    }
+
+    #[test]
+    fn test_functor() {
+        assert!(covariant_identity(Cell::new(1)).holds());
+        assert!(covariant_identity(RefCell::new(1)).holds());
+        assert!(covariant_composition(RefCell::new(1), print, parse::<u32>).holds());
+        assert!(lift_identity(RefCell::new(1)).holds());
+        assert!(lift_composition(RefCell::new(1), print, parse::<i64>).holds());
+    }
+
+    #[test]
+    fn test_semigroup() {
+        assert!(repeat_0(Cell::new(1)).holds());
+        assert!(repeat_1(RefCell::new("a".to_string())).holds());
+        assert!(semigroup_associativity(
+            RefCell::new("a".to_string()),
+            RefCell::new("b".to_string()),
+            RefCell::new("c".to_string())
+        )
+        .holds());
+    }
+
+    #[test]
+    fn test_locks() {
+        let actual = Mutex::new(1).map(|x| x + 1).combine(Mutex::pure(40));
+        assert_eq!(42, actual.into_inner().unwrap());
+
+        let actual = RwLock::new("foo").map(str::len).imap(|x| x * 2, |x| x / 2);
+        assert_eq!(6, actual.into_inner().unwrap());
+    }
+
+    #[test]
+    fn test_poisoned() {
+        let lock = std::sync::Arc::new(Mutex::new(1));
+        let shared = lock.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = shared.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+
+        let lock = std::sync::Arc::into_inner(lock).unwrap();
+        assert!(lock.is_poisoned());
+        let lock = lock.map(|x| x + 1);
+        assert!(!lock.is_poisoned());
+
+        let combined = lock.combine(Mutex::new(3));
+        assert!(!combined.is_poisoned());
+        assert_eq!(5, combined.into_inner().unwrap());
+    }
+}
@@ -0,0 +1,143 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use rust2fun::data::Ior;
+    use rust2fun::prelude::*;
+
+    use rust2fun_laws::align_laws::*;
+    use rust2fun_laws::applicative_laws::*;
+    use rust2fun_laws::apply_laws::*;
+    use rust2fun_laws::flatmap_laws::*;
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::invariant_laws::*;
+    use rust2fun_laws::monad_laws::*;
+    use rust2fun_laws::monoid_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+    use rust2fun_laws::semigroupal_laws::*;
+    use rust2fun_laws::stack_safety_laws::*;
+    use rust2fun_laws::strategies::chain;
+    use rust2fun_laws::traverse_laws::*;
+
+    use crate::common::{parse, print};
+
+    #[test]
+    fn test_deep_combine() {
+        let n = 1_000_000;
+        let left = (0..n).fold(Chain::new(), |acc, x| acc.append(x));
+        let right = (0..n).rev().fold(Chain::new(), |acc, x| acc.prepend(x));
+        assert_eq!(n, left.len());
+        assert_eq!(left, right);
+        assert_eq!(Some(n - 1), left.into_iter().last());
+    }
+
+    #[test]
+    fn test_stack_safety() {
+        assert!(combine_stack_safety(chain![1, 2], 100_000).holds());
+        assert!(flat_map_stack_safety::<Chain<_>>(1, 100_000).holds());
+        assert!(map_stack_safety(chain![1, 2], 100_000).holds());
+    }
+
+    #[test]
+    fn test_iter() {
+        let chain = chain![1, 2].combine(Chain::new()).append(3).prepend(0);
+        assert_eq!(4, chain.iter().len());
+        assert_eq!(vec![&0, &1, &2, &3], chain.iter().collect::<Vec<_>>());
+        assert_eq!(vec![0, 1, 2, 3], Vec::from(chain));
+    }
+
+    proptest! {
+        #[test]
+        fn test_vec_roundtrip(xs: Vec<i32>, ys: Vec<i32>) {
+            let chain = Chain::from(xs.clone()).combine(ys.iter().copied().collect());
+            prop_assert_eq!(xs.len() + ys.len(), chain.len());
+            prop_assert_eq!(xs.combine(ys), Vec::from(chain));
+        }
+
+        #[test]
+        fn test_invariant(fa in chain(any::<bool>(), 0..4)) {
+            prop_assert!(invariant_identity(fa.clone()).holds());
+            prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+        }
+
+        #[test]
+        fn test_functor(fa in chain(any::<bool>(), 0..4)) {
+            prop_assert!(covariant_identity(fa.clone()).holds());
+            prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+            prop_assert!(lift_identity(fa.clone()).holds());
+            prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+        }
+
+        #[test]
+        fn test_align(fa in chain(any::<bool>(), 0..4), fb in chain(any::<String>(), 0..4)) {
+            prop_assert!(align_idempotency(fa.clone()).holds());
+            prop_assert!(align_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert!(align_with_consistency(fa, fb, Ior::pad).holds());
+        }
+
+        #[test]
+        fn test_semigroup(fa in chain(any::<String>(), 0..4), fb in chain(any::<String>(), 0..4), fc in chain(any::<String>(), 0..4)) {
+            prop_assert!(repeat_0(fa.clone()).holds());
+            prop_assert!(repeat_1(fb.clone()).holds());
+            prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        }
+
+        #[test]
+        fn test_monoid(fa in chain(any::<String>(), 0..4)) {
+            prop_assert!(monoid_left_identity(fa.clone()).holds());
+            prop_assert!(monoid_right_identity(fa.clone()).holds());
+            prop_assert!(is_id(fa).holds());
+        }
+
+        #[test]
+        fn test_semigroupal(fa in chain(any::<bool>(), 0..4), fb in chain(any::<i32>(), 0..4), fc in chain(any::<u8>(), 0..4)) {
+            prop_assert!(semigroupal_associativity(fa.clone(), fb.clone(), fc).holds());
+            prop_assert!(product_ref_consistency(fa, fb).holds());
+        }
+
+        #[test]
+        fn test_apply(fa in chain(any::<String>(), 0..4), fb in chain(any::<usize>(), 0..4)) {
+            prop_assert!(map2_product_consistency(fa.clone(), fb.clone(), |a, b| a.len() == b).holds());
+            prop_assert!(product_r_consistency(fa.clone(), fb.clone()).holds());
+            prop_assert!(product_l_consistency(fa, fb).holds());
+        }
+
+        #[test]
+        fn test_applicative(a: bool, fa in vec(any::<bool>(), 0..=1).prop_map(Chain::from)) {
+            prop_assert!(applicative_identity(fa.clone()).holds());
+            prop_assert!(applicative_homomorphism::<Chain<_>, _, _>(a, print).holds());
+            prop_assert!(applicative_map(fa.clone(), print).holds());
+            let ff = fa.iter().map(|_| print).collect();
+            prop_assert!(ap_product_consistent(fa, ff).holds());
+            prop_assert!(applicative_unit::<Chain<_>>(a).holds());
+        }
+
+        #[test]
+        fn test_flatmap(fa in vec(any::<bool>(), 0..=1).prop_map(Chain::from)) {
+            prop_assert!(flat_map_associativity(fa.clone(), |x| chain![print(x)], |s| chain![parse::<bool>(s)]).holds());
+            prop_assert!(flat_map_associativity(fa.clone(), |_| Chain::new(), |s| chain![parse::<bool>(s)]).holds());
+            prop_assert!(flat_map_associativity(fa.clone(), |x| chain![print(x)], |_| Chain::<bool>::new()).holds());
+            let ff = fa.iter().map(|_| print).collect();
+            prop_assert!(flat_map_consistent_apply(fa.clone(), ff).holds());
+            prop_assert!(m_product_consistency(fa.clone(), |x| chain![print(x)]).holds());
+            prop_assert!(m_product_consistency(fa, |_| Chain::<String>::new()).holds());
+        }
+
+        #[test]
+        fn test_monad(a: bool, fa in chain(any::<bool>(), 0..4)) {
+            prop_assert!(monad_left_identity::<Chain<_>, _, _>(a, |x| chain![print(x)]).holds());
+            prop_assert!(monad_left_identity::<Chain<_>, _, _>(a, |_| Chain::<String>::new()).holds());
+            prop_assert!(monad_right_identity(fa.clone()).holds());
+            prop_assert!(map_flat_map_coherence(fa, print).holds());
+        }
+
+        #[test]
+        fn test_traverse(fa in chain(any::<bool>(), 0..4)) {
+            prop_assert!(traverse_identity(fa, print).holds());
+        }
+    }
+}
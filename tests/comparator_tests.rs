@@ -0,0 +1,52 @@
+mod common;
+
+if_std! {
+    use std::cmp::Ordering::*;
+
+    use rust2fun::prelude::*;
+
+    #[test]
+    fn test_divide() {
+        let mut cmp = Comparator::natural().divide(Comparator::natural(), |p: (i32, &str)| p);
+
+        assert_eq!(Less, cmp.compare((1, "b"), (2, "a")));
+        assert_eq!(Greater, cmp.compare((1, "b"), (1, "a")));
+        assert_eq!(Equal, cmp.compare((1, "a"), (1, "a")));
+    }
+
+    #[test]
+    fn test_divisible_identity() {
+        let mut left = Comparator::natural().divide(Comparator::conquer(), |x: i32| (x, ()));
+        let mut right = Comparator::conquer().divide(Comparator::natural(), |x: i32| ((), x));
+
+        for (x, y) in [(1, 2), (2, 1), (1, 1)] {
+            assert_eq!(x.cmp(&y), left.compare(x, y));
+            assert_eq!(x.cmp(&y), right.compare(x, y));
+        }
+    }
+
+    #[test]
+    fn test_decidable() {
+        let mut cmp = Comparator::natural().choose(Comparator::natural(), |r: Result<i32, char>| r);
+
+        assert_eq!(Less, cmp.compare(Ok(1), Ok(2)));
+        assert_eq!(Greater, cmp.compare(Err('b'), Err('a')));
+        assert_eq!(Less, cmp.compare(Ok(2), Err('a')));
+        assert_eq!(Greater, cmp.compare(Err('a'), Ok(2)));
+    }
+
+    #[test]
+    fn test_monoid() {
+        let by_len = || Comparator::natural().contramap(|s: &str| s.len());
+        let pairs = [("a", "bb"), ("bb", "a"), ("ab", "ba"), ("ba", "ab"), ("a", "a")];
+
+        let mut cmp = by_len().combine(Comparator::natural());
+        let mut left = Comparator::empty().combine(by_len());
+        let mut right = by_len().combine(Comparator::empty());
+        for (x, y) in pairs {
+            assert_eq!(x.len().cmp(&y.len()).then(x.cmp(y)), cmp.compare(x, y));
+            assert_eq!(x.len().cmp(&y.len()), left.compare(x, y));
+            assert_eq!(x.len().cmp(&y.len()), right.compare(x, y));
+        }
+    }
+}
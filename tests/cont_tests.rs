@@ -0,0 +1,68 @@
+mod common;
+
+if_std! {
+    use proptest::prelude::*;
+
+    use rust2fun::prelude::*;
+
+    use crate::common::{parse, print};
+
+    fn product(xs: Vec<i32>) -> Cont<i32, i32> {
+        Cont::call_cc(move |exit| {
+            fn go(xs: Vec<i32>, exit: std::rc::Rc<dyn Fn(i32) -> Cont<i32, i32>>) -> Cont<i32, i32> {
+                match xs.split_first() {
+                    None => Cont::pure(1),
+                    Some((0, _)) => exit(0),
+                    Some((x, rest)) => {
+                        let x = *x;
+                        go(rest.to_vec(), exit).map(move |p| x * p)
+                    }
+                }
+            }
+            go(xs, exit)
+        })
+    }
+
+    #[test]
+    fn test_call_cc() {
+        assert_eq!(24, product(vec![1, 2, 3, 4]).eval());
+        assert_eq!(0, product(vec![1, 2, 0, 4]).eval());
+        assert_eq!(100, product(vec![1, 2, 0, 4]).run_cont(|x| x + 100));
+        assert_eq!(124, product(vec![1, 2, 3, 4]).run_cont(|x| x + 100));
+    }
+
+    #[test]
+    fn test_bind() {
+        let cont = bind! {
+            for x in inherent(Cont::pure(1));
+            for y in inherent(Cont::new(|k: std::rc::Rc<dyn Fn(i32) -> Vec<i32>>| [k(10), k(20)].concat()));
+            x + y
+        };
+
+        assert_eq!(vec![11, 21], cont.run_cont(|x| vec![x]));
+    }
+
+    proptest! {
+        #[test]
+        fn test_functor(a: i32) {
+            prop_assert_eq!(a, Cont::pure(a).map(|x| x).eval());
+            prop_assert_eq!(
+                Cont::pure(a).map(print).map(parse::<i32>).eval(),
+                Cont::pure(a).map(|x| parse::<i32>(print(x))).eval()
+            );
+        }
+
+        #[test]
+        fn test_monad(a: i32) {
+            let f = |x: i32| Cont::<usize, _>::pure(x.to_string());
+            let g = |s: String| Cont::pure(s.len());
+
+            prop_assert_eq!(Cont::pure(a).flat_map(f).flat_map(g).eval(), f(a).flat_map(g).eval());
+            prop_assert_eq!(Cont::<i32, _>::pure(a).flat_map(Cont::pure).eval(), a);
+            prop_assert_eq!(
+                Cont::pure(a).flat_map(f).flat_map(g).eval(),
+                Cont::pure(a).flat_map(move |x| f(x).flat_map(g)).eval()
+            );
+        }
+    }
+}
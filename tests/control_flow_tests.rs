@@ -0,0 +1,95 @@
+extern crate rust2fun_laws;
+
+use core::ops::ControlFlow;
+use core::ops::ControlFlow::{Break, Continue};
+
+use rust2fun_laws::applicative_laws::*;
+use rust2fun_laws::apply_laws::*;
+use rust2fun_laws::bifunctor_laws::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+#[test]
+fn test_invariant() {
+    assert!(invariant_identity(Continue::<bool, _>(1)).holds());
+    assert!(invariant_identity(Break::<_, i32>(true)).holds());
+    assert!(
+        invariant_composition(Continue::<bool, _>(1), print, parse, parse::<i32>, print).holds()
+    );
+}
+
+#[test]
+fn test_functor() {
+    assert!(covariant_identity(Continue::<bool, _>(1)).holds());
+    assert!(covariant_composition(Continue::<bool, _>(1), print, parse::<u32>).holds());
+    assert!(covariant_composition(Break::<_, i32>(true), print, parse::<u32>).holds());
+    assert!(lift_identity(Continue::<bool, _>(1)).holds());
+    assert!(lift_composition(Continue::<bool, _>(1), print, parse::<i64>).holds());
+}
+
+#[test]
+fn test_bifunctor() {
+    assert!(bifunctor_identity(Continue::<bool, i32>(1)).holds());
+    assert!(bifunctor_identity(Break::<bool, i32>(true)).holds());
+    assert!(bifunctor_composition(
+        Break::<bool, i32>(true),
+        print,
+        parse::<bool>,
+        print,
+        parse::<i32>
+    )
+    .holds());
+}
+
+#[test]
+fn test_semigroupal() {
+    assert!(semigroupal_associativity(Continue::<bool, _>(1), Continue("a"), Continue(2)).holds());
+    assert!(
+        semigroupal_associativity(Continue(1), Break::<_, &str>(true), Break::<_, i32>(false))
+            .holds()
+    );
+}
+
+#[test]
+fn test_apply() {
+    let check_length = |x: &str, l: usize| x.len() == l;
+
+    assert!(
+        map2_product_consistency(Continue::<bool, _>("str"), Continue(1), check_length).holds()
+    );
+    assert!(map2_product_consistency(Break(true), Continue(1), check_length).holds());
+    assert!(product_r_consistency(Continue("str"), Break::<_, i32>(true)).holds());
+    assert!(product_l_consistency(Continue::<bool, _>("str"), Continue(1)).holds());
+}
+
+#[test]
+fn test_applicative() {
+    assert!(applicative_identity(Continue::<bool, _>(1)).holds());
+    assert!(applicative_homomorphism::<ControlFlow<bool, _>, _, _>(1, print).holds());
+    assert!(applicative_map(Continue::<bool, _>(1), print).holds());
+    assert!(ap_product_consistent(Continue::<bool, _>(1), Continue(print)).holds());
+    assert!(applicative_unit::<ControlFlow<bool, _>>(1).holds());
+}
+
+#[test]
+fn test_flatmap() {
+    let break_if_odd = |x: i32| if x % 2 == 0 { Continue(x) } else { Break(x) };
+
+    assert!(flat_map_associativity(Continue(1), |x| Continue(x + 1), break_if_odd).holds());
+    assert!(flat_map_consistent_apply(Continue::<bool, _>(1), Continue(print)).holds());
+    assert!(m_product_consistency(Continue(1), break_if_odd).holds());
+}
+
+#[test]
+fn test_monad() {
+    assert!(monad_left_identity::<ControlFlow<bool, _>, _, _>(1, |x| Continue(x + 1)).holds());
+    assert!(monad_right_identity(Continue::<bool, _>(1)).holds());
+    assert!(monad_right_identity(Break::<_, i32>(true)).holds());
+}
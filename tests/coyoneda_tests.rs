@@ -0,0 +1,45 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_lift_lower(fa: Vec<i32>) {
+        prop_assert_eq!(fa.clone(), Coyoneda::lift(fa).lower());
+    }
+
+    #[test]
+    fn test_map_composition(fa: Vec<bool>) {
+        let lhs = Coyoneda::lift(fa.clone()).map(print).map(parse::<bool>).lower();
+        let rhs = fa.map(print).map(parse::<bool>);
+        prop_assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn test_into_iter(fa: Option<i32>) {
+        let lhs: Vec<_> = Coyoneda::lift(fa).map(print).into_iter().collect();
+        let rhs: Vec<_> = fa.map(print).into_iter().collect();
+        prop_assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn test_map_fusion() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+
+    let xs = Coyoneda::lift(vec![1, 2, 3]).map(|x| x + 1).map(move |x| {
+        counter.set(counter.get() + 1);
+        x * 2
+    });
+    assert_eq!(0, calls.get());
+    assert_eq!(vec![4, 6, 8], xs.lower());
+    assert_eq!(3, calls.get());
+}
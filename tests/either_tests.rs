@@ -0,0 +1,110 @@
+#![cfg(feature = "either")]
+
+extern crate rust2fun_laws;
+
+use either::Either::{self, Left, Right};
+use proptest::prelude::*;
+
+use rust2fun::data::{Invalid, Ior, Valid, Validated};
+
+use rust2fun_laws::applicative_laws::*;
+use rust2fun_laws::apply_laws::*;
+use rust2fun_laws::bifunctor_laws::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::strategies::either;
+use rust2fun_laws::traverse_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_invariant(fa in either(any::<i32>(), any::<bool>())) {
+        prop_assert!(invariant_identity(fa).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa in either(any::<i32>(), any::<bool>())) {
+        prop_assert!(covariant_identity(fa).holds());
+        prop_assert!(covariant_composition(fa, print, parse::<bool>).holds());
+        prop_assert!(lift_identity(fa).holds());
+        prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+    }
+
+    #[test]
+    fn test_bifunctor(fa: Result<bool, i32>) {
+        let fa = Either::from(fa);
+        prop_assert!(bifunctor_identity(fa).holds());
+        prop_assert!(bifunctor_composition(fa, print, parse::<i32>, print, parse::<bool>).holds())
+    }
+
+    #[test]
+    fn test_semigroupal(fa: Result<bool, i32>, fb: Result<String, i32>, fc: Result<u8, i32>) {
+        let (fa, fb, fc) = (Either::from(fa), Either::from(fb), Either::from(fc));
+        prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+    }
+
+    #[test]
+    fn test_apply(fa: Result<String, i32>, fb: Result<usize, i32>) {
+        let (fa, fb) = (Either::from(fa), Either::from(fb));
+        prop_assert!(map2_product_consistency(fa.clone(), fb, |a, b| a.len() == b).holds());
+        prop_assert!(product_r_consistency(fa.clone(), fb).holds());
+        prop_assert!(product_l_consistency(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_applicative(fa: Result<bool, i32>, a: bool) {
+        let fa = Either::from(fa);
+        prop_assert!(applicative_identity(fa).holds());
+        prop_assert!(applicative_homomorphism::<Either<i32, _>, _, _>(a, print).holds());
+        prop_assert!(applicative_map(fa, print).holds());
+        prop_assert!(ap_product_consistent(fa, Right(print)).holds());
+        prop_assert!(ap_product_consistent(fa, Left::<_, fn(bool) -> String>(-1)).holds());
+        prop_assert!(applicative_unit::<Either<i32, _>>(a).holds());
+    }
+
+    #[test]
+    fn test_flatmap(fa: Result<bool, i32>) {
+        let fa = Either::from(fa);
+        prop_assert!(flat_map_associativity(fa, |x| Right(print(x)), |s| Right(parse::<bool>(s))).holds());
+        prop_assert!(flat_map_associativity(fa, |_| Left(-1), |s| Right(parse::<bool>(s))).holds());
+        prop_assert!(flat_map_consistent_apply(fa, Right(print)).holds());
+        prop_assert!(m_product_consistency(fa, |x| Right(print(x))).holds());
+        prop_assert!(m_product_consistency(fa, |_| Left::<_, String>(-1)).holds());
+    }
+
+    #[test]
+    fn test_monad(a: bool, fa: Result<bool, i32>) {
+        let fa = Either::from(fa);
+        prop_assert!(monad_left_identity::<Either<i32, _>, _, _>(a, |x| Right(print(x))).holds());
+        prop_assert!(monad_right_identity(fa).holds());
+        prop_assert!(map_flat_map_coherence(fa, print).holds());
+    }
+
+    #[test]
+    fn test_traverse(fa: Result<bool, i32>) {
+        prop_assert!(traverse_identity(Either::from(fa), print).holds());
+    }
+
+    #[test]
+    fn test_conversions(fa: Result<bool, i32>) {
+        let either = Either::from(fa);
+        let validated = Validated::from(either);
+        prop_assert_eq!(Validated::from(fa), validated);
+        prop_assert_eq!(either, Either::from(validated));
+        prop_assert_eq!(Ior::from(fa), Ior::from(either));
+    }
+}
+
+#[test]
+fn test_validated() {
+    assert_eq!(Valid::<_, i32>(1), Right(1).into());
+    assert_eq!(Invalid::<bool, _>(2), Left(2).into());
+    assert_eq!(Left::<_, bool>("error"), Invalid("error").into());
+}
@@ -0,0 +1,69 @@
+mod common;
+
+if_std! {
+    use rust2fun::prelude::*;
+
+    #[test]
+    fn test_semigroup() {
+        let mut f = Func::new(|x: i32| Sum(x)).combine(Func::new(|x| Sum(x * 10)));
+
+        assert_eq!(Sum(11), f.run(1));
+        assert_eq!(Sum(-22), f.run(-2));
+    }
+
+    #[test]
+    fn test_monoid_identity() {
+        let mut left = Func::new(|x: i32| x.to_string()).combine(Func::empty());
+        let mut right = Func::empty().combine(Func::new(|x: i32| x.to_string()));
+
+        for x in -2..3 {
+            assert_eq!(x.to_string(), left.run(x));
+            assert_eq!(x.to_string(), right.run(x));
+        }
+    }
+
+    #[test]
+    fn test_combine_all() {
+        let mut f = Monoid::combine_all((2..4).map(|d| Func::new(move |x: i32| Any(x % d == 0))));
+
+        for x in -6..7 {
+            assert_eq!(Any(x % 2 == 0 || x % 3 == 0), f.run(x));
+        }
+    }
+
+    #[test]
+    fn test_functor() {
+        let mut f = Func::new(|x: i32| x + 1).map(|x| x * 2).map(|x| x.to_string());
+
+        assert_eq!("4", f.run(1));
+        assert_eq!("-2", f.run(-2));
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut f = Func::new(|s: String| s.len()).ap(Func::new(|s: String| move |n| format!("{s}:{n}")));
+
+        assert_eq!("foo:3", f.run("foo".to_string()));
+    }
+
+    #[test]
+    fn test_flat_map() {
+        let mut left = Func::<i32, i32>::pure(5).flat_map(|y| Func::new(move |x: i32| x * y));
+        let mut right = Func::new(|x: i32| x - 1).flat_map(Func::pure);
+        let mut env = Func::new(|x: i32| x > 0).flat_map(|pos| Func::new(move |x: i32| if pos { x } else { -x }));
+
+        for x in -2..3 {
+            assert_eq!(x * 5, left.run(x));
+            assert_eq!(x - 1, right.run(x));
+            assert_eq!(x.abs(), env.run(x));
+        }
+    }
+
+    #[test]
+    fn test_contramap() {
+        let mut f = Func::new(|x: usize| x + 1).contramap(|s: &str| s.len()).contramap(|x: i32| if x > 0 { "pos" } else { "no" });
+
+        assert_eq!(4, f.run(1));
+        assert_eq!(3, f.run(0));
+    }
+}
@@ -7,6 +7,11 @@ if_std! {
 
     use proptest::prelude::*;
 
+    use rust2fun::data::{Invalid, Ior, Valid};
+    use rust2fun::semigroup::Semigroup;
+    use rust2fun::traverse::Traverse;
+
+    use rust2fun_laws::align_laws::*;
     use rust2fun_laws::apply_laws::*;
     use rust2fun_laws::bifunctor_laws::*;
     use rust2fun_laws::flatmap_laws::*;
@@ -15,6 +20,8 @@ if_std! {
     use rust2fun_laws::monoid_laws::*;
     use rust2fun_laws::semigroup_laws::*;
     use rust2fun_laws::semigroupal_laws::*;
+    use rust2fun_laws::strategies::small_hash_map;
+    use rust2fun_laws::traverse_laws::*;
 
     use crate::common::{parse, print};
 
@@ -33,6 +40,13 @@ if_std! {
             prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
         }
 
+        #[test]
+        fn test_align(fa: HashMap::<i32, bool>, fb: HashMap::<i32, String>) {
+            prop_assert!(align_idempotency(fa.clone()).holds());
+            prop_assert!(align_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert!(align_with_consistency(fa, fb, Ior::pad).holds());
+        }
+
         #[test]
         fn test_bifunctor(fa: HashMap::<i32, bool>) {
             prop_assert!(bifunctor_identity(fa.clone()).holds());
@@ -57,6 +71,30 @@ if_std! {
             prop_assert!(monoid_right_identity(fa.clone()).holds());
             prop_assert!(is_id(fa).holds());
         }
+
+        #[test]
+        fn test_overlapping_keys(fa in small_hash_map(any::<bool>()), fb in small_hash_map(any::<String>()), fc in small_hash_map(any::<String>())) {
+            prop_assert!(align_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert!(semigroupal_associativity(fa.clone(), fb.clone(), fc.clone()).holds());
+            prop_assert!(map2_product_consistency(fb.clone(), fa, |s, b| s.len() > 3 && b).holds());
+            prop_assert!(semigroup_associativity(fb.clone(), fc.clone(), fb.clone()).holds());
+            prop_assert!(semigroup_commutativity(fb.len(), fc.len()).holds());
+        }
+
+        #[test]
+        fn test_traverse(fa: HashMap<i8, i8>) {
+            prop_assert!(traverse_identity(fa.clone(), print).holds());
+
+            let errors = fa.values().filter(|x| **x < 0).count();
+            let actual = fa.clone().traverse(|x| {
+                if x < 0 { Invalid(vec![x]) } else { Valid(x) }
+            });
+            if errors == 0 {
+                prop_assert_eq!(Valid(fa), actual);
+            } else {
+                prop_assert_eq!(errors, actual.unwrap_err().len());
+            }
+        }
     }
 
     #[test]
@@ -74,6 +112,18 @@ if_std! {
         assert!(semigroup_associativity(fa, fb, fc).holds());
     }
 
+    #[test]
+    fn test_semigroup_order() {
+        let larger = HashMap::from([(0, "a".to_owned()), (1, "a".to_owned())]);
+        let smaller = HashMap::from([(0, "b".to_owned())]);
+
+        let actual = larger.clone().combine(smaller.clone());
+        assert_eq!(HashMap::from([(0, "ab".to_owned()), (1, "a".to_owned())]), actual);
+
+        let actual = smaller.combine(larger);
+        assert_eq!(HashMap::from([(0, "ba".to_owned()), (1, "a".to_owned())]), actual);
+    }
+
     #[test]
     fn test_flatmap() {
         assert!(flat_map_associativity(
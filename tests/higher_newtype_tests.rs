@@ -0,0 +1,84 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use proptest::prelude::*;
+
+    use rust2fun::prelude::*;
+    use rust2fun_laws::applicative_laws::*;
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::monad_laws::*;
+    use rust2fun_laws::monoid_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+
+    use crate::common::{parse, print};
+
+    #[derive(Debug, Clone, PartialEq, Eq, HigherNewtype)]
+    struct Checked<T>(Vec<T>);
+
+    #[derive(Debug, Clone, PartialEq, Eq, HigherNewtype)]
+    struct Keyed<K, T> {
+        value: Result<T, K>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, HigherNewtype)]
+    #[higher_newtype(Functor, Pure)]
+    struct ZipVec<T>(Vec<T>);
+
+    impl<A, B> Semigroupal<B> for ZipVec<A> {
+        fn product(self, fb: ZipVec<B>) -> ZipVec<(A, B)> {
+            ZipVec(self.0.into_iter().zip(fb.0).collect())
+        }
+    }
+
+    fn keyed<T>(value: Result<T, String>) -> Keyed<String, T> {
+        Keyed { value }
+    }
+
+    proptest! {
+        #[test]
+        fn test_covariant_identity(fa: Vec<bool>) {
+            prop_assert!(covariant_identity(Checked(fa)).holds());
+        }
+
+        #[test]
+        fn test_covariant_composition(fa: Result<i32, String>) {
+            prop_assert!(covariant_composition(keyed(fa), print, parse::<i32>).holds());
+        }
+
+        #[test]
+        fn test_applicative_identity(fa: Vec<bool>) {
+            prop_assert!(applicative_identity(Checked(fa)).holds());
+        }
+
+        #[test]
+        fn test_monad_left_identity(a: i32) {
+            let f = |x: i32| Checked(vec![x, x.wrapping_add(1)]);
+            prop_assert!(monad_left_identity::<Checked<_>, _, _>(a, f).holds());
+        }
+
+        #[test]
+        fn test_monad_right_identity(fa: Result<i32, String>) {
+            prop_assert!(monad_right_identity(keyed(fa)).holds());
+        }
+
+        #[test]
+        fn test_semigroup_associativity(a: Vec<u8>, b: Vec<u8>, c: Vec<u8>) {
+            prop_assert!(semigroup_associativity(Checked(a), Checked(b), Checked(c)).holds());
+        }
+
+        #[test]
+        fn test_monoid_identity(fa: Vec<u8>) {
+            prop_assert!(monoid_left_identity(Checked(fa.clone())).holds());
+            prop_assert!(monoid_right_identity(Checked(fa)).holds());
+        }
+
+        #[test]
+        fn test_zip_vec(xs: Vec<u8>, ys: Vec<u8>) {
+            let expected: Vec<_> = xs.iter().copied().zip(ys.iter().copied()).collect();
+            prop_assert_eq!(ZipVec(expected), ZipVec(xs).product(ZipVec(ys)));
+            prop_assert_eq!(ZipVec(vec![1]), ZipVec::pure(1));
+        }
+    }
+}
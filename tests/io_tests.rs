@@ -0,0 +1,93 @@
+mod common;
+
+if_std! {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use proptest::prelude::*;
+
+    use rust2fun::prelude::*;
+
+    use crate::common::{parse, print};
+
+    fn count(counter: &Rc<Cell<u32>>) -> IO<u32> {
+        let counter = counter.clone();
+        IO::delay(move || {
+            counter.set(counter.get() + 1);
+            counter.get()
+        })
+    }
+
+    #[test]
+    fn test_laziness() {
+        let counter = Rc::new(Cell::new(0));
+        let io = count(&counter).flat_map(|_| IO::pure(())).map(|_| 0);
+        assert_eq!(0, counter.get());
+
+        drop(io);
+        assert_eq!(0, counter.get());
+
+        let io = count(&counter);
+        let c = counter.clone();
+        let twice = bind! {
+            for x in inherent(count(&c));
+            for y in inherent(count(&c));
+            x + y
+        };
+        assert_eq!(0, counter.get());
+        assert_eq!(1, io.run());
+        assert_eq!(5, twice.run());
+    }
+
+    #[test]
+    fn test_stack_safety() {
+        let n = 1_000_000u64;
+        let left = (0..n).fold(IO::pure(0), |io, i| io.flat_map(move |x| IO::pure(x + i)));
+        assert_eq!(n * (n - 1) / 2, left.run());
+
+        fn loop_(n: u64, acc: u64) -> IO<u64> {
+            if n == 0 {
+                IO::pure(acc)
+            } else {
+                IO::pure(n).flat_map(move |n| loop_(n - 1, acc + n))
+            }
+        }
+        assert_eq!(n * (n + 1) / 2, loop_(n, 0).run());
+
+        let unused = (0..n).fold(IO::pure(0), |io, i| io.map(move |x| x + i));
+        drop(unused);
+    }
+
+    #[test]
+    fn test_attempt() {
+        let io = IO::delay(|| -> i32 { panic!("boom") }).map(|x| x + 1).attempt();
+        assert!(io.run().is_err());
+
+        let io = IO::delay(|| 41).map(|x| x + 1).attempt();
+        assert_eq!(42, io.run().unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn test_functor(a: i32) {
+            prop_assert_eq!(a, IO::pure(a).map(|x| x).run());
+            prop_assert_eq!(
+                IO::pure(a).map(print).map(parse::<i32>).run(),
+                IO::pure(a).map(|x| parse::<i32>(print(x))).run()
+            );
+        }
+
+        #[test]
+        fn test_monad(a: i32) {
+            let f = |x: i32| IO::delay(move || x.to_string());
+            let g = |s: String| IO::delay(move || s.len());
+
+            prop_assert_eq!(IO::pure(a).flat_map(f).run(), f(a).run());
+            prop_assert_eq!(IO::delay(move || a).flat_map(IO::pure).run(), a);
+            prop_assert_eq!(
+                IO::pure(a).flat_map(f).flat_map(g).run(),
+                IO::pure(a).flat_map(move |x| f(x).flat_map(g)).run()
+            );
+        }
+    }
+}
@@ -0,0 +1,98 @@
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+use rust2fun_laws::applicative_laws::*;
+use rust2fun_laws::apply_laws::*;
+use rust2fun_laws::bifunctor_laws::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_laws::*;
+use rust2fun_laws::semigroup_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::strategies::ior;
+
+use crate::common::{parse, print};
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_invariant(fa in ior(any::<i32>(), any::<bool>())) {
+        prop_assert!(invariant_identity(fa).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa in ior(any::<i32>(), any::<bool>())) {
+        prop_assert!(covariant_identity(fa).holds());
+        prop_assert!(covariant_composition(fa, print, parse::<bool>).holds());
+        prop_assert!(lift_identity(fa).holds());
+        prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+    }
+
+    #[test]
+    fn test_bifunctor(fa in ior(any::<i32>(), any::<bool>())) {
+        prop_assert!(bifunctor_identity(fa).holds());
+        prop_assert!(bifunctor_composition(fa, print, parse::<i32>, print, parse::<bool>).holds())
+    }
+
+    #[test]
+    fn test_semigroup(fa in ior(any::<String>(), any::<String>()), fb in ior(any::<String>(), any::<String>()), fc in ior(any::<String>(), any::<String>())) {
+        prop_assert!(repeat_0(fa.clone()).holds());
+        prop_assert!(repeat_1(fb.clone()).holds());
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+    }
+
+    #[test]
+    fn test_semigroupal(fa in ior(any::<String>(), any::<bool>()), fb in ior(any::<String>(), any::<i32>()), fc in ior(any::<String>(), any::<u8>())) {
+        prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+    }
+
+    #[test]
+    fn test_apply(fa in ior(any::<String>(), any::<String>()), fb in ior(any::<String>(), any::<usize>())) {
+        prop_assert!(map2_product_consistency(fa.clone(), fb.clone(), |a, b| a.len() == b).holds());
+        prop_assert!(product_r_consistency(fa.clone(), fb.clone()).holds());
+        prop_assert!(product_l_consistency(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_applicative(fa in ior(any::<String>(), any::<bool>()), a: bool) {
+        prop_assert!(applicative_identity(fa.clone()).holds());
+        prop_assert!(applicative_homomorphism::<Ior<String, _>, _, _>(a, print).holds());
+        prop_assert!(applicative_map(fa.clone(), print).holds());
+        prop_assert!(ap_product_consistent(fa.clone(), Ior::Both("w".to_string(), print)).holds());
+        prop_assert!(ap_product_consistent(fa, Ior::Left::<_, fn(bool) -> String>("e".to_string())).holds());
+        prop_assert!(applicative_unit::<Ior<String, _>>(a).holds());
+    }
+
+    #[test]
+    fn test_flatmap(fa in ior(any::<String>(), any::<bool>())) {
+        let warn = |x: bool| Ior::Both(print(x), print(x));
+        let fail = |s: String| Ior::Left::<_, bool>(s);
+        prop_assert!(flat_map_associativity(fa.clone(), warn, |s| Ior::Right(parse::<bool>(s))).holds());
+        prop_assert!(flat_map_associativity(fa.clone(), warn, fail).holds());
+        prop_assert!(flat_map_consistent_apply(fa.clone(), Ior::Both("w".to_string(), print)).holds());
+        prop_assert!(m_product_consistency(fa, warn).holds());
+    }
+
+    #[test]
+    fn test_monad(a: bool, fa in ior(any::<String>(), any::<bool>())) {
+        prop_assert!(monad_left_identity::<Ior<String, _>, _, _>(a, |x| Ior::Both(print(x), print(x))).holds());
+        prop_assert!(monad_left_identity::<Ior<String, _>, _, _>(a, |x| Ior::Left::<_, String>(print(x))).holds());
+        prop_assert!(monad_right_identity(fa.clone()).holds());
+        prop_assert!(map_flat_map_coherence(fa, print).holds());
+    }
+
+    #[test]
+    fn test_conversions(fa: Result<bool, i32>) {
+        let ior: Ior<i32, bool> = fa.into();
+        prop_assert_eq!(fa, ior.into_result());
+        prop_assert_eq!(Validated::from(fa), ior.into_validated());
+
+        let ior: Ior<i32, bool> = Validated::from(fa).into();
+        prop_assert_eq!(fa, ior.into_result());
+    }
+}
@@ -0,0 +1,68 @@
+mod common;
+
+if_std! {
+    use rust2fun::prelude::*;
+
+    fn parse(s: String) -> Option<i32> {
+        s.parse().ok()
+    }
+
+    fn half(x: i32) -> Option<i32> {
+        if x % 2 == 0 { Some(x / 2) } else { None }
+    }
+
+    #[test]
+    fn test_category_identity() {
+        let mut left = Kleisli::new(parse).and_then(Kleisli::<(), Option<()>>::id());
+        let mut right = Kleisli::<(), Option<()>>::id().and_then(Kleisli::new(parse));
+
+        for s in ["42", "foo"] {
+            assert_eq!(parse(s.to_string()), left.run(s.to_string()));
+            assert_eq!(parse(s.to_string()), right.run(s.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_category_associativity() {
+        let mut left = Kleisli::new(parse)
+            .and_then(Kleisli::new(half))
+            .and_then(Kleisli::new(half));
+        let mut right =
+            Kleisli::new(parse).and_then(Kleisli::new(half).and_then(Kleisli::new(half)));
+
+        for s in ["4", "6", "7", "foo"] {
+            assert_eq!(left.run(s.to_string()), right.run(s.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_compose() {
+        let mut k = Kleisli::new(half).compose(Kleisli::new(parse));
+        assert_eq!(Some(21), k.run("42".to_string()));
+        assert_eq!(None, k.run("21".to_string()));
+    }
+
+    #[test]
+    fn test_arrow() {
+        let mut k = Kleisli::new(half).split(Kleisli::new(parse));
+        assert_eq!(Some((2, 1)), k.run((4, "1".to_string())));
+        assert_eq!(None, k.run((3, "1".to_string())));
+
+        let mut k = Kleisli::new(half).fanout(Kleisli::new(|x: i32| Some(x + 1)));
+        assert_eq!(Some((2, 5)), k.run(4));
+        assert_eq!(None, k.run(3));
+
+        let mut k = Strong::<&str>::first(Kleisli::new(half));
+        assert_eq!(Some((2, "foo")), k.run((4, "foo")));
+    }
+
+    #[test]
+    fn test_profunctor() {
+        let mut k = Kleisli::new(half).dimap(|s: &str| s.len() as i32, |x| x * 10);
+        assert_eq!(Some(20), k.run("four"));
+
+        let mut k = Choice::<&str>::left(Kleisli::new(half));
+        assert_eq!(Some(Err(2)), k.run(Err(4)));
+        assert_eq!(Some(Ok("foo")), k.run(Ok("foo")));
+    }
+}
@@ -0,0 +1,60 @@
+mod vec_laws {
+    use rust2fun_laws::law_suite;
+
+    law_suite!(Vec<i32>: invariant, functor, semigroupal, apply, applicative, flat_map, monad);
+}
+
+mod vec_string_laws {
+    use rust2fun_laws::law_suite;
+
+    law_suite!(Vec<String>: align, traverse, semigroup, monoid);
+}
+
+mod linked_list_laws {
+    use std::collections::LinkedList;
+
+    use rust2fun_laws::law_suite;
+
+    law_suite!(LinkedList<char>: functor, apply, monad, semigroup, monoid);
+}
+
+mod option_laws {
+    use rust2fun_laws::law_suite;
+
+    law_suite!(Option<u8>: functor, semigroupal, apply, applicative, flat_map, monad, align, traverse);
+}
+
+mod option_string_laws {
+    use rust2fun_laws::law_suite;
+
+    law_suite!(Option<String>: semigroup, monoid);
+}
+
+mod result_laws {
+    use rust2fun_laws::law_suite;
+
+    law_suite!(Result<bool, i32>: functor, semigroupal, apply, applicative, flat_map, monad, traverse);
+}
+
+mod u32_laws {
+    use rust2fun_laws::law_suite;
+
+    law_suite!(u32 = 0..u32::MAX / 3; semigroup, commutative_semigroup, monoid);
+}
+
+mod wrapping_laws {
+    use std::num::Wrapping;
+
+    use rust2fun_laws::law_suite;
+
+    law_suite!(Wrapping<u32>: semigroup, commutative_semigroup, monoid);
+}
+
+#[cfg(feature = "proptest-support")]
+mod ne_vec_laws {
+    use rust2fun::data::NEVec;
+
+    use rust2fun_laws::law_suite;
+
+    law_suite!(NEVec<i32>: functor, apply, monad, reducible, semigroup);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+if_std! {
+    use std::cell::Cell;
+
+    use proptest::prelude::*;
+
+    use rust2fun::prelude::*;
+
+    use crate::common::{parse, print};
+
+    proptest! {
+        #[test]
+        fn test_functor(xs: Vec<i32>) {
+            prop_assert_eq!(&xs, &LazyIter::new(xs.clone()).map(id).collect::<Vec<_>>());
+            prop_assert_eq!(
+                xs.clone().map(print).map(parse::<i64>),
+                LazyIter::new(xs).map(print).map(parse::<i64>).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_flatmap(xs: Vec<u8>) {
+            let f = |x: u8| vec![x; x as usize % 4];
+            prop_assert_eq!(
+                xs.clone().flat_map(f),
+                LazyIter::new(xs).flat_map(f).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_semigroupal(xs: Vec<u8>, ys: Vec<bool>) {
+            prop_assert_eq!(
+                xs.clone().product(ys.clone()),
+                LazyIter::new(xs).cartesian_product(LazyIter::new(ys)).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn test_monoid(xs: Vec<u8>, ys: Vec<u8>) {
+            prop_assert_eq!(
+                xs.clone().combine(ys.clone()),
+                LazyIter::new(xs.clone()).combine(LazyIter::new(ys)).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(
+                &xs,
+                &LazyIter::empty().combine(LazyIter::new(xs.clone())).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(&xs, &LazyIter::new(xs.clone()).combine(LazyIter::empty()).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_laziness() {
+        let pulled = Cell::new(0);
+        let source = (0..).inspect(|_| pulled.set(pulled.get() + 1));
+        let mut xs = LazyIter::new(source)
+            .map(|x| x * 10)
+            .flat_map(|x| [x, x + 1])
+            .cartesian_product(LazyIter::pure('a'));
+        assert_eq!(0, pulled.get());
+
+        assert_eq!(Some((0, 'a')), xs.next());
+        assert_eq!(Some((1, 'a')), xs.next());
+        assert_eq!(Some((10, 'a')), xs.next());
+        assert_eq!(2, pulled.get());
+    }
+
+    #[test]
+    fn test_iterator_product() {
+        assert_eq!(24, LazyIter::new(1..=4).product::<i32>());
+    }
+}
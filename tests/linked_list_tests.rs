@@ -4,7 +4,7 @@ if_std! {
     extern crate rust2fun_laws;
 
     use std::collections::LinkedList;
-    use std::iter::repeat;
+    use std::iter::repeat_n;
 
     use proptest::collection::linked_list;
     use proptest::prelude::*;
@@ -19,6 +19,7 @@ if_std! {
     use rust2fun_laws::monoid_laws::*;
     use rust2fun_laws::semigroup_laws::*;
     use rust2fun_laws::semigroupal_laws::*;
+    use rust2fun_laws::traverse_laws::*;
 
     use crate::common::{parse, print};
 
@@ -66,7 +67,7 @@ if_std! {
 
         #[test]
         fn test_applicative(a: bool, fa in linked_list(any::<bool>(), 0..=1)) {
-            let ff= repeat(print).take(fa.len()).collect::<LinkedList<_>>();
+            let ff= repeat_n(print, fa.len()).collect::<LinkedList<_>>();
 
             prop_assert!(applicative_identity(fa.clone()).holds());
             prop_assert!(applicative_homomorphism::<Vec<_>, _, _>(a, print).holds());
@@ -77,7 +78,7 @@ if_std! {
 
         #[test]
         fn test_flatmap(fa in linked_list(any::<bool>(), 0..=1)) {
-            let ff= repeat(print).take(fa.len()).collect::<LinkedList<_>>();
+            let ff= repeat_n(print, fa.len()).collect::<LinkedList<_>>();
 
             prop_assert!(flat_map_associativity(fa.clone(), |x| LinkedList::pure(print(x)), |s| LinkedList::pure(parse::<bool>(s))).holds());
             prop_assert!(flat_map_associativity(fa.clone(), |_| LinkedList::new(), |s| LinkedList::pure(parse::<bool>(s))).holds());
@@ -95,5 +96,10 @@ if_std! {
             prop_assert!(monad_right_identity(fa.clone()).holds());
             prop_assert!(map_flat_map_coherence(fa, print).holds());
         }
+
+        #[test]
+        fn test_traverse(fa: LinkedList<bool>) {
+            prop_assert!(traverse_identity(fa, print).holds());
+        }
     }
 }
@@ -0,0 +1,116 @@
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+use rust2fun_laws::monoid_laws::*;
+use rust2fun_laws::semigroup_laws::*;
+
+mod common;
+
+macro_rules! test_monoid {
+    ($name:ident, $t:ty, $wrap:expr) => {
+        proptest! {
+            #[test]
+            fn $name(a: $t, b: $t, c: $t) {
+                let (a, b, c) = ($wrap(a), $wrap(b), $wrap(c));
+
+                prop_assert!(repeat_0(a).holds());
+                prop_assert!(repeat_1(b).holds());
+                prop_assert!(semigroup_associativity(a, b, c).holds());
+                prop_assert!(monoid_left_identity(a).holds());
+                prop_assert!(monoid_right_identity(a).holds());
+                prop_assert!(is_id(a).holds());
+            }
+        }
+    };
+}
+
+test_monoid!(test_sum, u8, |x: u8| Sum(x as u32));
+test_monoid!(test_product, u8, |x: u8| Product(x as u64 % 16));
+test_monoid!(test_min, i32, Min);
+test_monoid!(test_max, i32, Max);
+test_monoid!(test_min_char, char, Min);
+test_monoid!(test_max_bool, bool, Max);
+test_monoid!(test_any, bool, Any);
+test_monoid!(test_all, bool, All);
+test_monoid!(test_dual, i8, |x: i8| Dual(Max(x)));
+test_monoid!(test_first, Option<u8>, |x: Option<u8>| x.map(First));
+test_monoid!(test_last, Option<u8>, |x: Option<u8>| x.map(Last));
+
+proptest! {
+    #[test]
+    fn test_ordering(a: i8, b: i8, c: i8) {
+        let (a, b, c) = (a.cmp(&0), b.cmp(&0), c.cmp(&0));
+
+        prop_assert!(repeat_0(a).holds());
+        prop_assert!(repeat_1(b).holds());
+        prop_assert!(semigroup_associativity(a, b, c).holds());
+        prop_assert!(monoid_left_identity(a).holds());
+        prop_assert!(monoid_right_identity(a).holds());
+        prop_assert!(is_id(a).holds());
+    }
+
+    #[test]
+    fn test_commutativity(a: i16, b: i16, x: f64, y: f64) {
+        prop_assert!(semigroup_commutativity(Sum(a as i32), Sum(b as i32)).holds());
+        prop_assert!(semigroup_commutativity(Product(a as i32), Product(b as i32)).holds());
+        prop_assert!(semigroup_commutativity(Min(a), Min(b)).holds());
+        prop_assert!(semigroup_commutativity(Max(a), Max(b)).holds());
+        prop_assert!(semigroup_commutativity(Min(Total(x)), Min(Total(y))).holds());
+        prop_assert!(semigroup_commutativity(Max(Total(x)), Max(Total(y))).holds());
+        prop_assert!(semigroup_commutativity(Any(a > 0), Any(b > 0)).holds());
+        prop_assert!(semigroup_commutativity(All(a > 0), All(b > 0)).holds());
+    }
+
+    #[test]
+    fn test_dual_reverses(a: String, b: String) {
+        prop_assert_eq!(b.clone() + &a, Dual(a).combine(Dual(b)).into_inner());
+    }
+
+    #[test]
+    fn test_first_last(a: String, b: String, c: String) {
+        prop_assert!(semigroup_associativity(First(a.clone()), First(b.clone()), First(c.clone())).holds());
+        prop_assert!(semigroup_associativity(Last(a.clone()), Last(b.clone()), Last(c)).holds());
+        prop_assert_eq!(a.clone(), First(a.clone()).combine(First(b.clone())).into_inner());
+        prop_assert_eq!(b.clone(), Last(a).combine(Last(b)).into_inner());
+    }
+}
+
+if_std! {
+    use std::collections::{BTreeSet, HashSet};
+
+    proptest! {
+        #[test]
+        fn test_endo(x: i16) {
+            let x = x as i32;
+            let f = || Endo::new(|x: i32| x + 1);
+            let g = || Endo::new(|x: i32| x * 2);
+            let h = || Endo::new(|x: i32| x - 3);
+
+            prop_assert_eq!(f().combine(g()).combine(h()).run(x), f().combine(g().combine(h())).run(x));
+            prop_assert_eq!(f().run(x), Endo::empty().combine(f()).run(x));
+            prop_assert_eq!(f().run(x), f().combine(Endo::empty()).run(x));
+            prop_assert_eq!((x - 3) * 2 + 1, Monoid::combine_all(vec![f(), g(), h()]).run(x));
+        }
+
+        #[test]
+        fn test_union(a: HashSet<u8>, b: HashSet<u8>, c: HashSet<u8>) {
+            let (fa, fb, fc) = (Union(a.clone()), Union(b.clone()), Union(c));
+            prop_assert!(semigroup_associativity(fa.clone(), fb.clone(), fc.clone()).holds());
+            prop_assert!(semigroup_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert!(monoid_left_identity(fa.clone()).holds());
+            prop_assert!(monoid_right_identity(fc.clone()).holds());
+            prop_assert!(is_id(fc).holds());
+            prop_assert_eq!(&a | &b, fa.combine(fb).into_inner());
+        }
+
+        #[test]
+        fn test_intersection(a: BTreeSet<u8>, b: BTreeSet<u8>, c: BTreeSet<u8>) {
+            let (fa, fb, fc) = (Intersection(a.clone()), Intersection(b.clone()), Intersection(c));
+            prop_assert!(semigroup_associativity(fa.clone(), fb.clone(), fc).holds());
+            prop_assert!(semigroup_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert_eq!(&a & &b, fa.combine(fb).into_inner());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use rust2fun::prelude::*;
+    use rust2fun_laws::applicative_laws::*;
+    use rust2fun_laws::apply_laws::*;
+    use rust2fun_laws::flatmap_laws::*;
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::invariant_laws::*;
+    use rust2fun_laws::monad_laws::*;
+    use rust2fun_laws::reducible_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+    use rust2fun_laws::semigroupal_laws::*;
+    use rust2fun_laws::stack_safety_laws::*;
+    use rust2fun_laws::strategies::ne_chain;
+
+    use crate::common::{parse, print};
+
+    #[test]
+    fn test_validated_nec() {
+        fn positive(x: i32) -> ValidatedNec<i32, i32> {
+            if x > 0 { Valid(x) } else { Invalid(NEChain::new(x)) }
+        }
+
+        let actual = (-1000..=0).map(positive).reduce(|acc, x| acc.map2(x, |a, b| a + b));
+        let expected = (-1000..=0).collect::<Vec<_>>();
+        assert_eq!(expected, Vec::from(actual.unwrap().unwrap_err()));
+    }
+
+    #[test]
+    fn test_stack_safety() {
+        assert!(combine_stack_safety(NEChain::new(1), 100_000).holds());
+        assert!(flat_map_stack_safety::<NEChain<_>>(1, 100_000).holds());
+    }
+
+    proptest! {
+        #[test]
+        fn test_conversions(fa in vec(any::<i32>(), 1..9)) {
+            let nevec = NEVec::try_from(fa.clone()).unwrap();
+            let nechain = NEChain::from(nevec.clone());
+
+            prop_assert_eq!(fa.len(), nechain.len());
+            prop_assert_eq!(&fa[0], nechain.first());
+            prop_assert_eq!(nevec, NEVec::from(nechain.clone()));
+            prop_assert_eq!(Some(nechain.clone()), NEChain::from_chain(nechain.clone().into_chain()));
+            prop_assert_eq!(fa, Vec::from(nechain));
+        }
+
+        #[test]
+        fn test_invariant(fa in ne_chain(any::<bool>(), 1..4)) {
+            prop_assert!(invariant_identity(fa.clone()).holds());
+            prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+        }
+
+        #[test]
+        fn test_functor(fa in ne_chain(any::<bool>(), 1..4)) {
+            prop_assert!(covariant_identity(fa.clone()).holds());
+            prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+            prop_assert!(lift_identity(fa.clone()).holds());
+            prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+        }
+
+        #[test]
+        fn test_reducible(fa in ne_chain(any::<String>(), 1..4)) {
+            prop_assert!(reduce_left_consistent_with_iter(fa.clone(), |x, y| x + &y).holds());
+            prop_assert!(reduce_consistent_with_reduce_left(fa.clone()).holds());
+            prop_assert!(reduce_map_consistent_with_reduce_left_to(fa, |s| s.len()).holds());
+        }
+
+        #[test]
+        fn test_semigroup(fa in ne_chain(any::<String>(), 1..4), fb in ne_chain(any::<String>(), 1..4), fc in ne_chain(any::<String>(), 1..4)) {
+            prop_assert!(repeat_0(fa.clone()).holds());
+            prop_assert!(repeat_1(fb.clone()).holds());
+            prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        }
+
+        #[test]
+        fn test_semigroupal(fa in ne_chain(any::<bool>(), 1..4), fb in ne_chain(any::<i32>(), 1..4), fc in ne_chain(any::<u8>(), 1..4)) {
+            prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+        }
+
+        #[test]
+        fn test_apply(fa in ne_chain(any::<String>(), 1..4), fb in ne_chain(any::<usize>(), 1..4)) {
+            prop_assert!(map2_product_consistency(fa.clone(), fb.clone(), |a, b| a.len() == b).holds());
+            prop_assert!(product_r_consistency(fa.clone(), fb.clone()).holds());
+            prop_assert!(product_l_consistency(fa, fb).holds());
+        }
+
+        #[test]
+        fn test_applicative(a: bool) {
+            let fa = NEChain::new(a);
+
+            prop_assert!(applicative_identity(fa.clone()).holds());
+            prop_assert!(applicative_homomorphism::<NEChain<_>, _, _>(a, print).holds());
+            prop_assert!(applicative_map(fa.clone(), print).holds());
+            prop_assert!(ap_product_consistent(fa, NEChain::new(print)).holds());
+            prop_assert!(applicative_unit::<NEChain<_>>(a).holds());
+        }
+
+        #[test]
+        fn test_flatmap(a: bool) {
+            let fa = NEChain::new(a);
+
+            prop_assert!(flat_map_associativity(fa.clone(), |x| ne_chain![print(x)], |s| ne_chain![parse::<bool>(s)]).holds());
+            prop_assert!(flat_map_consistent_apply(fa.clone(), NEChain::new(print)).holds());
+            prop_assert!(m_product_consistency(fa, |x| ne_chain![print(x)]).holds());
+        }
+
+        #[test]
+        fn test_monad(a: bool, fa in ne_chain(any::<bool>(), 1..4)) {
+            prop_assert!(monad_left_identity::<NEChain<_>, _, _>(a, |x| ne_chain![print(x)]).holds());
+            prop_assert!(monad_right_identity(fa.clone()).holds());
+            prop_assert!(map_flat_map_coherence(fa, print).holds());
+        }
+    }
+}
@@ -0,0 +1,69 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use std::collections::HashMap;
+
+    use proptest::collection::hash_map;
+    use proptest::prelude::*;
+
+    use rust2fun::prelude::*;
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::invariant_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+    use rust2fun_laws::strategies::{ne_map, small_key};
+    use rust2fun_laws::traverse_laws::*;
+
+    use crate::common::{parse, print};
+
+    proptest! {
+        #[test]
+        fn test_conversions(fa in hash_map(any::<i8>(), any::<bool>(), 0..9)) {
+            let nemap = NEMap::try_from(fa.clone());
+
+            prop_assert_eq!(fa.is_empty(), nemap.is_err());
+            if let Ok(nemap) = nemap {
+                prop_assert_eq!(fa.len(), nemap.len());
+                prop_assert_eq!(&fa, nemap.as_map());
+                prop_assert_eq!(fa, HashMap::from(nemap));
+            }
+        }
+
+        #[test]
+        fn test_invariant(fa in ne_map(any::<i8>(), any::<bool>(), 1..9)) {
+            prop_assert!(invariant_identity(fa.clone()).holds());
+            prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+        }
+
+        #[test]
+        fn test_functor(fa in ne_map(any::<i8>(), any::<bool>(), 1..9)) {
+            prop_assert!(covariant_identity(fa.clone()).holds());
+            prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+            prop_assert!(lift_identity(fa.clone()).holds());
+            prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+        }
+
+        #[test]
+        fn test_semigroup(fa in ne_map(small_key(), "[a-z]{0,3}", 1..=4), fb in ne_map(small_key(), "[a-z]{0,3}", 1..=4), fc in ne_map(small_key(), "[a-z]{0,3}", 1..=4)) {
+            prop_assert!(repeat_0(fa.clone()).holds());
+            prop_assert!(repeat_1(fb.clone()).holds());
+            prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        }
+
+        #[test]
+        fn test_traverse(fa in ne_map(any::<i8>(), any::<i8>(), 1..9)) {
+            prop_assert!(traverse_identity(fa.clone(), print).holds());
+
+            let errors = fa.values().filter(|x| **x < 0).count();
+            let actual = fa.clone().non_empty_traverse(|x| {
+                if x < 0 { Invalid(vec![x]) } else { Valid(x) }
+            });
+            if errors == 0 {
+                prop_assert_eq!(Valid(fa), actual);
+            } else {
+                prop_assert_eq!(errors, actual.unwrap_err().len());
+            }
+        }
+    }
+}
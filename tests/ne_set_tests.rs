@@ -0,0 +1,73 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use std::collections::HashSet;
+
+    use proptest::collection::hash_set;
+    use proptest::prelude::*;
+
+    use rust2fun::prelude::*;
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::invariant_laws::*;
+    use rust2fun_laws::reducible_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+    use rust2fun_laws::strategies::ne_set;
+
+    use crate::common::{parse, print};
+
+    proptest! {
+        #[test]
+        fn test_conversions(fa in hash_set(any::<i8>(), 0..9)) {
+            let neset = NESet::try_from(fa.clone());
+
+            prop_assert_eq!(fa.is_empty(), neset.is_err());
+            if let Ok(neset) = neset {
+                prop_assert_eq!(fa.len(), neset.len());
+                prop_assert_eq!(&fa, neset.as_set());
+                prop_assert_eq!(fa, HashSet::from(neset));
+            }
+        }
+
+        #[test]
+        fn test_set_operations(fa in ne_set(any::<i8>(), 1..9), fb in ne_set(any::<i8>(), 1..9)) {
+            let union = fa.as_set() | fb.as_set();
+            let intersection = fa.as_set() & fb.as_set();
+            let difference = fa.as_set() - fb.as_set();
+
+            prop_assert_eq!(union, fa.clone().union(fb.clone()).into_set());
+            prop_assert_eq!(intersection.is_empty(), fa.is_disjoint(&fb));
+            prop_assert_eq!(NESet::from_set(intersection), fa.clone().intersection(fb.clone()));
+            prop_assert_eq!(difference.is_empty(), fa.is_subset(&fb));
+            prop_assert_eq!(NESet::from_set(difference), fa.difference(fb));
+        }
+
+        #[test]
+        fn test_invariant(fa in ne_set(any::<bool>(), 1..9)) {
+            prop_assert!(invariant_identity(fa.clone()).holds());
+            prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+        }
+
+        #[test]
+        fn test_functor(fa in ne_set(any::<bool>(), 1..9)) {
+            prop_assert!(covariant_identity(fa.clone()).holds());
+            prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+            prop_assert!(lift_identity(fa.clone()).holds());
+            prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+        }
+
+        #[test]
+        fn test_reducible(fa in ne_set(any::<u8>(), 1..9)) {
+            prop_assert!(reduce_left_consistent_with_iter(fa.clone(), u8::wrapping_add).holds());
+            prop_assert!(reduce_map_consistent_with_reduce_left_to(fa, |x| x as u32).holds());
+        }
+
+        #[test]
+        fn test_semigroup(fa in ne_set(any::<String>(), 1..9), fb in ne_set(any::<String>(), 1..9), fc in ne_set(any::<String>(), 1..9)) {
+            prop_assert!(repeat_0(fa.clone()).holds());
+            prop_assert!(repeat_1(fb.clone()).holds());
+            prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        }
+    }
+}
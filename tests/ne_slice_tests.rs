@@ -0,0 +1,42 @@
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_from_slice(xs: Vec<u8>) {
+        let actual = NESlice::from_slice(&xs);
+
+        prop_assert_eq!(xs.is_empty(), actual.is_none());
+        if let Some(actual) = actual {
+            prop_assert_eq!(&xs[..], &*actual);
+            prop_assert_eq!(xs.first(), Some(actual.first()));
+            prop_assert_eq!(xs.last(), Some(actual.last()));
+            prop_assert_eq!(xs.split_first(), Some(actual.split_first()));
+            prop_assert_eq!(xs.split_last(), Some(actual.split_last()));
+        }
+    }
+
+    #[test]
+    fn test_windows(xs in proptest::collection::vec(any::<u8>(), 1..9), size in 1..10usize) {
+        let actual = NESlice::from_slice(&xs).unwrap();
+        let windows = actual.windows(core::num::NonZeroUsize::new(size).unwrap());
+
+        prop_assert_eq!(xs.windows(size).len(), windows.len());
+        prop_assert!(xs.windows(size).eq(windows.clone().map(|w| w.as_slice())));
+        prop_assert!(xs.windows(size).rev().eq(windows.rev().map(|w| w.as_slice())));
+    }
+}
+
+if_std! {
+    proptest! {
+        #[test]
+        fn test_to_ne_vec(xs in proptest::collection::vec(any::<u8>(), 1..9)) {
+            let actual = NESlice::from_slice(&xs).unwrap().to_ne_vec();
+
+            prop_assert_eq!(xs, actual.into_vec());
+        }
+    }
+}
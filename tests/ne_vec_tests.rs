@@ -9,15 +9,31 @@ if_std! {
     use rust2fun::prelude::*;
     use rust2fun_laws::applicative_laws::*;
     use rust2fun_laws::apply_laws::*;
+    use rust2fun_laws::comonad_laws::*;
     use rust2fun_laws::flatmap_laws::*;
     use rust2fun_laws::functor_laws::*;
     use rust2fun_laws::invariant_laws::*;
     use rust2fun_laws::monad_laws::*;
+    use rust2fun_laws::reducible_laws::*;
     use rust2fun_laws::semigroup_laws::*;
     use rust2fun_laws::semigroupal_laws::*;
 
     use crate::common::{parse, print};
 
+    #[test]
+    fn test_conversions_reuse_buffer() {
+        let xs = vec![1, 2, 3];
+        let ptr = xs.as_ptr();
+        let fa = NEVec::from_vec(xs).unwrap();
+        assert_eq!(ptr, fa.as_ptr());
+
+        let xs = fa.into_vec();
+        assert_eq!(ptr, xs.as_ptr());
+        let fa: NEVec<_> = xs.try_into().unwrap();
+        assert_eq!(ptr, fa.as_ptr());
+        assert_eq!(ne_vec![1, 2, 3], fa);
+    }
+
     proptest! {
         #[test]
         fn test_invariant(fa in vec(any::<bool>(), 1..9)) {
@@ -37,6 +53,33 @@ if_std! {
             prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
         }
 
+        #[test]
+        fn test_non_empty_traverse(fa in vec(any::<i8>(), 1..9)) {
+            let fa: NEVec<_> = fa.try_into().unwrap();
+            let errors: Vec<_> = fa.iter().copied().filter(|x| *x < 0).collect();
+
+            prop_assert_eq!(Some(fa.clone()), fa.clone().non_empty_traverse(Some));
+            prop_assert_eq!(fa.clone().map(Some).non_empty_sequence(), Some(fa.clone()));
+
+            let actual = fa.clone().non_empty_traverse(|x| {
+                if x < 0 { Invalid(vec![x]) } else { Valid(x) }
+            });
+            if errors.is_empty() {
+                prop_assert_eq!(Valid(fa), actual);
+            } else {
+                prop_assert_eq!(Invalid(errors), actual);
+            }
+        }
+
+        #[test]
+        fn test_reducible(fa in vec(any::<String>(), 1..9)) {
+            let fa: NEVec<_> = fa.try_into().unwrap();
+
+            prop_assert!(reduce_left_consistent_with_iter(fa.clone(), |x, y| x + &y).holds());
+            prop_assert!(reduce_consistent_with_reduce_left(fa.clone()).holds());
+            prop_assert!(reduce_map_consistent_with_reduce_left_to(fa, |s| s.len()).holds());
+        }
+
         #[test]
         fn test_semigroup(fa in vec(any::<String>(), 1..9),
                           fb in vec(any::<String>(), 1..9),
@@ -58,7 +101,8 @@ if_std! {
             let fb: NEVec<_> = fb.try_into().unwrap();
             let fc: NEVec<_> = fc.try_into().unwrap();
 
-            prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+            prop_assert!(semigroupal_associativity(fa.clone(), fb.clone(), fc).holds());
+            prop_assert!(product_ref_consistency(fa, fb).holds());
         }
 
         #[test]
@@ -100,5 +144,260 @@ if_std! {
             prop_assert!(monad_right_identity(fa.clone()).holds());
             prop_assert!(map_flat_map_coherence(fa, print).holds());
         }
+
+        #[test]
+        fn test_comonad(fa in vec(any::<i8>(), 1..9)) {
+            let fa: NEVec<_> = fa.try_into().unwrap();
+            let len = |xs: NEVec<i8>| xs.len();
+            let sum = |xs: NEVec<usize>| xs.into_iter().sum::<usize>();
+
+            prop_assert!(comonad_left_identity(fa.clone()).holds());
+            prop_assert!(comonad_right_identity(fa.clone(), len).holds());
+            prop_assert!(coflat_map_associativity(fa, len, sum).holds());
+        }
+
+        #[test]
+        fn test_sort(xs in vec(any::<(i8, u8)>(), 1..9)) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+            let mut expected = xs.clone();
+            expected.sort_by_key(|x| x.0 / 8);
+            let mut actual = fa.clone();
+            actual.sort_by_key(|x| x.0 / 8);
+            prop_assert_eq!(expected, actual.clone().into_vec());
+            prop_assert!(actual.is_sorted_by_key(|x| x.0 / 8));
+
+            let mut expected = xs;
+            expected.sort_unstable();
+            let mut actual = fa;
+            actual.sort_unstable();
+            prop_assert_eq!(expected, actual.clone().into_vec());
+            prop_assert!(actual.is_sorted());
+        }
+
+        #[test]
+        fn test_slice(xs in vec(any::<u8>(), 1..9)) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+            prop_assert_eq!(&xs[..], fa.as_slice());
+            prop_assert_eq!(&xs[..], &*fa.as_ne_slice());
+            prop_assert_eq!(&xs[0], fa.head());
+            prop_assert_eq!(&xs[1..], fa.tail());
+            prop_assert_eq!(fa.clone(), fa.as_ne_slice().to_ne_vec());
+        }
+
+        #[test]
+        fn test_zip(xs in vec(any::<u8>(), 1..9), ys in vec(any::<bool>(), 1..9)) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+            let fb: NEVec<_> = ys.clone().try_into().unwrap();
+            let expected: Vec<_> = xs.into_iter().zip(ys).collect();
+
+            let zipped = fa.clone().zip(fb.clone());
+            prop_assert_eq!(expected.clone(), zipped.clone().into_vec());
+            prop_assert_eq!(zipped.clone(), fa.zip_with(fb, |x, y| (x, y)));
+
+            let (left, right) = zipped.unzip();
+            prop_assert_eq!(expected, left.zip(right).into_vec());
+        }
+
+        #[test]
+        fn test_flatten(xss in vec(vec(any::<u8>(), 1..4), 1..4)) {
+            let expected: Vec<_> = xss.concat();
+            let fa: NEVec<NEVec<_>> = xss.into_iter().map(|xs| xs.try_into().unwrap()).collect();
+
+            prop_assert_eq!(expected.clone(), fa.concat().into_vec());
+            prop_assert_eq!(
+                expected.clone(),
+                fa.iter().map(NEVec::as_ne_slice).collect::<NEVec<_>>().concat().into_vec()
+            );
+            prop_assert_eq!(expected, fa.flatten().into_vec());
+        }
+
+        #[test]
+        fn test_mutation(xs in vec(any::<u8>(), 1..9), ys: Vec<u8>, len in 1..9usize) {
+            let mut fa: NEVec<_> = xs.clone().try_into().unwrap();
+            let mut expected = xs;
+
+            fa.append(&mut ys.clone());
+            expected.extend(ys);
+            prop_assert_eq!(expected.as_slice(), fa.as_slice());
+
+            fa.push(0);
+            expected.push(0);
+            prop_assert_eq!(expected.pop(), fa.pop());
+
+            fa.truncate(core::num::NonZeroUsize::new(len).unwrap());
+            expected.truncate(len);
+            prop_assert_eq!(expected.as_slice(), fa.as_slice());
+
+            fa.clear_tail();
+            expected.truncate(1);
+            prop_assert_eq!(expected.as_slice(), fa.as_slice());
+            prop_assert_eq!(None, fa.pop());
+        }
+
+        #[test]
+        fn test_split(xs in vec(any::<u8>(), 1..9), mid in 1..9usize) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+            prop_assert_eq!(xs.split_first().unwrap(), fa.split_first());
+            prop_assert_eq!(xs.split_last().unwrap(), fa.split_last());
+
+            let mid = mid.min(xs.len());
+            let (left, right) = fa.split_at(core::num::NonZeroUsize::new(mid).unwrap());
+            prop_assert_eq!(&xs[..mid], left.as_slice());
+            prop_assert_eq!(&xs[mid..], right.as_slice());
+        }
+
+        #[test]
+        fn test_min_max(xs in vec(any::<i8>(), 1..9)) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+            let key = |x: &i8| x.unsigned_abs();
+
+            prop_assert_eq!(xs.iter().copied().max().unwrap(), fa.clone().maximum());
+            prop_assert_eq!(xs.iter().copied().min().unwrap(), fa.clone().minimum());
+            prop_assert_eq!(fa.clone(), fa.clone().max(NEVec::new(i8::MIN)));
+            prop_assert_eq!(NEVec::new(i8::MIN), fa.clone().min(NEVec::new(i8::MIN)));
+            prop_assert_eq!(xs.iter().copied().max_by_key(key).unwrap(), fa.clone().max_by_key(key));
+            prop_assert_eq!(xs.iter().copied().min_by_key(key).unwrap(), fa.clone().min_by_key(key));
+            prop_assert_eq!(
+                xs.iter().copied().reduce(i8::wrapping_sub).unwrap(),
+                fa.fold1(i8::wrapping_sub)
+            );
+        }
+
+        #[test]
+        fn test_search(mut xs in vec(any::<u8>(), 1..9), x: u8) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+            prop_assert_eq!(xs.contains(&x), fa.contains(&x));
+            prop_assert_eq!(xs.iter().position(|y| *y == x), fa.position(|y| *y == x));
+
+            xs.sort();
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+            prop_assert_eq!(xs.binary_search(&x).is_ok(), fa.binary_search(&x).is_ok());
+            match fa.binary_search_by(|y| y.cmp(&x)) {
+                Ok(i) => prop_assert_eq!(x, fa[i]),
+                Err(i) => {
+                    prop_assert!(xs[..i].iter().all(|y| *y < x));
+                    prop_assert!(xs[i..].iter().all(|y| *y > x));
+                }
+            }
+        }
+
+        #[test]
+        fn test_group_by(xs in vec(0..3u8, 1..9), size in 1..4usize) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+            let groups = fa.clone().group_by(|x, y| x == y);
+            prop_assert_eq!(&xs, &groups.clone().flatten().into_vec());
+            prop_assert!(groups.iter().all(|g| g.iter().all(|x| x == g.head())));
+            prop_assert!(groups.as_slice().windows(2).all(|w| w[0].last() != w[1].head()));
+
+            let chunks = fa.chunks(core::num::NonZeroUsize::new(size).unwrap());
+            let expected: Vec<Vec<u8>> = xs.chunks(size).map(|c| c.to_vec()).collect();
+            let actual: Vec<Vec<u8>> = chunks.into_iter().map(NEVec::into_vec).collect();
+            prop_assert_eq!(expected, actual);
+        }
+
+        #[test]
+        fn test_iterators(xs in vec(any::<u8>(), 1..9)) {
+            fn assert_iter<I>(iter: I, expected: Vec<I::Item>) -> Result<(), TestCaseError>
+            where
+                I: DoubleEndedIterator + ExactSizeIterator + core::iter::FusedIterator + Clone,
+                I::Item: PartialEq + core::fmt::Debug,
+            {
+                prop_assert_eq!(expected.len(), iter.len());
+                let mut rev = expected;
+                rev.reverse();
+                prop_assert_eq!(rev, iter.clone().rev().collect::<Vec<_>>());
+                let mut iter = iter.skip(usize::MAX);
+                prop_assert!(iter.next().is_none());
+                prop_assert!(iter.next().is_none());
+                Ok(())
+            }
+
+            let mut fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+            assert_iter(fa.iter(), xs.iter().collect())?;
+            assert_iter(fa.clone().into_iter(), xs.clone())?;
+
+            let mut iter = fa.iter_mut();
+            prop_assert_eq!(xs.len(), iter.len());
+            prop_assert_eq!(xs.last(), iter.next_back().map(|x| &*x));
+            for x in fa.iter_mut().rev() {
+                *x = x.wrapping_add(1);
+            }
+            prop_assert!(xs.iter().zip(fa.iter()).all(|(x, y)| x.wrapping_add(1) == *y));
+        }
+
+        #[test]
+        fn test_capacity(x: u8, capacity in 0..32usize, additional in 0..32usize) {
+            let mut fa = NEVec::with_capacity(x, capacity);
+            prop_assert!(fa.capacity() >= capacity.max(1));
+
+            fa.reserve(additional);
+            prop_assert!(fa.capacity() > additional);
+            fa.reserve_exact(additional + 1);
+            prop_assert!(fa.capacity() >= 2 + additional);
+
+            fa.push(x);
+            fa.shrink_to_fit();
+            prop_assert!(fa.capacity() >= 2);
+            prop_assert_eq!(fa, [x, x]);
+        }
+
+        #[test]
+        fn test_filter(xs in vec(any::<u8>(), 1..9)) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+            let even = |x: &u8| x.is_multiple_of(2);
+            let expected: Vec<u8> = xs.iter().copied().filter(even).collect();
+
+            prop_assert_eq!(&expected, &fa.clone().retain(even));
+            prop_assert_eq!(NEVec::from_vec(expected.clone()), fa.clone().filter(even));
+            prop_assert_eq!(
+                NEVec::from_vec(expected),
+                fa.filter_map_ne(|x| even(&x).then_some(x))
+            );
+        }
+
+        #[test]
+        fn test_windows(xs in vec(any::<u8>(), 1..9), size in 1..10usize) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+            let windows = fa.windows(core::num::NonZeroUsize::new(size).unwrap());
+
+            prop_assert!(xs.windows(size).eq(windows.map(|w| w.as_slice())));
+        }
+
+        #[test]
+        fn test_try_from_iter(xs: Vec<u8>) {
+            let expected = NEVec::from_vec(xs.clone());
+
+            prop_assert_eq!(&expected, &NEVec::try_from_iter(xs.clone()));
+            prop_assert_eq!(&expected, &xs.into_iter().collect_nonempty());
+        }
+
+        #[test]
+        fn test_from_array(xs: [u8; 3]) {
+            prop_assert_eq!(NEVec::from(xs), xs);
+            prop_assert_eq!(NEVec::from(xs), NESlice::from_array(&xs).to_ne_vec());
+        }
+
+        #[test]
+        fn test_dedup(xs in vec(0..3u8, 1..9)) {
+            let fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+            let mut expected = xs.clone();
+            expected.dedup();
+            let mut actual = fa.clone();
+            actual.dedup();
+            prop_assert_eq!(expected, actual.into_vec());
+
+            let mut expected = xs;
+            expected.dedup_by_key(|x| *x / 2);
+            let mut actual = fa;
+            actual.dedup_by_key(|x| *x / 2);
+            prop_assert_eq!(expected, actual.into_vec());
+        }
     }
 }
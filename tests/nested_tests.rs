@@ -0,0 +1,80 @@
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+use rust2fun_laws::applicative_laws::*;
+use rust2fun_laws::apply_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::traverse_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_invariant(fa: Vec<Option<bool>>) {
+        let fa = Nested::new(fa);
+        prop_assert!(invariant_identity(fa.clone()).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa: Vec<Option<bool>>) {
+        let fa = Nested::new(fa);
+        prop_assert!(covariant_identity(fa.clone()).holds());
+        prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+        prop_assert!(lift_identity(fa.clone()).holds());
+        prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+    }
+
+    #[test]
+    fn test_semigroupal(fa: Vec<Option<bool>>, fb: Vec<Option<i32>>, fc: Vec<Option<u8>>) {
+        let (fa, fb, fc) = (Nested::new(fa), Nested::new(fb), Nested::new(fc));
+        prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+    }
+
+    #[test]
+    fn test_apply(fa: Option<Result<String, i32>>, fb: Option<Result<usize, i32>>) {
+        let (fa, fb) = (Nested::new(fa), Nested::new(fb));
+        prop_assert!(map2_product_consistency(fa.clone(), fb, |a, b| a.len() == b).holds());
+        prop_assert!(product_r_consistency(fa.clone(), fb).holds());
+        prop_assert!(product_l_consistency(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_applicative(fa: Option<Result<bool, i32>>, a: bool) {
+        let fa = Nested::new(fa);
+        prop_assert!(applicative_identity(fa).holds());
+        prop_assert!(applicative_map(fa, print).holds());
+        prop_assert!(applicative_unit::<Nested<Option<Result<bool, i32>>, _>>(a).holds());
+    }
+
+    #[test]
+    fn test_traverse(fa: Vec<Option<bool>>) {
+        prop_assert!(traverse_identity(Nested::new(fa), print).holds());
+    }
+
+    #[test]
+    fn test_map_k(fa: Vec<Result<bool, i32>>) {
+        let expected: Vec<_> = fa.iter().map(|x| x.ok()).collect();
+        prop_assert_eq!(&expected, &map_k(fa.clone(), OkToOption));
+        prop_assert_eq!(expected, Nested::new(fa).map_k(Result::ok).into_inner());
+    }
+}
+
+#[test]
+fn test_traverse_effect() {
+    let xs = Nested::new(vec![Some("1"), None, Some("3")]);
+    let actual = xs.traverse(|s| s.parse::<i32>().ok());
+    assert_eq!(
+        Some(vec![Some(1), None, Some(3)]),
+        actual.map(Nested::into_inner)
+    );
+
+    let xs = Nested::new(vec![Some("1"), Some("foo")]);
+    assert_eq!(None, xs.traverse(|s| s.parse::<i32>().ok()));
+}
@@ -0,0 +1,147 @@
+extern crate rust2fun_laws;
+
+use std::num::{NonZeroU8, Saturating, Wrapping};
+use std::time::Duration;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+use rust2fun_laws::group_laws::*;
+use rust2fun_laws::monoid_laws::*;
+use rust2fun_laws::semigroup_laws::*;
+
+mod common;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Meters(i32);
+monoid_imap!(Meters, i32, Meters, |m: Meters| m.0);
+
+proptest! {
+    #[test]
+    fn test_semigroup(fa in -1000i32..1000, fb in -1000i32..1000, fc in -1000i32..1000) {
+        prop_assert!(repeat_0(fa).holds());
+        prop_assert!(repeat_1(fb).holds());
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        prop_assert!(semigroup_commutativity(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_monoid(fa: u8) {
+        prop_assert!(monoid_left_identity(fa).holds());
+        prop_assert!(monoid_right_identity(fa).holds());
+        prop_assert!(is_id(fa).holds());
+    }
+
+    #[test]
+    fn test_group(fa in -1000i64..1000, fb in -1000i64..1000) {
+        prop_assert!(group_left_inverse(fa).holds());
+        prop_assert!(group_right_inverse(fa).holds());
+        prop_assert!(group_consistent_remove(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_tuple_group(fa in (-1000i32..1000, -1000i16..1000), fb in (-1000i32..1000, -1000i16..1000)) {
+        prop_assert!(semigroup_commutativity(fa, fb).holds());
+        prop_assert!(group_left_inverse(fa).holds());
+        prop_assert!(group_right_inverse(fa).holds());
+        prop_assert!(group_consistent_remove(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_monoid_imap(fa in -1000i32..1000, fb in -1000i32..1000, fc in -1000i32..1000) {
+        let (fa, fb, fc) = (Meters(fa), Meters(fb), Meters(fc));
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        prop_assert!(monoid_left_identity(fa).holds());
+        prop_assert!(monoid_right_identity(fa).holds());
+        prop_assert!(is_id(fa).holds());
+    }
+
+    #[test]
+    fn test_wrapping(fa: i8, fb: i8, fc: i8) {
+        let (fa, fb, fc) = (Wrapping(fa), Wrapping(fb), Wrapping(fc));
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        prop_assert!(semigroup_commutativity(fa, fb).holds());
+        prop_assert!(monoid_left_identity(fa).holds());
+        prop_assert!(monoid_right_identity(fa).holds());
+        prop_assert!(is_id(fa).holds());
+    }
+
+    #[test]
+    fn test_saturating(fa: u8, fb: u8, fc: u8) {
+        let (fa, fb, fc) = (Saturating(fa), Saturating(fb), Saturating(fc));
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        prop_assert!(semigroup_commutativity(fa, fb).holds());
+        prop_assert!(monoid_left_identity(fa).holds());
+        prop_assert!(monoid_right_identity(fa).holds());
+        prop_assert!(is_id(fa).holds());
+    }
+
+    #[test]
+    fn test_non_zero(fa in 1u8.., fb in 1u8.., fc in 1u8..) {
+        let non_zero = |x| NonZeroU8::new(x).unwrap();
+        let (fa, fb, fc) = (non_zero(fa), non_zero(fb), non_zero(fc));
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        prop_assert!(semigroup_commutativity(fa, fb).holds());
+        prop_assert!(monoid_left_identity(fa).holds());
+        prop_assert!(monoid_right_identity(fa).holds());
+        prop_assert!(is_id(fa).holds());
+    }
+
+    #[test]
+    fn test_duration(fa: u64, fb: u64, fc: u64, nanos in 0u32..1_000_000_000) {
+        let (fa, fb, fc) = (Duration::new(fa, nanos), Duration::from_secs(fb), Duration::from_nanos(fc));
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds());
+        prop_assert!(semigroup_commutativity(fa, fb).holds());
+        prop_assert!(monoid_left_identity(fa).holds());
+        prop_assert!(monoid_right_identity(fa).holds());
+        prop_assert!(is_id(fa).holds());
+    }
+
+    #[test]
+    fn test_float(fa in -1e6f64..1e6, fb in -1e6f64..1e6, fc in -1e6f64..1e6) {
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds_approx(1e-9));
+        prop_assert!(semigroup_associativity(Some(fa), Some(fb), None).holds_approx(1e-9));
+        prop_assert!(semigroup_associativity((fa, 1), (fb, 2), (fc, 3)).holds_approx(1e-9));
+        prop_assert!(semigroup_associativity(fa, fb, fc).holds_by(|x, y| (x - y).abs() < 1e-3));
+        prop_assert!(semigroup_commutativity(fa as f32, fb as f32).holds_approx(0.0));
+        prop_assert!(monoid_left_identity(fa).holds_approx(0.0));
+        prop_assert!(monoid_right_identity(fa).holds_approx(0.0));
+    }
+
+    #[test]
+    fn test_eqv(fa: f64, fb: f64, fc: f64) {
+        prop_assert!(semigroup_associativity(Max(fa), Max(fb), Max(fc)).holds_eqv());
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Max(f64);
+
+impl Semigroup for Max {
+    fn combine(self, other: Self) -> Self {
+        Max(self.0.max(other.0))
+    }
+}
+
+impl rust2fun::eq::Eq for Max {
+    fn eqv(&self, other: &Self) -> bool {
+        self.0 == other.0 || self.0.is_nan() && other.0.is_nan()
+    }
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(Wrapping(44u8), Wrapping(200).combine(Wrapping(100)));
+    assert_eq!(Saturating(255u8), Saturating(200).combine(Saturating(100)));
+    assert_eq!(
+        NonZeroU8::MAX,
+        NonZeroU8::new(16)
+            .unwrap()
+            .combine(NonZeroU8::new(16).unwrap())
+    );
+    assert_eq!(
+        Wrapping(0u8),
+        Monoid::combine_all(vec![Wrapping(128u8), Wrapping(128)])
+    );
+    assert_eq!(Duration::MAX, Duration::MAX.combine(Duration::from_secs(1)));
+}
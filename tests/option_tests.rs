@@ -2,15 +2,21 @@ extern crate rust2fun_laws;
 
 use proptest::prelude::*;
 
+use rust2fun::data::Ior;
+
+use rust2fun_laws::align_laws::*;
+use rust2fun_laws::and_then_laws::*;
 use rust2fun_laws::applicative_laws::*;
 use rust2fun_laws::apply_laws::*;
 use rust2fun_laws::flatmap_laws::*;
 use rust2fun_laws::functor_laws::*;
 use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_error_laws::*;
 use rust2fun_laws::monad_laws::*;
 use rust2fun_laws::monoid_laws::*;
 use rust2fun_laws::semigroup_laws::*;
 use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::traverse_laws::*;
 
 use crate::common::{parse, print};
 
@@ -31,6 +37,13 @@ proptest! {
         prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
     }
 
+    #[test]
+    fn test_align(fa: Option<bool>, fb: Option<String>) {
+        prop_assert!(align_idempotency(fa).holds());
+        prop_assert!(align_commutativity(fa, fb.clone()).holds());
+        prop_assert!(align_with_consistency(fa, fb, Ior::pad).holds());
+    }
+
     #[test]
     fn test_semigroup(fa: Option<String>, fb: Option<String>, fc: Option<String>) {
         prop_assert!(repeat_0(fa.clone()).holds());
@@ -77,6 +90,8 @@ proptest! {
         prop_assert!(flat_map_consistent_apply(fa, None::<fn(bool) -> String>).holds());
         prop_assert!(m_product_consistency(fa, |x| Some(print(x))).holds());
         prop_assert!(m_product_consistency(fa, |_| None::<String>).holds());
+        prop_assert!(and_then_flat_map_consistency(fa, |x| Some(print(x))).holds());
+        prop_assert!(and_then_flat_map_consistency(fa, |_| None::<String>).holds());
     }
 
     #[test]
@@ -86,4 +101,21 @@ proptest! {
         prop_assert!(monad_right_identity(fa).holds());
         prop_assert!(map_flat_map_coherence(fa, print).holds());
     }
+
+    #[test]
+    fn test_monad_error(a: bool, fa: Option<bool>) {
+        prop_assert!(handle_error_with_raise_error((), |_| Some(a)).holds());
+        prop_assert!(handle_error_raise_error::<Option<_>, _, _>((), |_| a).holds());
+        prop_assert!(handle_error_with_pure::<Option<_>, _, _>(a, |_| None).holds());
+        prop_assert!(raise_error_attempt::<Option<bool>, _>(()).holds());
+        prop_assert!(pure_attempt::<Option<_>, ()>(a).holds());
+        prop_assert!(attempt_rethrow::<_, ()>(fa).holds());
+        prop_assert!(monad_error_left_zero::<Option<bool>, _, _, _>((), |x| Some(print(x))).holds());
+        prop_assert!(ensure_consistency(fa, (), |x| *x).holds());
+    }
+
+    #[test]
+    fn test_traverse(fa: Option<bool>) {
+        prop_assert!(traverse_identity(fa, print).holds());
+    }
 }
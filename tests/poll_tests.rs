@@ -0,0 +1,94 @@
+extern crate rust2fun_laws;
+
+use core::task::Poll;
+
+use rust2fun::prelude::*;
+
+use rust2fun_laws::applicative_laws::*;
+use rust2fun_laws::apply_laws::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::traverse_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+#[test]
+fn test_invariant() {
+    assert!(invariant_identity(Poll::Ready(1)).holds());
+    assert!(invariant_identity(Poll::<i32>::Pending).holds());
+    assert!(invariant_composition(Poll::Ready(1), print, parse, parse::<i32>, print).holds());
+}
+
+#[test]
+fn test_functor() {
+    assert!(covariant_identity(Poll::Ready(1)).holds());
+    assert!(covariant_composition(Poll::Ready(1), print, parse::<u32>).holds());
+    assert!(covariant_composition(Poll::<i32>::Pending, print, parse::<u32>).holds());
+    assert!(lift_identity(Poll::Ready(1)).holds());
+    assert!(lift_composition(Poll::Ready(1), print, parse::<i64>).holds());
+}
+
+#[test]
+fn test_semigroupal() {
+    assert!(semigroupal_associativity(Poll::Ready(1), Poll::Ready("a"), Poll::Ready(true)).holds());
+    assert!(
+        semigroupal_associativity(Poll::Ready(1), Poll::<&str>::Pending, Poll::Ready(true)).holds()
+    );
+}
+
+#[test]
+fn test_apply() {
+    let check_length = |x: &str, l: usize| x.len() == l;
+
+    assert!(map2_product_consistency(Poll::Ready("str"), Poll::Ready(1), check_length).holds());
+    assert!(map2_product_consistency(Poll::Pending, Poll::Ready(1), check_length).holds());
+    assert!(product_r_consistency(Poll::Ready("str"), Poll::<i32>::Pending).holds());
+    assert!(product_l_consistency(Poll::Ready("str"), Poll::Ready(1)).holds());
+}
+
+#[test]
+fn test_applicative() {
+    assert!(applicative_identity(Poll::Ready(1)).holds());
+    assert!(applicative_homomorphism::<Poll<_>, _, _>(1, print).holds());
+    assert!(applicative_map(Poll::Ready(1), print).holds());
+    assert!(ap_product_consistent(Poll::Ready(1), Poll::Ready(print)).holds());
+    assert!(applicative_unit::<Poll<_>>(1).holds());
+}
+
+#[test]
+fn test_flatmap() {
+    let pending_if_odd = |x: i32| {
+        if x % 2 == 0 {
+            Poll::Ready(x)
+        } else {
+            Poll::Pending
+        }
+    };
+
+    assert!(flat_map_associativity(Poll::Ready(1), |x| Poll::Ready(x + 1), pending_if_odd).holds());
+    assert!(flat_map_consistent_apply(Poll::Ready(1), Poll::Ready(print)).holds());
+    assert!(m_product_consistency(Poll::Ready(1), pending_if_odd).holds());
+}
+
+#[test]
+fn test_monad() {
+    assert!(monad_left_identity::<Poll<_>, _, _>(1, |x| Poll::Ready(x + 1)).holds());
+    assert!(monad_right_identity(Poll::Ready(1)).holds());
+    assert!(monad_right_identity(Poll::<i32>::Pending).holds());
+}
+
+#[test]
+fn test_traverse() {
+    assert!(traverse_identity(Poll::Ready(true), print).holds());
+    assert!(traverse_identity(Poll::<bool>::Pending, print).holds());
+
+    let actual = Poll::Ready("42").traverse(|s| s.parse::<i32>().ok());
+    assert_eq!(Some(Poll::Ready(42)), actual);
+    let actual = Poll::<&str>::Pending.traverse(|s| s.parse::<i32>().ok());
+    assert_eq!(Some(Poll::Pending), actual);
+}
@@ -0,0 +1,71 @@
+mod common;
+
+if_std! {
+    use rust2fun::prelude::*;
+
+    #[test]
+    fn test_divide() {
+        let mut p = Predicate::new(|x: i32| x > 0)
+            .divide(Predicate::new(|s: String| s.len() < 3), |(x, s): (i32, String)| (x, s));
+
+        assert!(p.test((1, "ab".to_string())));
+        assert!(!p.test((0, "ab".to_string())));
+        assert!(!p.test((1, "abc".to_string())));
+    }
+
+    #[test]
+    fn test_divisible_identity() {
+        let mut left = Predicate::new(|x: i32| x > 0).divide(Predicate::conquer(), |x| (x, ()));
+        let mut right = Predicate::conquer().divide(Predicate::new(|x: i32| x > 0), |x| ((), x));
+
+        for x in -2..3 {
+            assert_eq!(x > 0, left.test(x));
+            assert_eq!(x > 0, right.test(x));
+        }
+    }
+
+    #[test]
+    fn test_decidable() {
+        let mut p = Predicate::new(|x: i32| x > 0).choose(Predicate::new(|s: &str| s.is_empty()), |s: &str| {
+            s.parse().map_err(|_| s)
+        });
+
+        assert!(p.test("1"));
+        assert!(!p.test("-1"));
+        assert!(!p.test("foo"));
+    }
+
+    #[test]
+    fn test_boolean_operators() {
+        let positive = || Predicate::new(|x: i32| x > 0);
+        let even = || Predicate::new(|x: i32| x % 2 == 0);
+        let mut and = positive().and(even());
+        let mut or = positive().or(even());
+        let mut xor = positive().xor(even());
+        let mut not = !positive();
+
+        for x in -4..5 {
+            assert_eq!(x > 0 && x % 2 == 0, and.test(x));
+            assert_eq!(x > 0 || x % 2 == 0, or.test(x));
+            assert_eq!((x > 0) ^ (x % 2 == 0), xor.test(x));
+            assert_eq!(x <= 0, not.test(x));
+        }
+    }
+
+    #[test]
+    fn test_monoids() {
+        let mut all = Conjunction::<i32>::empty().into_inner();
+        let mut any = Disjunction::<i32>::empty().into_inner();
+        assert!(all.test(0));
+        assert!(!any.test(0));
+
+        let checks = || (1..4).map(|d| Predicate::new(move |x: i32| x % d == 0));
+        let mut all = Monoid::combine_all(checks().map(Conjunction)).into_inner();
+        let mut any = Monoid::combine_all(checks().skip(1).map(Disjunction)).into_inner();
+
+        for x in -6..7 {
+            assert_eq!(x % 6 == 0, all.test(x));
+            assert_eq!(x % 2 == 0 || x % 3 == 0, any.test(x));
+        }
+    }
+}
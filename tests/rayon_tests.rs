@@ -0,0 +1,45 @@
+#![cfg(feature = "rayon")]
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use rayon::prelude::*;
+
+use rust2fun::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_ne_vec(xs in vec(any::<u8>(), 1..99)) {
+        let mut fa: NEVec<_> = xs.clone().try_into().unwrap();
+
+        prop_assert_eq!(&xs, &(&fa).into_par_iter().copied().collect::<Vec<_>>());
+        (&mut fa).into_par_iter().for_each(|x| *x = x.wrapping_add(1));
+        prop_assert_eq!(&fa, &fa.clone().into_par_iter().collect::<NEVec<_>>());
+        prop_assert_eq!(
+            xs.iter().map(|x| x.wrapping_add(2)).collect::<Vec<_>>(),
+            fa.par_map(|x| x.wrapping_add(1)).into_vec()
+        );
+    }
+
+    #[test]
+    fn test_try_from_par_iter(xs: Vec<u8>) {
+        prop_assert_eq!(NEVec::from_vec(xs.clone()), NEVec::try_from_par_iter(xs));
+    }
+
+    #[test]
+    fn test_chain(xs: Vec<u8>, ys: Vec<u8>) {
+        let fa = Chain::from(xs.clone()).combine(Chain::from(ys.clone()));
+        let expected: Vec<u8> = xs.into_iter().chain(ys).collect();
+
+        prop_assert_eq!(&expected, &(&fa).into_par_iter().copied().collect::<Vec<_>>());
+        prop_assert_eq!(
+            Chain::from(expected),
+            fa.into_par_iter().collect::<Chain<_>>()
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_from_par_iter_empty() {
+    let _: NEVec<u8> = Vec::new().into_par_iter().collect();
+}
@@ -0,0 +1,155 @@
+mod common;
+
+if_std! {
+    extern crate rust2fun_laws;
+
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use rust2fun::prelude::*;
+
+    use rust2fun_laws::applicative_laws::*;
+    use rust2fun_laws::apply_laws::*;
+    use rust2fun_laws::flatmap_laws::*;
+    use rust2fun_laws::functor_laws::*;
+    use rust2fun_laws::invariant_laws::*;
+    use rust2fun_laws::monad_laws::*;
+    use rust2fun_laws::semigroup_laws::*;
+    use rust2fun_laws::semigroupal_laws::*;
+
+    use crate::common::{parse, print};
+
+    #[test]
+    fn test_rc_invariant() {
+        assert!(invariant_identity(Rc::new("id")).holds());
+        assert!(invariant_composition(Rc::new(1), print, parse, parse::<i32>, print).holds());
+    }
+
+    #[test]
+    fn test_rc_functor() {
+        assert!(covariant_identity(Rc::new(1)).holds());
+        assert!(covariant_composition(Rc::new(1), print, parse::<u32>).holds());
+        assert!(lift_identity(Rc::new(1)).holds());
+        assert!(lift_composition(Rc::new(1), print, parse::<i64>).holds());
+    }
+
+    #[test]
+    fn test_rc_semigroup() {
+        assert!(repeat_0(Rc::new(1)).holds());
+        assert!(repeat_1(Rc::new(1)).holds());
+        assert!(semigroup_associativity(Rc::new(1), Rc::new(2), Rc::new(3)).holds());
+    }
+
+    #[test]
+    fn test_rc_semigroupal() {
+        assert!(semigroupal_associativity(
+            Rc::new(1),
+            Rc::new("box".to_string()),
+            Rc::new(Ok::<_, bool>("ok"))
+        )
+        .holds());
+    }
+
+    #[test]
+    fn test_rc_apply() {
+        let check_length = |x: &str, l: usize| x.len() == l;
+
+        assert!(map2_product_consistency(Rc::new("str"), Rc::new(1), check_length).holds());
+        assert!(product_r_consistency(Rc::new("str"), Rc::new(1)).holds());
+        assert!(product_l_consistency(Rc::new("str"), Rc::new(1)).holds());
+    }
+
+    #[test]
+    fn test_rc_applicative() {
+        assert!(applicative_identity(Rc::new(1)).holds());
+        assert!(applicative_homomorphism::<Rc<_>, _, _>(1, print).holds());
+        assert!(applicative_map(Rc::new(1), print).holds());
+        assert!(ap_product_consistent(Rc::new(1), Rc::new(print)).holds());
+        assert!(applicative_unit::<Rc<_>>(1).holds());
+    }
+
+    #[test]
+    fn test_rc_flatmap() {
+        assert!(flat_map_associativity(Rc::new(1), |x| Rc::new(x + 1), |x| Rc::new(x + 2)).holds());
+        assert!(flat_map_consistent_apply(Rc::new(1), Rc::new(print)).holds());
+        assert!(m_product_consistency(Rc::new(1), |x| Rc::new(x + 1)).holds());
+    }
+
+    #[test]
+    fn test_rc_monad() {
+        assert!(monad_left_identity::<Rc<_>, _, _>(1, |x| Rc::new(x + 1)).holds());
+        assert!(monad_right_identity(Rc::new(1)).holds());
+        assert!(map_flat_map_coherence(Rc::new(1), |x| x + 1).holds());
+    }
+
+    #[test]
+    fn test_arc_invariant() {
+        assert!(invariant_identity(Arc::new("id")).holds());
+        assert!(invariant_composition(Arc::new(1), print, parse, parse::<i32>, print).holds());
+    }
+
+    #[test]
+    fn test_arc_functor() {
+        assert!(covariant_identity(Arc::new(1)).holds());
+        assert!(covariant_composition(Arc::new(1), print, parse::<u32>).holds());
+        assert!(lift_identity(Arc::new(1)).holds());
+        assert!(lift_composition(Arc::new(1), print, parse::<i64>).holds());
+    }
+
+    #[test]
+    fn test_arc_semigroup() {
+        assert!(repeat_0(Arc::new(1)).holds());
+        assert!(repeat_1(Arc::new(1)).holds());
+        assert!(semigroup_associativity(Arc::new(1), Arc::new(2), Arc::new(3)).holds());
+    }
+
+    #[test]
+    fn test_arc_semigroupal() {
+        assert!(semigroupal_associativity(
+            Arc::new(1),
+            Arc::new("box".to_string()),
+            Arc::new(Ok::<_, bool>("ok"))
+        )
+        .holds());
+    }
+
+    #[test]
+    fn test_arc_apply() {
+        let check_length = |x: &str, l: usize| x.len() == l;
+
+        assert!(map2_product_consistency(Arc::new("str"), Arc::new(1), check_length).holds());
+        assert!(product_r_consistency(Arc::new("str"), Arc::new(1)).holds());
+        assert!(product_l_consistency(Arc::new("str"), Arc::new(1)).holds());
+    }
+
+    #[test]
+    fn test_arc_applicative() {
+        assert!(applicative_identity(Arc::new(1)).holds());
+        assert!(applicative_homomorphism::<Arc<_>, _, _>(1, print).holds());
+        assert!(applicative_map(Arc::new(1), print).holds());
+        assert!(ap_product_consistent(Arc::new(1), Arc::new(print)).holds());
+        assert!(applicative_unit::<Arc<_>>(1).holds());
+    }
+
+    #[test]
+    fn test_arc_flatmap() {
+        assert!(flat_map_associativity(Arc::new(1), |x| Arc::new(x + 1), |x| Arc::new(x + 2)).holds());
+        assert!(flat_map_consistent_apply(Arc::new(1), Arc::new(print)).holds());
+        assert!(m_product_consistency(Arc::new(1), |x| Arc::new(x + 1)).holds());
+    }
+
+    #[test]
+    fn test_arc_monad() {
+        assert!(monad_left_identity::<Arc<_>, _, _>(1, |x| Arc::new(x + 1)).holds());
+        assert!(monad_right_identity(Arc::new(1)).holds());
+        assert!(map_flat_map_coherence(Arc::new(1), |x| x + 1).holds());
+    }
+
+    #[test]
+    fn test_shared() {
+        let x = Rc::new("foo".to_string());
+        let shared = Rc::clone(&x);
+        assert_eq!(Rc::new(3), x.map(|s| s.len()));
+        assert_eq!("foo", *shared);
+    }
+}
@@ -2,14 +2,17 @@ extern crate rust2fun_laws;
 
 use proptest::prelude::*;
 
+use rust2fun_laws::and_then_laws::*;
 use rust2fun_laws::applicative_laws::*;
 use rust2fun_laws::apply_laws::*;
 use rust2fun_laws::bifunctor_laws::*;
 use rust2fun_laws::flatmap_laws::*;
 use rust2fun_laws::functor_laws::*;
 use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_error_laws::*;
 use rust2fun_laws::monad_laws::*;
 use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::traverse_laws::*;
 
 use crate::common::{parse, print};
 
@@ -32,7 +35,7 @@ proptest! {
 
     #[test]
     fn test_bifunctor(fa: Result<bool, i32>) {
-        prop_assert!(bifunctor_identity(fa.clone()).holds());
+        prop_assert!(bifunctor_identity(fa).holds());
         prop_assert!(bifunctor_composition(fa, print, parse::<bool>, print, parse::<i32>).holds())
     }
 
@@ -68,6 +71,8 @@ proptest! {
         prop_assert!(flat_map_consistent_apply(fa, Err::<fn(bool)-> String, _>(-1)).holds());
         prop_assert!(m_product_consistency(fa, |x| Ok(print(x))).holds());
         prop_assert!(m_product_consistency(fa, |_| Err::<String, _>(-1)).holds());
+        prop_assert!(and_then_flat_map_consistency(fa, |x| Ok(print(x))).holds());
+        prop_assert!(and_then_flat_map_consistency(fa, |_| Err::<String, _>(-1)).holds());
     }
 
     #[test]
@@ -77,4 +82,22 @@ proptest! {
         prop_assert!(monad_right_identity(fa).holds());
         prop_assert!(map_flat_map_coherence(fa, print).holds());
     }
+
+    #[test]
+    fn test_monad_error(a: bool, e: i32, fa: Result<bool, i32>) {
+        prop_assert!(handle_error_with_raise_error(e, |x| Ok::<_, i32>(x > 0)).holds());
+        prop_assert!(handle_error_with_raise_error(e, |x| Err::<bool, _>(x.wrapping_add(1))).holds());
+        prop_assert!(handle_error_raise_error::<Result<_, i32>, _, _>(e, |x| x > 0).holds());
+        prop_assert!(handle_error_with_pure::<Result<_, i32>, _, _>(a, Err).holds());
+        prop_assert!(raise_error_attempt::<Result<bool, _>, _>(e).holds());
+        prop_assert!(pure_attempt::<Result<_, i32>, _>(a).holds());
+        prop_assert!(attempt_rethrow(fa).holds());
+        prop_assert!(monad_error_left_zero::<Result<bool, _>, _, _, _>(e, |x| Ok(print(x))).holds());
+        prop_assert!(ensure_consistency(fa, e, |x| *x).holds());
+    }
+
+    #[test]
+    fn test_traverse(fa: Result<bool, i32>) {
+        prop_assert!(traverse_identity(fa, print).holds());
+    }
 }
@@ -0,0 +1,65 @@
+#![cfg(all(feature = "std", feature = "serde"))]
+
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+use rust2fun::prelude::*;
+
+proptest! {
+    #[test]
+    fn test_validated(fa: Result<u8, String>) {
+        let fa: Validated<_, String> = fa.into();
+        let json = serde_json::to_string(&fa).unwrap();
+
+        prop_assert_eq!(fa, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn test_ior(a: Option<u8>, b: Option<String>) {
+        let fa = match (a, b) {
+            (Some(a), Some(b)) => Ior::Both(a, b),
+            (Some(a), None) => Ior::Left(a),
+            (None, Some(b)) => Ior::Right(b),
+            (None, None) => return Ok(()),
+        };
+        let json = serde_json::to_string(&fa).unwrap();
+
+        prop_assert_eq!(fa, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn test_ne_vec(head: u8, tail: Vec<u8>) {
+        let fa = NEVec::from((head, tail));
+        let json = serde_json::to_string(&fa).unwrap();
+
+        prop_assert_eq!(serde_json::to_string(&Vec::from(&fa)).unwrap(), json.clone());
+        prop_assert_eq!(fa, serde_json::from_str::<NEVec<u8>>(&json).unwrap());
+    }
+
+    #[test]
+    fn test_ne_map(k: String, v: u8, map: HashMap<String, u8>) {
+        let mut fa = NEMap::new(k, v);
+        fa.extend(map);
+        let json = serde_json::to_string(&fa).unwrap();
+
+        prop_assert_eq!(fa, serde_json::from_str(&json).unwrap());
+    }
+}
+
+#[test]
+fn test_externally_tagged() {
+    let fa: Validated<i32, String> = Valid(1);
+    assert_eq!(r#"{"Valid":1}"#, serde_json::to_string(&fa).unwrap());
+
+    let fa: Validated<i32, String> = Invalid("error".to_string());
+    assert_eq!(
+        r#"{"Invalid":"error"}"#,
+        serde_json::to_string(&fa).unwrap()
+    );
+}
+
+#[test]
+fn test_empty_rejected() {
+    assert!(serde_json::from_str::<NEVec<i32>>("[]").is_err());
+    assert!(serde_json::from_str::<NEMap<String, i32>>("{}").is_err());
+}
@@ -0,0 +1,48 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+
+struct Celsius(f64);
+show_contramap!(Celsius, |c: &Celsius| c.0);
+
+proptest! {
+    #[test]
+    fn test_show_numeric(a: i64, b: u8, c: f64) {
+        prop_assert_eq!(a.to_string(), a.show());
+        prop_assert_eq!(b.to_string(), b.show());
+        prop_assert_eq!(c.to_string(), c.show());
+    }
+
+    #[test]
+    fn test_show_to_consistency(a: Vec<Option<i32>>) {
+        let mut s = String::new();
+        a.show_to(&mut s).unwrap();
+        prop_assert_eq!(a.show(), s);
+    }
+
+    #[test]
+    fn test_show_contramap(a: f64) {
+        prop_assert_eq!(a.show(), Celsius(a).show());
+    }
+}
+
+#[test]
+fn test_show_containers() {
+    assert_eq!("abc", "abc".show());
+    assert_eq!("()", ().show());
+    assert_eq!("(1, a, true)", (1, 'a', true).show());
+    assert_eq!("[]", Vec::<i32>::new().show());
+    assert_eq!("[1, 2, 3]", [1, 2, 3].show());
+    assert_eq!(
+        "[Some(1), None]",
+        VecDeque::from(vec![Some(1), None]).show()
+    );
+    assert_eq!("Err(x)", Err::<i32, _>("x").show());
+    assert_eq!("{1, 2}", BTreeSet::from([2, 1]).show());
+    assert_eq!(
+        "{a: [1], b: []}",
+        BTreeMap::from([("b", vec![]), ("a", vec![1])]).show()
+    );
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "smallvec")]
+
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+use smallvec::SmallVec;
+
+use rust2fun::prelude::*;
+
+use rust2fun_laws::apply_laws::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monoid_laws::*;
+use rust2fun_laws::semigroup_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+type Small<T> = SmallVec<[T; 4]>;
+
+proptest! {
+    #[test]
+    fn test_invariant(fa: Vec<bool>) {
+        let fa: Small<_> = fa.into();
+        prop_assert!(invariant_identity(fa.clone()).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa: Vec<i32>) {
+        let fa: Small<_> = fa.into();
+        prop_assert!(covariant_identity(fa.clone()).holds());
+        prop_assert!(covariant_composition(fa.clone(), print, parse::<i32>).holds());
+        prop_assert!(lift_identity(fa.clone()).holds());
+        prop_assert!(lift_composition(fa, print, parse::<i64>).holds());
+    }
+
+    #[test]
+    fn test_semigroupal(fa: Vec<u8>, fb: Vec<bool>, fc: Vec<String>) {
+        let (fa, fb, fc): (Small<_>, Small<_>, Small<_>) = (fa.into(), fb.into(), fc.into());
+        prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+    }
+
+    #[test]
+    fn test_apply(fa: Vec<String>, fb: Vec<usize>) {
+        let (fa, fb): (Small<_>, Small<_>) = (fa.into(), fb.into());
+        prop_assert!(map2_product_consistency(fa.clone(), fb.clone(), |a, b| a.len() == b).holds());
+        prop_assert!(product_r_consistency(fa.clone(), fb.clone()).holds());
+        prop_assert!(product_l_consistency(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_flatmap(fa: Vec<u8>) {
+        let fa: Small<_> = fa.into();
+        let f = |x: u8| Small::from_elem(x, x as usize % 3);
+        let g = |x: u8| Small::pure(x.wrapping_add(1));
+        prop_assert!(flat_map_associativity(fa.clone(), f, g).holds());
+        prop_assert!(m_product_consistency(fa, f).holds());
+    }
+
+    #[test]
+    fn test_monoid(fa: Vec<u8>, fb: Vec<u8>, fc: Vec<u8>) {
+        let (fa, fb, fc): (Small<_>, Small<_>, Small<_>) = (fa.into(), fb.into(), fc.into());
+        prop_assert!(semigroup_associativity(fa.clone(), fb, fc).holds());
+        prop_assert!(monoid_left_identity(fa.clone()).holds());
+        prop_assert!(monoid_right_identity(fa.clone()).holds());
+        prop_assert!(is_id(fa).holds());
+    }
+
+    #[test]
+    fn test_ne_small_vec(head: u8, tail: Vec<u8>) {
+        let mut xs = vec![head];
+        xs.extend(tail);
+        let fa = NESmallVec::<_, 2>::try_from_iter(xs.clone()).unwrap();
+
+        prop_assert!(covariant_identity(fa.clone()).holds());
+        prop_assert!(covariant_composition(fa.clone(), print, parse::<u8>).holds());
+        prop_assert!(semigroup_associativity(fa.clone(), fa.clone(), NESmallVec::new(0)).holds());
+        prop_assert!(flat_map_associativity(fa.clone(), NESmallVec::pure, |x| NESmallVec::new(x / 2)).holds());
+        prop_assert_eq!(xs.iter().map(|x| *x as u32).sum::<u32>(), fa.reduce_left_to(|x| x as u32, |acc, x| acc + x as u32));
+    }
+}
+
+#[test]
+fn test_validated_errors() {
+    let check = |x: i32| {
+        if x < 0 {
+            Invalid(NESmallVec::<_, 2>::new(x))
+        } else {
+            Valid(x)
+        }
+    };
+
+    let actual = check(-1).map3(check(2), check(-3), |a, b, c| a + b + c);
+    let errors = actual.unwrap_err();
+    assert_eq!([-1, -3], *errors);
+    assert!(!errors.spilled());
+}
@@ -0,0 +1,61 @@
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+
+type Counter<A> = StateT<Option<(u32, A)>, u32, A>;
+
+fn tick() -> Counter<u32> {
+    StateT::new(|s: u32| s.checked_add(1).map(|next| (next, s)))
+}
+
+proptest! {
+    #[test]
+    fn test_pure(s: u32, a: bool) {
+        prop_assert_eq!(Some((s, a)), Counter::pure(a).run(s));
+    }
+
+    #[test]
+    fn test_get_set(s: u32, t: u32) {
+        prop_assert_eq!(Some((s, s)), Counter::get().run(s));
+        prop_assert_eq!(Some((t, ())), Counter::set(t).run(s));
+        prop_assert_eq!(Some((t, t)), Counter::set(t).flat_map(|_| Counter::get()).run(s));
+    }
+
+    #[test]
+    fn test_map(s in 0..u32::MAX) {
+        prop_assert_eq!(Some((s + 1, s.to_string())), tick().map(|x| x.to_string()).run(s));
+    }
+
+    #[test]
+    fn test_flat_map_associativity(s in 0..10_000u32) {
+        let lhs = tick().flat_map(|x| tick().map(move |y| x + y)).flat_map(|z| tick().map(move |w| z * w));
+        let rhs = tick().flat_map(|x| tick().map(move |y| x + y).flat_map(|z| tick().map(move |w| z * w)));
+        prop_assert_eq!(lhs.run(s), rhs.run(s));
+    }
+
+    #[test]
+    fn test_monad_trans(s: u32, ga: Vec<bool>) {
+        let lifted = StateT::<Vec<_>, u32, _>::lift(ga.clone());
+        prop_assert_eq!(ga.iter().map(|a| (s, *a)).collect::<Vec<_>>(), lifted.run(s));
+
+        let identity = StateT::<Option<_>, _, _>::lift(Some(true));
+        prop_assert_eq!(Counter::pure(true).run(s), identity.run(s));
+    }
+}
+
+#[test]
+fn test_run() {
+    assert_eq!(None, tick().run(u32::MAX));
+    assert_eq!(Some(2), tick().flat_map(|_| tick()).run_s(0));
+    assert_eq!(Some(1), tick().flat_map(|_| tick()).run_a(0));
+}
+
+#[test]
+fn test_stack_safety() {
+    let n = 100_000;
+    let program = (0..n).fold(Counter::pure(0), |st, _| st.flat_map(|_| tick()));
+    assert_eq!(Some((n, n - 1)), program.run(0));
+
+    let program = (0..n).fold(tick(), |st, _| st.map(|x| x + 1));
+    assert_eq!(Some((1, n)), program.run(0));
+}
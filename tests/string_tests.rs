@@ -14,6 +14,18 @@ if_std! {
             assert!(semigroup_associativity(fa, fb, fc).holds());
         }
 
+        #[test]
+        fn test_combine_n(fa: String, n in 0u32..64) {
+            assert!(repeat_n(fa.clone(), n).holds());
+            assert!(repeat_n(Some(vec![fa]), n).holds());
+        }
+
+        #[test]
+        fn test_combine_all(xs: Vec<String>) {
+            assert!(combine_all_option_consistency(xs.clone()).holds());
+            assert!(combine_all_consistency(xs).holds());
+        }
+
         #[test]
         fn test_monoid(fa: String) {
             assert!(monoid_left_identity(fa.clone()).holds());
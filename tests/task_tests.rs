@@ -0,0 +1,68 @@
+#![cfg(feature = "async")]
+
+use futures::channel::oneshot;
+use futures::executor::block_on;
+use proptest::prelude::*;
+
+use rust2fun::data::task;
+use rust2fun::prelude::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_functor(x: i32) {
+        prop_assert_eq!(x, block_on(Task::pure(x).map(id)));
+        prop_assert_eq!(
+            block_on(Task::pure(x).map(print).map(parse::<i64>)),
+            block_on(Task::pure(x).map(|x| parse::<i64>(print(x))))
+        );
+    }
+
+    #[test]
+    fn test_monad(x: i32) {
+        let f = |x: i32| Task::new(async move { x.wrapping_add(1) });
+        let g = |x: i32| Task::new(async move { x.wrapping_mul(2) });
+
+        prop_assert_eq!(block_on(f(x)), block_on(Task::pure(x).flat_map(f)));
+        prop_assert_eq!(x, block_on(Task::pure(x).flat_map(Task::pure)));
+        prop_assert_eq!(
+            block_on(Task::pure(x).flat_map(f).flat_map(g)),
+            block_on(Task::pure(x).flat_map(move |x| f(x).flat_map(g)))
+        );
+    }
+
+    #[test]
+    fn test_apply(x: i32, y: bool) {
+        prop_assert_eq!((x, y), block_on(Task::pure(x).product(Task::pure(y))));
+        prop_assert_eq!(print(x), block_on(Task::pure(print).ap(Task::pure(x))));
+    }
+
+    #[test]
+    fn test_traverse(xs: Vec<u8>) {
+        let expected: Vec<_> = xs.iter().map(|x| *x as u32 * 2).collect();
+        let actual = task::traverse(xs, |x| Task::new(async move { x as u32 * 2 }));
+        prop_assert_eq!(expected, block_on(actual));
+    }
+}
+
+#[test]
+fn test_concurrent() {
+    let (tx, rx) = oneshot::channel();
+    let receive = Task::new(async { rx.await.unwrap() });
+    let send = Task::new(async move { tx.send(42).unwrap() });
+
+    assert_eq!((42, ()), block_on(receive.product(send)));
+
+    let (tx, rx) = oneshot::channel();
+    let tasks = vec![
+        Task::new(async { rx.await.unwrap() }),
+        Task::new(async move {
+            tx.send(1).unwrap();
+            2
+        }),
+    ];
+    assert_eq!(vec![1, 2], block_on(task::sequence(tasks)));
+}
@@ -0,0 +1,51 @@
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+use rust2fun_laws::order_laws::*;
+
+mod common;
+
+fn float() -> impl Strategy<Value = Total<f64>> {
+    prop_oneof![
+        any::<f64>(),
+        Just(f64::NAN),
+        Just(-f64::NAN),
+        Just(0.0),
+        Just(-0.0),
+        Just(f64::INFINITY),
+        Just(f64::NEG_INFINITY),
+    ]
+    .prop_map(Total)
+}
+
+proptest! {
+    #[test]
+    fn test_eq(a in float(), b in float(), c in float()) {
+        prop_assert!(eqv_reflexivity(a).holds());
+        prop_assert!(eqv_symmetry(a, b).holds());
+        prop_assert!(eqv_transitivity(a, b, c).holds());
+    }
+
+    #[test]
+    fn test_partial_order(a in float(), b in float(), c in float()) {
+        prop_assert!(partial_order_antisymmetry(a, b).holds());
+        prop_assert!(partial_order_transitivity(a, b, c).holds());
+    }
+
+    #[test]
+    fn test_order(a in float(), b in float()) {
+        prop_assert!(order_totality(a, b).holds());
+        prop_assert!(order_compare_consistency(a, b).holds());
+        prop_assert!(order_eqv_consistency(a, b).holds());
+        prop_assert!(order_min_max(a, b).holds());
+    }
+}
+
+#[test]
+fn test_ord_min_max_with_prelude() {
+    assert_eq!(1, i32::min(1, 2));
+    assert_eq!(2, i32::max(1, 2));
+    assert_eq!(Total(1.0), Total(1.0).min(Total(2.0)));
+}
@@ -2,9 +2,17 @@ extern crate rust2fun_laws;
 
 use proptest::prelude::*;
 
+use rust2fun_laws::applicative_laws::*;
+use rust2fun_laws::apply_laws::*;
 use rust2fun_laws::bifunctor_laws::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_laws::*;
 use rust2fun_laws::monoid_laws::*;
 use rust2fun_laws::semigroup_laws::*;
+use rust2fun_laws::semigroupal_laws::*;
+use rust2fun_laws::traverse_laws::*;
 
 use crate::common::{parse, print};
 
@@ -27,7 +35,63 @@ proptest! {
 
     #[test]
     fn test_bifunctor(fa: (bool, i32)) {
-        prop_assert!(bifunctor_identity(fa.clone()).holds());
+        prop_assert!(bifunctor_identity(fa).holds());
         prop_assert!(bifunctor_composition(fa, print, parse::<bool>, print, parse::<i32>).holds());
     }
+
+    #[test]
+    fn test_invariant(fa: (String, bool)) {
+        prop_assert!(invariant_identity(fa.clone()).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa: (String, bool)) {
+        prop_assert!(covariant_identity(fa.clone()).holds());
+        prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+        prop_assert!(lift_identity(fa.clone()).holds());
+        prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+    }
+
+    #[test]
+    fn test_semigroupal(fa: (String, bool), fb: (String, i32), fc: (String, Result<String, u8>)) {
+        prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+    }
+
+    #[test]
+    fn test_apply(fa: (String, String), fb: (String, usize)) {
+        prop_assert!(map2_product_consistency(fa.clone(), fb.clone(), |a, b| a.len() == b).holds());
+        prop_assert!(product_r_consistency(fa.clone(), fb.clone()).holds());
+        prop_assert!(product_l_consistency(fa, fb).holds());
+    }
+
+    #[test]
+    fn test_applicative(fa: (String, bool), a: bool, w: String) {
+        prop_assert!(applicative_identity(fa.clone()).holds());
+        prop_assert!(applicative_homomorphism::<(String, _), _, _>(a, print).holds());
+        prop_assert!(applicative_map(fa.clone(), print).holds());
+        prop_assert!(ap_product_consistent(fa, (w, print)).holds());
+        prop_assert!(applicative_unit::<(String, _)>(a).holds());
+    }
+
+    #[test]
+    fn test_flatmap(fa: (String, bool), w1: String, w2: String) {
+        let f = move |x| (w1.clone(), print(x));
+        let g = move |s| (w2.clone(), parse::<bool>(s));
+        prop_assert!(flat_map_associativity(fa.clone(), f.clone(), g).holds());
+        prop_assert!(flat_map_consistent_apply(fa.clone(), ("w".to_string(), print)).holds());
+        prop_assert!(m_product_consistency(fa, f).holds());
+    }
+
+    #[test]
+    fn test_monad(a: bool, fa: (String, bool), w: String) {
+        prop_assert!(monad_left_identity::<(String, _), _, _>(a, move |x| (w.clone(), print(x))).holds());
+        prop_assert!(monad_right_identity(fa.clone()).holds());
+        prop_assert!(map_flat_map_coherence(fa, print).holds());
+    }
+
+    #[test]
+    fn test_traverse(fa: (String, bool)) {
+        prop_assert!(traverse_identity(fa, print).holds());
+    }
 }
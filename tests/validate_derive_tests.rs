@@ -0,0 +1,80 @@
+#![cfg(feature = "std")]
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Error {
+    Empty,
+    TooOld(u8),
+}
+
+fn non_empty(s: String) -> ValidatedNev<String, Error> {
+    if s.is_empty() {
+        ValidatedNev::invalid_nev(Error::Empty)
+    } else {
+        Valid(s)
+    }
+}
+
+fn young(age: u8) -> ValidatedNev<u8, Error> {
+    if age > 150 {
+        ValidatedNev::invalid_nev(Error::TooOld(age))
+    } else {
+        Valid(age)
+    }
+}
+
+#[derive(Debug, PartialEq, Validate)]
+#[validate(error = Error)]
+struct User {
+    #[validate(with = non_empty)]
+    name: String,
+    #[validate(with = young)]
+    age: u8,
+    admin: bool,
+}
+
+#[derive(Debug, PartialEq, Validate)]
+#[validate(error = Error)]
+struct Pair<T>(#[validate(with = non_empty)] String, T);
+
+#[derive(Debug, PartialEq, Validate)]
+#[validate(error = Error)]
+struct Unit;
+
+proptest! {
+    #[test]
+    fn test_named(name: String, age: u8, admin: bool) {
+        let mut errors = Vec::new();
+        if name.is_empty() {
+            errors.push(Error::Empty);
+        }
+        if age > 150 {
+            errors.push(Error::TooOld(age));
+        }
+        let expected = match NEVec::from_vec(errors) {
+            None => Valid(User { name: name.clone(), age, admin }),
+            Some(errors) => Invalid(errors),
+        };
+
+        prop_assert_eq!(expected, User::validate(name, age, admin));
+    }
+
+    #[test]
+    fn test_tuple(name: String, x: i32) {
+        let expected = if name.is_empty() {
+            ValidatedNev::invalid_nev(Error::Empty)
+        } else {
+            Valid(Pair(name.clone(), x))
+        };
+
+        prop_assert_eq!(expected, Pair::validate(name, x));
+    }
+}
+
+#[test]
+fn test_unit() {
+    assert_eq!(Valid(Unit), Unit::validate());
+}
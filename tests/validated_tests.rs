@@ -3,6 +3,8 @@ extern crate rust2fun_laws;
 use proptest::prelude::*;
 
 use rust2fun::prelude::*;
+use rust2fun_laws::and_then_laws::*;
+use rust2fun_laws::ap_n_laws::*;
 use rust2fun_laws::applicative_laws::*;
 use rust2fun_laws::apply_laws::*;
 use rust2fun_laws::bifunctor_laws::*;
@@ -38,7 +40,7 @@ proptest! {
     fn test_bifunctor(fa: Result<bool, i32>) {
         let fa: Validated<_, i32> = fa.into();
 
-        prop_assert!(bifunctor_identity(fa.clone()).holds());
+        prop_assert!(bifunctor_identity(fa).holds());
         prop_assert!(bifunctor_composition(fa, print, parse::<bool>, print, parse::<i32>).holds())
     }
 
@@ -83,4 +85,141 @@ proptest! {
         prop_assert!(ap_product_consistent(fa, Invalid::<fn(bool) -> String, _>(-1)).holds());
         prop_assert!(applicative_unit::<Option<_>>(a).holds());
     }
+
+    #[test]
+    fn test_and_then(a: bool, fa: Result<bool, i32>) {
+        let fa: Validated<_, i32> = fa.into();
+        let f = |x: bool| if x { Valid(print(x)) } else { Invalid(-1) };
+        let g = |s: String| if s.len() > 4 { Invalid(-2) } else { Valid(s.len()) };
+
+        prop_assert!(and_then_left_identity::<Validated<_, i32>, _, _>(a, f).holds());
+        prop_assert!(and_then_right_identity(fa).holds());
+        prop_assert!(and_then_associativity(fa, f, g).holds());
+    }
+
+    #[test]
+    fn test_ap_n(fa: Result<String, Vec<i32>>, fb: Result<usize, Vec<i32>>, fc: Result<bool, Vec<i32>>) {
+        let fa: Validated<_, Vec<i32>> = fa.into();
+        let fb: Validated<_, _> = fb.into();
+        let fc: Validated<_, _> = fc.into();
+
+        prop_assert!(ap2_product_consistency(fa.clone(), fb.clone(), |a, b| a.len() == b).holds());
+        prop_assert!(map3_product_consistency(fa, fb, fc, |a, b, c| (a.len() == b) == c).holds());
+    }
+
+    #[test]
+    fn test_fail_fast_and_then(e0: Vec<i32>, e1: Vec<i32>, e2: Vec<i32>) {
+        let fa: Validated<i32, Vec<i32>> = Invalid(e1.clone());
+        let fb: Validated<i32, Vec<i32>> = Invalid(e2.clone());
+        let add = |a: i32, b: i32| a + b;
+
+        prop_assert_eq!(Invalid(e1.clone()), fa.clone().and_then(|_| fb.clone()));
+        prop_assert_eq!(Invalid(e0.clone()), Invalid::<fn(i32, i32) -> i32, _>(e0).ap2(fa.clone(), fb.clone()));
+        prop_assert_eq!(Invalid(e1.clone().combine(e2.clone())), fa.clone().map2(fb.clone(), add));
+        prop_assert_eq!(Invalid(e1.combine(e2)), Valid(add).ap2(fa, fb));
+    }
+
+    #[test]
+    fn test_from_iterator(xs: Vec<Result<u8, String>>) {
+        let expected_valid: Vec<_> = xs.iter().filter_map(|x| x.clone().ok()).collect();
+        let expected_invalid: Vec<_> = xs.iter().filter_map(|x| x.clone().err()).collect();
+        let actual: Validated<Vec<_>, Vec<_>> = xs
+            .into_iter()
+            .map(|x| Validated::from(x.map_err(|e| vec![e])))
+            .collect();
+
+        if expected_invalid.is_empty() {
+            prop_assert_eq!(Valid(expected_valid), actual);
+        } else {
+            prop_assert_eq!(Invalid(expected_invalid), actual);
+        }
+    }
+
+    #[test]
+    fn test_validated_nev(fa: Result<bool, i32>) {
+        let expected: ValidatedNev<_, _> = fa.into();
+
+        prop_assert_eq!(expected.clone(), fa.to_validated_nev());
+        prop_assert_eq!(expected.clone(), ValidatedNev::from_result_nev(fa));
+        if let Err(e) = fa {
+            prop_assert_eq!(expected, Validated::invalid_nev(e));
+        }
+    }
+
+    #[test]
+    fn test_or_else_accumulate(fa: Result<bool, Vec<i32>>, fb: Result<bool, Vec<i32>>) {
+        let fa: Validated<_, Vec<i32>> = fa.into();
+        let fb: Validated<_, Vec<i32>> = fb.into();
+
+        let expected = match (fa.clone(), fb.clone()) {
+            (Invalid(e1), Invalid(e2)) => Invalid(e1.combine(e2)),
+            (fa, fb) => fa.or(fb),
+        };
+        prop_assert_eq!(expected, fa.or_else_accumulate(fb));
+    }
+
+    #[test]
+    fn test_cond(test: bool, x: i32, e: i32) {
+        prop_assert_eq!(test, Validated::cond(test, x, e).is_valid());
+        prop_assert_eq!(
+            Validated::cond(x > e, x, (x, e)),
+            Validated::from_predicate(x, |x| *x > e, |x| (x, e))
+        );
+    }
+
+    #[test]
+    fn test_partition(xs: Vec<Result<u8, String>>) {
+        let expected_valid: Vec<_> = xs.iter().filter_map(|x| x.clone().ok()).collect();
+        let expected_invalid: Vec<_> = xs.iter().filter_map(|x| x.clone().err()).collect();
+        let expected = (expected_invalid, expected_valid);
+
+        prop_assert_eq!(expected.clone(), partition_map(xs.clone(), Validated::from));
+        prop_assert_eq!(
+            expected,
+            partition_validated(xs.into_iter().map(Validated::<_, String>::from))
+        );
+    }
+
+    #[test]
+    fn test_swap(fa: Result<bool, i32>) {
+        let fa: Validated<_, i32> = fa.into();
+
+        let swapped = fa.swap();
+        prop_assert_eq!(fa, swapped.swap());
+        prop_assert_eq!(fa.iter().next(), swapped.iter_err().next());
+        prop_assert_eq!(fa.iter_err().next(), swapped.iter().next());
+        prop_assert_eq!(fa.valid(), fa.into_iter().next());
+    }
+
+    #[test]
+    fn test_ior(fa: Result<bool, Vec<i32>>, w: Vec<i32>) {
+        let fa: Validated<_, Vec<i32>> = fa.into();
+
+        prop_assert_eq!(fa.clone(), Validated::from_ior(fa.clone().into_ior()));
+        match fa.clone().with_warning(w.clone()) {
+            Ior::Both(warnings, x) => {
+                prop_assert_eq!(w, warnings);
+                prop_assert_eq!(fa, Valid(x));
+            }
+            Ior::Left(errors) => prop_assert_eq!(fa.invalid().unwrap().combine(w), errors),
+            Ior::Right(_) => prop_assert!(false),
+        }
+    }
+
+    #[test]
+    fn test_validated_macro(fa: Result<bool, String>, fb: Result<u8, String>, fc: Result<i32, String>) {
+        #[derive(Debug, PartialEq)]
+        struct Record {
+            a: bool,
+            b: u8,
+            c: i32,
+        }
+
+        let fa: Validated<_, String> = fa.into();
+        let fb: Validated<_, String> = fb.into();
+        let fc: Validated<_, String> = fc.into();
+        let expected = fa.clone().map3(fb.clone(), fc.clone(), |a, b, c| Record { a, b, c });
+
+        prop_assert_eq!(expected, validated! { Record { a: fa, b: fb, c: fc } });
+    }
 }
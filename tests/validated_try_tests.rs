@@ -0,0 +1,29 @@
+#![cfg(feature = "try_trait")]
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+
+fn validated_to_validated(fa: Validated<i32, u8>) -> Validated<i32, u16> {
+    Valid(fa? + 1)
+}
+
+fn result_to_validated(fa: Result<i32, u8>) -> Validated<i32, u16> {
+    Valid(fa? + 1)
+}
+
+fn validated_to_result(fa: Validated<i32, u8>) -> Result<i32, u16> {
+    Ok(fa? + 1)
+}
+
+proptest! {
+    #[test]
+    fn test_try(fa: Result<i16, u8>) {
+        let fa = fa.map(i32::from);
+        let expected = fa.map(|x| x + 1).map_err(u16::from);
+
+        prop_assert_eq!(Validated::from(expected), validated_to_validated(fa.into()));
+        prop_assert_eq!(Validated::from(expected), result_to_validated(fa));
+        prop_assert_eq!(expected, validated_to_result(fa.into()));
+    }
+}
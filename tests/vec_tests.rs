@@ -3,9 +3,20 @@ mod common;
 if_std! {
     extern crate rust2fun_laws;
 
+    use std::cell::Cell;
+    use std::rc::Rc;
+
     use proptest::collection::vec;
     use proptest::prelude::*;
 
+    use rust2fun::ap_n::ApN;
+    use rust2fun::apply::Apply;
+    use rust2fun::data::Ior;
+    use rust2fun::pure::Pure;
+    use rust2fun::semigroupal::{ProductRef, Semigroupal};
+    use rust2fun::traverse::{sequence_, traverse_, Traverse};
+
+    use rust2fun_laws::align_laws::*;
     use rust2fun_laws::applicative_laws::*;
     use rust2fun_laws::apply_laws::*;
     use rust2fun_laws::flatmap_laws::*;
@@ -15,9 +26,65 @@ if_std! {
     use rust2fun_laws::monoid_laws::*;
     use rust2fun_laws::semigroup_laws::*;
     use rust2fun_laws::semigroupal_laws::*;
+    use rust2fun_laws::traverse_laws::*;
 
     use crate::common::{parse, print};
 
+    #[derive(Debug)]
+    struct Counted(Rc<Cell<usize>>);
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            Counted(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_ap_clones() {
+        let clones = Rc::new(Cell::new(0));
+        let fa = vec![Counted(clones.clone()), Counted(clones.clone())];
+
+        let actual = Vec::pure(|x: Counted| x.0.get()).ap(fa.clone());
+        assert_eq!(vec![2, 2], actual);
+        assert_eq!(2, clones.get());
+
+        let fs: Vec<fn(Counted) -> usize> = vec![|_| 0, |_| 1, |_| 2];
+        assert_eq!(vec![0, 0, 1, 1, 2, 2], fs.ap(fa));
+        assert_eq!(6, clones.get());
+    }
+
+    #[test]
+    fn test_ap_n_clones() {
+        let clones = Rc::new(Cell::new(0));
+        let fa = vec![Counted(clones.clone()), Counted(clones.clone())];
+        clones.set(0);
+
+        let f = |x: Counted, y: u8| x.0.get() + y as usize;
+        let actual = Vec::pure(f).ap2(fa.clone(), vec![1, 2, 3]);
+        assert_eq!(6, actual.len());
+        assert_eq!(8, clones.get());
+
+        clones.set(0);
+        let fs: Vec<fn(Counted, u8) -> u8> = vec![|_, y| y, |_, y| y + 1];
+        assert_eq!(vec![1, 2, 3, 1, 2, 3, 2, 3, 4, 2, 3, 4], fs.ap2(fa, vec![1, 2, 3]));
+        assert_eq!(12, clones.get());
+    }
+
+    #[test]
+    fn test_product_clones() {
+        let clones = Rc::new(Cell::new(0));
+        let fa = vec![Counted(clones.clone()), Counted(clones.clone())];
+        let fb = vec![Counted(clones.clone()); 3];
+        clones.set(0);
+
+        assert_eq!(6, fa.product_ref(&fb).len());
+        assert_eq!(0, clones.get());
+
+        assert_eq!(6, fa.product(fb).len());
+        assert_eq!(12, clones.get());
+    }
+
     proptest! {
         #[test]
         fn test_invariant(fa: Vec<bool>) {
@@ -33,6 +100,13 @@ if_std! {
             prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
         }
 
+        #[test]
+        fn test_align(fa: Vec<bool>, fb: Vec<String>) {
+            prop_assert!(align_idempotency(fa.clone()).holds());
+            prop_assert!(align_commutativity(fa.clone(), fb.clone()).holds());
+            prop_assert!(align_with_consistency(fa, fb, Ior::pad).holds());
+        }
+
         #[test]
         fn test_semigroup(fa: Vec<String>, fb: Vec<String>, fc: Vec<String>) {
             prop_assert!(repeat_0(fa.clone()).holds());
@@ -47,9 +121,16 @@ if_std! {
             prop_assert!(is_id(fa).holds());
         }
 
+        #[test]
+        fn test_combine_all(xs: Vec<Vec<u8>>) {
+            prop_assert!(combine_all_option_consistency(xs.clone()).holds());
+            prop_assert!(combine_all_consistency(xs).holds());
+        }
+
         #[test]
         fn test_semigroupal(fa: Vec<bool>, fb: Vec<i32>, fc: Vec<Result<String, u8>>) {
-            prop_assert!(semigroupal_associativity(fa, fb, fc).holds());
+            prop_assert!(semigroupal_associativity(fa.clone(), fb.clone(), fc).holds());
+            prop_assert!(product_ref_consistency(fa, fb).holds());
         }
 
         #[test]
@@ -87,5 +168,19 @@ if_std! {
             prop_assert!(monad_right_identity(fa.clone()).holds());
             prop_assert!(map_flat_map_coherence(fa, print).holds());
         }
+
+        #[test]
+        fn test_traverse(fa: Vec<bool>) {
+            prop_assert!(traverse_identity(fa, print).holds());
+        }
+
+        #[test]
+        fn test_traverse_(fa: Vec<i8>) {
+            let f = |x: i8| if x > 0 { Ok(x) } else { Err(x) };
+            let expected = fa.clone().traverse(f).map(|_| ());
+
+            prop_assert_eq!(expected, traverse_(fa.clone(), f));
+            prop_assert_eq!(expected, sequence_(fa.into_iter().map(f)));
+        }
     }
 }
@@ -0,0 +1,70 @@
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+use rust2fun_laws::flatmap_laws::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+use rust2fun_laws::monad_laws::*;
+use rust2fun_laws::monad_trans_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+type Writer<F, A> = WriterT<F, String, A>;
+
+fn log<A: ToString>(x: A) -> Writer<Vec<(String, A)>, A> {
+    WriterT::new(vec![(x.to_string(), x)])
+}
+
+proptest! {
+    #[test]
+    fn test_invariant(fa: Option<(String, bool)>) {
+        let fa: Writer<_, _> = WriterT::new(fa);
+        prop_assert!(invariant_identity(fa.clone()).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa: Vec<(String, bool)>) {
+        let fa: Writer<_, _> = WriterT::new(fa);
+        prop_assert!(covariant_identity(fa.clone()).holds());
+        prop_assert!(covariant_composition(fa.clone(), print, parse::<bool>).holds());
+        prop_assert!(lift_identity(fa.clone()).holds());
+        prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+    }
+
+    #[test]
+    fn test_flatmap(fa: Vec<(String, bool)>) {
+        let fa: Writer<_, _> = WriterT::new(fa);
+        prop_assert!(flat_map_associativity(fa.clone(), |x| log(print(x)), |s| log(parse::<bool>(s))).holds());
+        prop_assert!(flat_map_associativity(fa, |_| WriterT::new(vec![]), |s| log(parse::<bool>(s))).holds());
+    }
+
+    #[test]
+    fn test_monad(a: bool, fa: Option<(String, bool)>) {
+        let fa: Writer<_, _> = WriterT::new(fa);
+        prop_assert!(monad_left_identity::<Writer<Vec<_>, _>, _, _>(a, |x| log(print(x))).holds());
+        prop_assert!(monad_right_identity(fa.clone()).holds());
+        prop_assert!(map_flat_map_coherence(fa, print).holds());
+    }
+
+    #[test]
+    fn test_monad_trans(a: bool, ga: Vec<bool>) {
+        prop_assert!(monad_trans_identity::<Writer<Option<_>, _>>(a).holds());
+        prop_assert!(monad_trans_composition::<Writer<Vec<_>, _>, _, _>(ga, |x| vec![print(x), print(!x)]).holds());
+    }
+}
+
+#[test]
+fn test_log_accumulation() {
+    let actual = bind! {
+        for x in log(1);
+        for y in log(2);
+        for _ in WriterT::<Vec<_>, _, _>::tell("!".to_string());
+        x + y
+    };
+    assert_eq!(vec![("12!".to_string(), 3)], actual.run());
+}
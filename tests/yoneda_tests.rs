@@ -0,0 +1,35 @@
+extern crate rust2fun_laws;
+
+use proptest::prelude::*;
+
+use rust2fun::prelude::*;
+use rust2fun_laws::functor_laws::*;
+use rust2fun_laws::invariant_laws::*;
+
+use crate::common::{parse, print};
+
+mod common;
+
+proptest! {
+    #[test]
+    fn test_invariant(fa: Vec<bool>) {
+        let fa = Yoneda::lift(fa);
+        prop_assert!(invariant_identity(fa.clone()).holds());
+        prop_assert!(invariant_composition(fa, print, parse, parse::<bool>, print).holds());
+    }
+
+    #[test]
+    fn test_functor(fa: Option<bool>) {
+        let fa = Yoneda::lift(fa);
+        prop_assert!(covariant_identity(fa).holds());
+        prop_assert!(covariant_composition(fa, print, parse::<bool>).holds());
+        prop_assert!(lift_identity(fa).holds());
+        prop_assert!(lift_composition(fa, print, parse::<bool>).holds());
+    }
+
+    #[test]
+    fn test_lift_lower(fa: Vec<i32>) {
+        prop_assert_eq!(fa.clone(), Yoneda::lift(fa.clone()).lower());
+        prop_assert_eq!(fa.clone().map(print), Yoneda::lift(fa).run(print));
+    }
+}